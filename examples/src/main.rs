@@ -3,9 +3,11 @@
 use dotenv;
 use heroku_rs::framework::{
     auth::Credentials,
+    cassette::Cassette,
     response::{ApiResponse, ApiResult},
     ApiEnvironment, HttpApiClient, HttpApiClientConfig,
 };
+use std::sync::Arc;
 
 mod account_examples;
 mod addon_examples;
@@ -35,6 +37,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // see the create_custom_client and uncomment this to use it
     // let api_client = create_custom_client(token)?;
 
+    // To record the examples to a cassette, and replay them later without network,
+    // see the create_cassette_client and uncomment this to use it
+    // let api_client = create_cassette_client(token, "cassettes/examples.json")?;
+
     apps_examples::run(&api_client);
     // addon_examples::run(&api_client);
     // collaborators_examples::run(&api_client);
@@ -66,6 +72,31 @@ fn create_custom_client(token: String) -> Result<HttpApiClient, Box<dyn std::err
     Ok(api_client)
 }
 
+/// Records the requests to the cassette at `path` when `RECORD=1` is set in the .env file, replays them otherwise.
+fn create_cassette_client(
+    token: String,
+    path: &str,
+) -> Result<HttpApiClient, Box<dyn std::error::Error>> {
+    let cassette = if dotenv::var("RECORD").is_ok() {
+        let mut cassette = Cassette::record(path);
+        cassette.scrub(&token);
+        cassette
+    } else {
+        Cassette::replay(path)?
+    };
+    let credentials = Credentials::UserAuthToken { token };
+    let api_client = HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig {
+            middlewares: vec![Arc::new(cassette)],
+            ..HttpApiClientConfig::default()
+        },
+        ApiEnvironment::Production,
+    )?;
+
+    Ok(api_client)
+}
+
 fn print_response<T: ApiResult>(response: ApiResponse<T>) {
     match response {
         Ok(success) => println!("Success: {:#?}", success),
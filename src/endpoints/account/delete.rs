@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for account and it's properties goes here.
 use super::{Account, AppTransfer};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Account Delete.
 ///
@@ -88,7 +88,7 @@ impl<'a> HerokuEndpoint<Account> for UserAccountDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("users/{}", self.account_id)
+        format!("users/{}", encode_segment(self.account_id))
     }
 }
 
@@ -134,6 +134,6 @@ impl<'a> HerokuEndpoint<AppTransfer> for AppTransferDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("account/app-transfers/{}", self.transfer_id)
+        format!("account/app-transfers/{}", encode_segment(self.transfer_id))
     }
 }
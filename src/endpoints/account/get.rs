@@ -3,7 +3,7 @@ use super::{
     Account, AccountFeature, AppTransfer, Credit, Invoice, InvoiceAddress, Key, SmsNumber,
 };
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Account Info
 ///
@@ -89,7 +89,7 @@ impl<'a> HerokuEndpoint<Account> for UserAccountDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("users/{}", self.account_id)
+        format!("users/{}", encode_segment(self.account_id))
     }
 }
 
@@ -177,7 +177,7 @@ impl<'a> HerokuEndpoint<AccountFeature> for AccountFeatureDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("account/features/{}", self.feature_id)
+        format!("account/features/{}", encode_segment(self.feature_id))
     }
 }
 
@@ -266,7 +266,7 @@ impl<'a> HerokuEndpoint<AppTransfer> for AppTransferDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("account/app-transfers/{}", self.transfer_id)
+        format!("account/app-transfers/{}", encode_segment(self.transfer_id))
     }
 }
 
@@ -313,7 +313,7 @@ impl<'a> HerokuEndpoint<Credit> for AccountCreditDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("account/credits/{}", self.credit_id)
+        format!("account/credits/{}", encode_segment(self.credit_id))
     }
 }
 
@@ -403,7 +403,7 @@ impl<'a> HerokuEndpoint<SmsNumber> for SmsNumberDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("users/{}/sms-number", self.account_id)
+        format!("users/{}/sms-number", encode_segment(self.account_id))
     }
 }
 
@@ -450,7 +450,7 @@ impl<'a> HerokuEndpoint<Invoice> for InvoiceDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("account/invoices/{}", self.invoice_id)
+        format!("account/invoices/{}", encode_segment(self.invoice_id))
     }
 }
 
@@ -583,7 +583,7 @@ impl<'a> HerokuEndpoint<Key> for KeyDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("account/keys/{}", self.key_id)
+        format!("account/keys/{}", encode_segment(self.key_id))
     }
 }
 
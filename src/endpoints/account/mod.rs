@@ -32,29 +32,59 @@ pub use key::Key;
 pub use password::PasswordResetResponse;
 pub use sms_number::SmsNumber;
 
-impl ApiResult for Account {}
+impl ApiResult for Account {
+    const NAME: &'static str = "Account";
+}
 
-impl ApiResult for AccountFeature {}
-impl ApiResult for Vec<AccountFeature> {}
+impl ApiResult for AccountFeature {
+    const NAME: &'static str = "AccountFeature";
+}
+impl ApiResult for Vec<AccountFeature> {
+    const NAME: &'static str = "Vec<AccountFeature>";
+}
 
-impl ApiResult for AppTransfer {}
-impl ApiResult for Vec<AppTransfer> {}
+impl ApiResult for AppTransfer {
+    const NAME: &'static str = "AppTransfer";
+}
+impl ApiResult for Vec<AppTransfer> {
+    const NAME: &'static str = "Vec<AppTransfer>";
+}
 
-impl ApiResult for Credit {}
-impl ApiResult for Vec<Credit> {}
+impl ApiResult for Credit {
+    const NAME: &'static str = "Credit";
+}
+impl ApiResult for Vec<Credit> {
+    const NAME: &'static str = "Vec<Credit>";
+}
 
-impl ApiResult for PasswordResetResponse {}
+impl ApiResult for PasswordResetResponse {
+    const NAME: &'static str = "PasswordResetResponse";
+}
 
-impl ApiResult for SmsNumber {}
+impl ApiResult for SmsNumber {
+    const NAME: &'static str = "SmsNumber";
+}
 
-impl ApiResult for Invoice {}
-impl ApiResult for Vec<Invoice> {}
+impl ApiResult for Invoice {
+    const NAME: &'static str = "Invoice";
+}
+impl ApiResult for Vec<Invoice> {
+    const NAME: &'static str = "Vec<Invoice>";
+}
 
-impl ApiResult for InvoiceAddress {}
-impl ApiResult for Vec<InvoiceAddress> {}
+impl ApiResult for InvoiceAddress {
+    const NAME: &'static str = "InvoiceAddress";
+}
+impl ApiResult for Vec<InvoiceAddress> {
+    const NAME: &'static str = "Vec<InvoiceAddress>";
+}
 
-impl ApiResult for Key {}
-impl ApiResult for Vec<Key> {}
+impl ApiResult for Key {
+    const NAME: &'static str = "Key";
+}
+impl ApiResult for Vec<Key> {
+    const NAME: &'static str = "Vec<Key>";
+}
 
 /// # Account
 ///
@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for account and it's properties goes here.
 use super::{Account, AccountFeature, AppTransfer};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Account Update
 ///
@@ -215,7 +215,7 @@ impl<'a> HerokuEndpoint<Account, (), UserAccountUpdateParams<'a>> for UserAccoun
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("users/{}", self.account_id)
+        format!("users/{}", encode_segment(self.account_id))
     }
     fn body(&self) -> Option<UserAccountUpdateParams<'a>> {
         Some(self.params.clone())
@@ -283,7 +283,7 @@ impl<'a> HerokuEndpoint<AccountFeature, (), AccountFeatureUpdateParams>
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("account/features/{}", self.feature_id)
+        format!("account/features/{}", encode_segment(self.feature_id))
     }
     fn body(&self) -> Option<AccountFeatureUpdateParams> {
         Some(self.params.clone())
@@ -349,7 +349,7 @@ impl<'a> HerokuEndpoint<AppTransfer, (), AppTransferUpdateParams<'a>> for AppTra
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("account/app-transfers/{}", self.transfer_id)
+        format!("account/app-transfers/{}", encode_segment(self.transfer_id))
     }
     fn body(&self) -> Option<AppTransferUpdateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to POST requests for account and it's properties goes here.
 use super::{AppTransfer, Credit, PasswordResetResponse, SmsNumber};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// App Transfer Create
 ///
@@ -332,7 +332,10 @@ impl<'a> HerokuEndpoint<PasswordResetResponse, (), PasswordResetConfirmParams<'a
         Method::Post
     }
     fn path(&self) -> String {
-        format!("password-resets/{}/actions/finalize", self.password_id)
+        format!(
+            "password-resets/{}/actions/finalize",
+            encode_segment(self.password_id)
+        )
     }
     fn body(&self) -> Option<PasswordResetConfirmParams<'a>> {
         Some(self.params.clone())
@@ -381,7 +384,10 @@ impl<'a> HerokuEndpoint<SmsNumber> for SmsNumberRecover<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("users/{}/sms-number/actions/recover", self.account_id)
+        format!(
+            "users/{}/sms-number/actions/recover",
+            encode_segment(self.account_id)
+        )
     }
 }
 
@@ -427,6 +433,9 @@ impl<'a> HerokuEndpoint<SmsNumber> for SmsNumberConfirm<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("users/{}/sms-number/actions/confirm", self.account_id)
+        format!(
+            "users/{}/sms-number/actions/confirm",
+            encode_segment(self.account_id)
+        )
     }
 }
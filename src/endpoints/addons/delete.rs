@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for Addons and it's variations goes here.
 use super::{Addon, AddonAttachment, AddonWebhook};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Add-on Delete
 ///
@@ -50,7 +50,11 @@ impl<'a> HerokuEndpoint<Addon> for AddonDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/addons/{}", self.app_id, self.addon_id)
+        format!(
+            "apps/{}/addons/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.addon_id)
+        )
     }
 }
 
@@ -97,7 +101,7 @@ impl<'a> HerokuEndpoint<AddonAttachment> for AttachmentDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("addon-attachments/{}", self.attachment_id)
+        format!("addon-attachments/{}", encode_segment(self.attachment_id))
     }
 }
 
@@ -151,6 +155,10 @@ impl<'a> HerokuEndpoint<AddonWebhook> for WebhookDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhooks/{}", self.addon_id, self.webhook_id)
+        format!(
+            "addons/{}/webhooks/{}",
+            encode_segment(self.addon_id),
+            encode_segment(self.webhook_id)
+        )
     }
 }
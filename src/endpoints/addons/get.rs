@@ -4,7 +4,7 @@ use super::{
     AddonWebhookDelivery, AddonWebhookEvent,
 };
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Add-on Info
 ///
@@ -48,7 +48,7 @@ impl<'a> HerokuEndpoint<Addon> for AddonDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}", self.addon_id)
+        format!("addons/{}", encode_segment(self.addon_id))
     }
 }
 
@@ -141,7 +141,11 @@ impl<'a> HerokuEndpoint<Addon> for AddonDetailsByApp<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/addons/{}", self.app_id, self.addon_id)
+        format!(
+            "apps/{}/addons/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.addon_id)
+        )
     }
 }
 
@@ -188,7 +192,7 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByApp<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/addons", self.app_id)
+        format!("apps/{}/addons", encode_segment(self.app_id))
     }
 }
 
@@ -235,7 +239,7 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByAccount<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("users/{}/addons", self.account_id)
+        format!("users/{}/addons", encode_segment(self.account_id))
     }
 }
 
@@ -282,7 +286,7 @@ impl<'a> HerokuEndpoint<Vec<Addon>> for AddonListByTeam<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/addons", self.team_id)
+        format!("teams/{}/addons", encode_segment(self.team_id))
     }
 }
 
@@ -329,7 +333,7 @@ impl<'a> HerokuEndpoint<AddonAttachment> for AttachmentDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addon-attachments/{}", self.attachment_id)
+        format!("addon-attachments/{}", encode_segment(self.attachment_id))
     }
 }
 
@@ -419,7 +423,7 @@ impl<'a> HerokuEndpoint<Vec<AddonAttachment>> for AttachmentListByAddon<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/addon-attachments", self.addon_id)
+        format!("addons/{}/addon-attachments", encode_segment(self.addon_id))
     }
 }
 
@@ -466,7 +470,7 @@ impl<'a> HerokuEndpoint<Vec<AddonAttachment>> for AttachmentListByApp<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/addon-attachments", self.app_id)
+        format!("apps/{}/addon-attachments", encode_segment(self.app_id))
     }
 }
 
@@ -521,7 +525,8 @@ impl<'a> HerokuEndpoint<AddonAttachment> for AttachmentDetailsByApp<'a> {
     fn path(&self) -> String {
         format!(
             "apps/{}/addon-attachments/{}",
-            self.app_id, self.attachment_id
+            encode_segment(self.app_id),
+            encode_segment(self.attachment_id)
         )
     }
 }
@@ -569,7 +574,7 @@ impl<'a> HerokuEndpoint<Vec<AddonConfig>> for AddonConfigList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/config", self.addon_id)
+        format!("addons/{}/config", encode_segment(self.addon_id))
     }
 }
 
@@ -659,7 +664,10 @@ impl<'a> HerokuEndpoint<Vec<AddonRegionCapability>> for RegionCapabilityListBySe
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addon-services/{}/region-capabilities", self.service_id)
+        format!(
+            "addon-services/{}/region-capabilities",
+            encode_segment(self.service_id)
+        )
     }
 }
 
@@ -706,7 +714,10 @@ impl<'a> HerokuEndpoint<Vec<AddonRegionCapability>> for RegionCapabilityListByRe
         Method::Get
     }
     fn path(&self) -> String {
-        format!("regions/{}/addon-region-capabilities", self.region_id)
+        format!(
+            "regions/{}/addon-region-capabilities",
+            encode_segment(self.region_id)
+        )
     }
 }
 
@@ -753,7 +764,7 @@ impl<'a> HerokuEndpoint<AddonService> for AddonServiceDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addon-services/{}", self.service_id)
+        format!("addon-services/{}", encode_segment(self.service_id))
     }
 }
 
@@ -843,7 +854,7 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhook>> for WebhookList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhooks", self.addon_id)
+        format!("addons/{}/webhooks", encode_segment(self.addon_id))
     }
 }
 
@@ -896,7 +907,11 @@ impl<'a> HerokuEndpoint<AddonWebhook> for WebhookDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhooks/{}", self.addon_id, self.webhook_id)
+        format!(
+            "addons/{}/webhooks/{}",
+            encode_segment(self.addon_id),
+            encode_segment(self.webhook_id)
+        )
     }
 }
 
@@ -951,7 +966,8 @@ impl<'a> HerokuEndpoint<AddonWebhookDelivery> for WebhookDeliveryDetails<'a> {
     fn path(&self) -> String {
         format!(
             "addons/{}/webhook-deliveries/{}",
-            self.addon_id, self.delivery_id
+            encode_segment(self.addon_id),
+            encode_segment(self.delivery_id)
         )
     }
 }
@@ -999,7 +1015,10 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhookDelivery>> for WebhookDeliveryList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhook-deliveries", self.addon_id)
+        format!(
+            "addons/{}/webhook-deliveries",
+            encode_segment(self.addon_id)
+        )
     }
 }
 
@@ -1046,7 +1065,7 @@ impl<'a> HerokuEndpoint<Vec<AddonWebhookEvent>> for WebhookEventList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhook-events", self.addon_id)
+        format!("addons/{}/webhook-events", encode_segment(self.addon_id))
     }
 }
 
@@ -1096,6 +1115,10 @@ impl<'a> HerokuEndpoint<AddonWebhookDelivery> for WebhookEventDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhook-events/{}", self.addon_id, self.event_id)
+        format!(
+            "addons/{}/webhook-events/{}",
+            encode_segment(self.addon_id),
+            encode_segment(self.event_id)
+        )
     }
 }
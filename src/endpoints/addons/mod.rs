@@ -26,29 +26,61 @@ pub use post::{
     WebhookCreateParams,
 };
 
-impl ApiResult for Addon {}
-impl ApiResult for Vec<Addon> {}
+impl ApiResult for Addon {
+    const NAME: &'static str = "Addon";
+}
+impl ApiResult for Vec<Addon> {
+    const NAME: &'static str = "Vec<Addon>";
+}
 
-impl ApiResult for AddonAttachment {}
-impl ApiResult for Vec<AddonAttachment> {}
+impl ApiResult for AddonAttachment {
+    const NAME: &'static str = "AddonAttachment";
+}
+impl ApiResult for Vec<AddonAttachment> {
+    const NAME: &'static str = "Vec<AddonAttachment>";
+}
 
-impl ApiResult for AddonConfig {}
-impl ApiResult for Vec<AddonConfig> {}
+impl ApiResult for AddonConfig {
+    const NAME: &'static str = "AddonConfig";
+}
+impl ApiResult for Vec<AddonConfig> {
+    const NAME: &'static str = "Vec<AddonConfig>";
+}
 
-impl ApiResult for AddonRegionCapability {}
-impl ApiResult for Vec<AddonRegionCapability> {}
+impl ApiResult for AddonRegionCapability {
+    const NAME: &'static str = "AddonRegionCapability";
+}
+impl ApiResult for Vec<AddonRegionCapability> {
+    const NAME: &'static str = "Vec<AddonRegionCapability>";
+}
 
-impl ApiResult for AddonService {}
-impl ApiResult for Vec<AddonService> {}
+impl ApiResult for AddonService {
+    const NAME: &'static str = "AddonService";
+}
+impl ApiResult for Vec<AddonService> {
+    const NAME: &'static str = "Vec<AddonService>";
+}
 
-impl ApiResult for AddonWebhook {}
-impl ApiResult for Vec<AddonWebhook> {}
+impl ApiResult for AddonWebhook {
+    const NAME: &'static str = "AddonWebhook";
+}
+impl ApiResult for Vec<AddonWebhook> {
+    const NAME: &'static str = "Vec<AddonWebhook>";
+}
 
-impl ApiResult for AddonWebhookDelivery {}
-impl ApiResult for Vec<AddonWebhookDelivery> {}
+impl ApiResult for AddonWebhookDelivery {
+    const NAME: &'static str = "AddonWebhookDelivery";
+}
+impl ApiResult for Vec<AddonWebhookDelivery> {
+    const NAME: &'static str = "Vec<AddonWebhookDelivery>";
+}
 
-impl ApiResult for AddonWebhookEvent {}
-impl ApiResult for Vec<AddonWebhookEvent> {}
+impl ApiResult for AddonWebhookEvent {
+    const NAME: &'static str = "AddonWebhookEvent";
+}
+impl ApiResult for Vec<AddonWebhookEvent> {
+    const NAME: &'static str = "Vec<AddonWebhookEvent>";
+}
 
 pub use addon::Addon;
 pub use addon_attachment::AddonAttachment;
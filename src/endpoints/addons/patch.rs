@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for Addons and it's variations goes here.
 use super::{Addon, AddonConfig, AddonWebhook};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Add-on Update
 ///
@@ -91,7 +91,11 @@ impl<'a> HerokuEndpoint<Addon, (), AddonUpdateParams<'a>> for AddonUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/addons/{}", self.app_id, self.addon_id)
+        format!(
+            "apps/{}/addons/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.addon_id)
+        )
     }
     fn body(&self) -> Option<AddonUpdateParams<'a>> {
         Some(self.params.clone())
@@ -176,7 +180,7 @@ impl<'a> HerokuEndpoint<Vec<AddonConfig>, (), AddonConfigUpdateParams> for Addon
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("addons/{}/config", self.addon_id)
+        format!("addons/{}/config", encode_segment(self.addon_id))
     }
     fn body(&self) -> Option<AddonConfigUpdateParams> {
         Some(self.params.clone())
@@ -314,7 +318,11 @@ impl<'a> HerokuEndpoint<AddonWebhook, (), WebhookUpdateParams<'a>> for WebhookUp
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhooks/{}", self.addon_id, self.webhook_id)
+        format!(
+            "addons/{}/webhooks/{}",
+            encode_segment(self.addon_id),
+            encode_segment(self.webhook_id)
+        )
     }
     fn body(&self) -> Option<WebhookUpdateParams<'a>> {
         Some(self.params.clone())
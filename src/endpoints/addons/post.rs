@@ -1,6 +1,6 @@
 //Anything related to POST requests for Addons and it's variations goes here.
 use super::{Addon, AddonAttachment, AddonWebhook};
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 use std::collections::HashMap;
 
 /// Add-on Create
@@ -136,7 +136,7 @@ impl<'a> HerokuEndpoint<Addon, (), AddonCreateParams<'a>> for AddonCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/addons", self.app_id)
+        format!("apps/{}/addons", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<AddonCreateParams<'a>> {
         Some(self.params.clone())
@@ -289,7 +289,7 @@ impl<'a> HerokuEndpoint<Addon> for AddonActionProvision<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("addons/{}/actions/provision", self.addon_id)
+        format!("addons/{}/actions/provision", encode_segment(self.addon_id))
     }
 }
 
@@ -336,7 +336,10 @@ impl<'a> HerokuEndpoint<Addon> for AddonActionDeprovision<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("addons/{}/actions/deprovision", self.addon_id)
+        format!(
+            "addons/{}/actions/deprovision",
+            encode_segment(self.addon_id)
+        )
     }
 }
 
@@ -667,7 +670,7 @@ impl<'a> HerokuEndpoint<AddonWebhook, (), WebhookCreateParams<'a>> for WebhookCr
         Method::Post
     }
     fn path(&self) -> String {
-        format!("addons/{}/webhooks", self.addon_id)
+        format!("addons/{}/webhooks", encode_segment(self.addon_id))
     }
     fn body(&self) -> Option<WebhookCreateParams<'a>> {
         Some(self.params.clone())
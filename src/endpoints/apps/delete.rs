@@ -1,7 +1,7 @@
 //Anything related to deleting apps and it's properties goes here.
 use super::{App, AppWebhook, SNI, SSL};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// App Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<App> for AppDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}", self.app_id)
+        format!("apps/{}", encode_segment(self.app_id))
     }
 }
 
@@ -91,7 +91,7 @@ impl<'a> HerokuEndpoint<App> for AppDisableAcm<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/acm", self.app_id)
+        format!("apps/{}/acm", encode_segment(self.app_id))
     }
 }
 
@@ -138,7 +138,11 @@ impl<'a> HerokuEndpoint<AppWebhook> for AppWebhookDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhooks/{}", self.app_id, self.webhook_id)
+        format!(
+            "apps/{}/webhooks/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.webhook_id)
+        )
     }
 }
 
@@ -186,7 +190,11 @@ impl<'a> HerokuEndpoint<SNI> for SNIDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/sni-endpoints/{}", self.app_id, self.sni_id)
+        format!(
+            "apps/{}/sni-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.sni_id)
+        )
     }
 }
 
@@ -234,6 +242,10 @@ impl<'a> HerokuEndpoint<SSL> for SSLDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/ssl-endpoints/{}", self.app_id, self.ssl_id)
+        format!(
+            "apps/{}/ssl-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.ssl_id)
+        )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to getting apps and it's properties goes here.
 use super::{App, AppFeature, AppSetup, AppWebhook, AppWebhookDelivery, WebhookEvent, SNI, SSL};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// App Info
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<App> for AppDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}", self.app_id)
+        format!("apps/{}", encode_segment(self.app_id))
     }
 }
 
@@ -134,7 +134,7 @@ impl<'a> HerokuEndpoint<Vec<App>> for AccountAppList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("users/{}/apps", self.account_id)
+        format!("users/{}/apps", encode_segment(self.account_id))
     }
 }
 
@@ -182,7 +182,11 @@ impl<'a> HerokuEndpoint<AppFeature> for AppFeatureDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/features/{}", self.app_id, self.feature_id)
+        format!(
+            "apps/{}/features/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.feature_id)
+        )
     }
 }
 
@@ -228,7 +232,7 @@ impl<'a> HerokuEndpoint<Vec<AppFeature>> for AppFeatureList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/features", self.app_id)
+        format!("apps/{}/features", encode_segment(self.app_id))
     }
 }
 
@@ -274,7 +278,7 @@ impl<'a> HerokuEndpoint<Vec<AppWebhook>> for AppWebhookList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhooks", self.app_id)
+        format!("apps/{}/webhooks", encode_segment(self.app_id))
     }
 }
 
@@ -322,7 +326,11 @@ impl<'a> HerokuEndpoint<AppWebhook> for AppWebhookDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhooks/{}", self.app_id, self.webhook_id)
+        format!(
+            "apps/{}/webhooks/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.webhook_id)
+        )
     }
 }
 
@@ -375,7 +383,8 @@ impl<'a> HerokuEndpoint<AppWebhookDelivery> for AppWebhookDeliveryDetails<'a> {
     fn path(&self) -> String {
         format!(
             "apps/{}/webhook-deliveries/{}",
-            self.app_id, self.webhook_delivery_id
+            encode_segment(self.app_id),
+            encode_segment(self.webhook_delivery_id)
         )
     }
 }
@@ -422,7 +431,7 @@ impl<'a> HerokuEndpoint<Vec<AppWebhookDelivery>> for AppWebhookDeliveryList<'a>
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhook-deliveries", self.app_id,)
+        format!("apps/{}/webhook-deliveries", encode_segment(self.app_id))
     }
 }
 
@@ -468,7 +477,7 @@ impl<'a> HerokuEndpoint<AppSetup> for AppSetupDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("app-setups/{}", self.setup_id)
+        format!("app-setups/{}", encode_segment(self.setup_id))
     }
 }
 
@@ -516,7 +525,11 @@ impl<'a> HerokuEndpoint<SNI> for SNIDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/sni-endpoints/{}", self.app_id, self.sni_id)
+        format!(
+            "apps/{}/sni-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.sni_id)
+        )
     }
 }
 
@@ -562,7 +575,7 @@ impl<'a> HerokuEndpoint<Vec<SNI>> for SNIList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/sni-endpoints", self.app_id)
+        format!("apps/{}/sni-endpoints", encode_segment(self.app_id))
     }
 }
 
@@ -608,7 +621,7 @@ impl<'a> HerokuEndpoint<Vec<SSL>> for SSLList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/ssl-endpoints", self.app_id)
+        format!("apps/{}/ssl-endpoints", encode_segment(self.app_id))
     }
 }
 
@@ -656,7 +669,11 @@ impl<'a> HerokuEndpoint<SSL> for SSLDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/ssl-endpoints/{}", self.app_id, self.ssl_id)
+        format!(
+            "apps/{}/ssl-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.ssl_id)
+        )
     }
 }
 
@@ -704,7 +721,11 @@ impl<'a> HerokuEndpoint<WebhookEvent> for WebhookEventDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhook-events/{}", self.app_id, self.event_id)
+        format!(
+            "apps/{}/webhook-events/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.event_id)
+        )
     }
 }
 
@@ -750,6 +771,6 @@ impl<'a> HerokuEndpoint<Vec<WebhookEvent>> for WebhookEventList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhook-events", self.app_id)
+        format!("apps/{}/webhook-events", encode_segment(self.app_id))
     }
 }
@@ -24,29 +24,61 @@ pub use post::{
     SSLCreateParams,
 };
 
-impl ApiResult for App {}
-impl ApiResult for Vec<App> {}
+impl ApiResult for App {
+    const NAME: &'static str = "App";
+}
+impl ApiResult for Vec<App> {
+    const NAME: &'static str = "Vec<App>";
+}
 
-impl ApiResult for AppFeature {}
-impl ApiResult for Vec<AppFeature> {}
+impl ApiResult for AppFeature {
+    const NAME: &'static str = "AppFeature";
+}
+impl ApiResult for Vec<AppFeature> {
+    const NAME: &'static str = "Vec<AppFeature>";
+}
 
-impl ApiResult for AppWebhook {}
-impl ApiResult for Vec<AppWebhook> {}
+impl ApiResult for AppWebhook {
+    const NAME: &'static str = "AppWebhook";
+}
+impl ApiResult for Vec<AppWebhook> {
+    const NAME: &'static str = "Vec<AppWebhook>";
+}
 
-impl ApiResult for AppWebhookDelivery {}
-impl ApiResult for Vec<AppWebhookDelivery> {}
+impl ApiResult for AppWebhookDelivery {
+    const NAME: &'static str = "AppWebhookDelivery";
+}
+impl ApiResult for Vec<AppWebhookDelivery> {
+    const NAME: &'static str = "Vec<AppWebhookDelivery>";
+}
 
-impl ApiResult for AppSetup {}
-impl ApiResult for Vec<AppSetup> {}
+impl ApiResult for AppSetup {
+    const NAME: &'static str = "AppSetup";
+}
+impl ApiResult for Vec<AppSetup> {
+    const NAME: &'static str = "Vec<AppSetup>";
+}
 
-impl ApiResult for SNI {}
-impl ApiResult for Vec<SNI> {}
+impl ApiResult for SNI {
+    const NAME: &'static str = "SNI";
+}
+impl ApiResult for Vec<SNI> {
+    const NAME: &'static str = "Vec<SNI>";
+}
 
-impl ApiResult for SSL {}
-impl ApiResult for Vec<SSL> {}
+impl ApiResult for SSL {
+    const NAME: &'static str = "SSL";
+}
+impl ApiResult for Vec<SSL> {
+    const NAME: &'static str = "Vec<SSL>";
+}
 
-impl ApiResult for WebhookEvent {}
-impl ApiResult for Vec<WebhookEvent> {}
+impl ApiResult for WebhookEvent {
+    const NAME: &'static str = "WebhookEvent";
+}
+impl ApiResult for Vec<WebhookEvent> {
+    const NAME: &'static str = "Vec<WebhookEvent>";
+}
 
 pub use app_setup::AppSetup;
 pub use sni_endpoints::SNI;
@@ -1,7 +1,7 @@
 //Anything related to patching(updating) apps and it's properties goes here.
 use super::{App, AppFeature, AppWebhook, SNI, SSL};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// App Update
 ///
@@ -104,7 +104,7 @@ impl<'a> HerokuEndpoint<App, (), AppUpdateParams<'a>> for AppUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}", self.app_id)
+        format!("apps/{}", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<AppUpdateParams<'a>> {
         Some(self.params.clone())
@@ -153,7 +153,7 @@ impl<'a> HerokuEndpoint<App> for AppRefreshAcm<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/acm", self.app_id)
+        format!("apps/{}/acm", encode_segment(self.app_id))
     }
 }
 
@@ -218,7 +218,11 @@ impl<'a> HerokuEndpoint<AppFeature, (), AppFeatureUpdateParams> for AppFeatureUp
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/features/{}", self.app_id, self.feature_id)
+        format!(
+            "apps/{}/features/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.feature_id)
+        )
     }
     fn body(&self) -> Option<AppFeatureUpdateParams> {
         Some(self.params.clone())
@@ -357,7 +361,11 @@ impl<'a> HerokuEndpoint<AppWebhook, (), AppWebhookUpdateParams<'a>> for AppWebho
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhooks/{}", self.app_id, self.webhook_id)
+        format!(
+            "apps/{}/webhooks/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.webhook_id)
+        )
     }
     fn body(&self) -> Option<AppWebhookUpdateParams<'a>> {
         Some(self.params.clone())
@@ -441,7 +449,11 @@ impl<'a> HerokuEndpoint<SNI, (), SNIUpdateParams<'a>> for SNIUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/sni-endpoints/{}", self.app_id, self.sni_id)
+        format!(
+            "apps/{}/sni-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.sni_id)
+        )
     }
     fn body(&self) -> Option<SNIUpdateParams<'a>> {
         Some(self.params.clone())
@@ -554,7 +566,11 @@ impl<'a> HerokuEndpoint<SSL, (), SSLUpdateParams<'a>> for SSLUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/ssl-endpoints/{}", self.app_id, self.ssl_id)
+        format!(
+            "apps/{}/ssl-endpoints/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.ssl_id)
+        )
     }
     fn body(&self) -> Option<SSLUpdateParams<'a>> {
         Some(self.params.clone())
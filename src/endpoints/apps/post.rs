@@ -2,7 +2,7 @@
 use super::{App, AppSetup, AppWebhook, SNI, SSL};
 use std::collections::HashMap;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// App Create
 ///
@@ -153,7 +153,7 @@ impl<'a> HerokuEndpoint<App> for AppEnableAcm<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/acm", self.app_id)
+        format!("apps/{}/acm", encode_segment(self.app_id))
     }
 }
 
@@ -266,7 +266,7 @@ impl<'a> HerokuEndpoint<AppWebhook, (), AppWebhookCreateParams<'a>> for AppWebho
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/webhooks", self.app_id)
+        format!("apps/{}/webhooks", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<AppWebhookCreateParams<'a>> {
         Some(self.params.clone())
@@ -569,7 +569,7 @@ impl<'a> HerokuEndpoint<SNI, (), SNICreateParams<'a>> for SNICreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/sni-endpoints", self.app_id)
+        format!("apps/{}/sni-endpoints", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<SNICreateParams<'a>> {
         Some(self.params.clone())
@@ -665,7 +665,7 @@ impl<'a> HerokuEndpoint<SSL, (), SSLCreateParams<'a>> for SSLCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/ssl-endpoints", self.app_id)
+        format!("apps/{}/ssl-endpoints", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<SSLCreateParams<'a>> {
         Some(self.params.clone())
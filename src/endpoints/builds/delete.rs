@@ -1,5 +1,5 @@
 //Anything related to DELETE requests for build and it's properties goes here.
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Build Delete cache
 ///
@@ -42,6 +42,6 @@ impl<'a> HerokuEndpoint for BuildDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/build-cache", self.app_id)
+        format!("apps/{}/build-cache", encode_segment(self.app_id))
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to GET requests for build and it's properties goes here.
 use super::{Build, BuildpackInstallation};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Build List
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Vec<Build>> for BuildList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/builds", self.app_id)
+        format!("apps/{}/builds", encode_segment(self.app_id))
     }
 }
 
@@ -93,7 +93,11 @@ impl<'a> HerokuEndpoint<Build> for BuildDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/builds/{}", self.app_id, self.build_id)
+        format!(
+            "apps/{}/builds/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.build_id)
+        )
     }
 }
 
@@ -139,6 +143,9 @@ impl<'a> HerokuEndpoint<Vec<BuildpackInstallation>> for BuildPackInstallationLis
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/buildpack-installations", self.app_id)
+        format!(
+            "apps/{}/buildpack-installations",
+            encode_segment(self.app_id)
+        )
     }
 }
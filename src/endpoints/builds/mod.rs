@@ -12,11 +12,19 @@ pub use get::{BuildDetails, BuildList, BuildPackInstallationList};
 pub use post::{BuildCreate, BuildCreateParams, BuildpackParam, SourceBlobParam};
 pub use put::{BuildpackInstallationUpdate, BuildpackInstallationUpdateParams};
 
-impl ApiResult for Build {}
-impl ApiResult for Vec<Build> {}
+impl ApiResult for Build {
+    const NAME: &'static str = "Build";
+}
+impl ApiResult for Vec<Build> {
+    const NAME: &'static str = "Vec<Build>";
+}
 
-impl ApiResult for BuildpackInstallation {}
-impl ApiResult for Vec<BuildpackInstallation> {}
+impl ApiResult for BuildpackInstallation {
+    const NAME: &'static str = "BuildpackInstallation";
+}
+impl ApiResult for Vec<BuildpackInstallation> {
+    const NAME: &'static str = "Vec<BuildpackInstallation>";
+}
 
 /// Build
 ///
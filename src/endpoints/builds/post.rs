@@ -1,7 +1,7 @@
 //Anything related to POST requests for build and it's properties goes here.
 use super::Build;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Build Create
 ///
@@ -132,7 +132,7 @@ impl<'a> HerokuEndpoint<Build, (), BuildCreateParams<'a>> for BuildCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/builds", self.app_id)
+        format!("apps/{}/builds", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<BuildCreateParams<'a>> {
         Some(self.params.clone())
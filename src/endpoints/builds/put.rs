@@ -1,7 +1,7 @@
 //Anything related to PUT requests for build and it's properties goes here.
 use super::{BuildpackInstallation, Update};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Buildpack Installations Update
 ///
@@ -75,7 +75,10 @@ impl<'a> HerokuEndpoint<Vec<BuildpackInstallation>, (), BuildpackInstallationUpd
         Method::Put
     }
     fn path(&self) -> String {
-        format!("apps/{}/buildpack-installations", self.app_id)
+        format!(
+            "apps/{}/buildpack-installations",
+            encode_segment(self.app_id)
+        )
     }
     fn body(&self) -> Option<BuildpackInstallationUpdateParams> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Collaborator Delete
 ///
@@ -52,7 +52,8 @@ impl<'a> HerokuEndpoint<Collaborator> for CollaboratorDelete<'a> {
     fn path(&self) -> String {
         format!(
             "apps/{}/collaborators/{}",
-            self.app_id, self.collaborator_id
+            encode_segment(self.app_id),
+            encode_segment(self.collaborator_id)
         )
     }
 }
@@ -106,7 +107,8 @@ impl<'a> HerokuEndpoint<TeamCollaborator> for TeamCollaboratorDelete<'a> {
     fn path(&self) -> String {
         format!(
             "teams/apps/{}/collaborators/{}",
-            self.app_id, self.collaborator_id
+            encode_segment(self.app_id),
+            encode_segment(self.collaborator_id)
         )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to GET requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Collaborator List
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Vec<Collaborator>> for CollaboratorList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/collaborators", self.app_id)
+        format!("apps/{}/collaborators", encode_segment(self.app_id))
     }
 }
 
@@ -98,7 +98,8 @@ impl<'a> HerokuEndpoint<Collaborator> for CollaboratorDetails<'a> {
     fn path(&self) -> String {
         format!(
             "apps/{}/collaborators/{}",
-            self.app_id, self.collaborator_id
+            encode_segment(self.app_id),
+            encode_segment(self.collaborator_id)
         )
     }
 }
@@ -144,7 +145,7 @@ impl<'a> HerokuEndpoint<Vec<TeamCollaborator>> for TeamCollaboratorList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}/collaborators", self.app_id)
+        format!("teams/apps/{}/collaborators", encode_segment(self.app_id))
     }
 }
 
@@ -196,7 +197,8 @@ impl<'a> HerokuEndpoint<TeamCollaborator> for TeamCollaboratorDetails<'a> {
     fn path(&self) -> String {
         format!(
             "teams/apps/{}/collaborators/{}",
-            self.app_id, self.collaborator_id
+            encode_segment(self.app_id),
+            encode_segment(self.collaborator_id)
         )
     }
 }
@@ -18,11 +18,19 @@ pub use post::{
 
 pub use patch::{TeamCollaboratorUpdate, TeamCollaboratorUpdateParams};
 
-impl ApiResult for Collaborator {}
-impl ApiResult for Vec<Collaborator> {}
+impl ApiResult for Collaborator {
+    const NAME: &'static str = "Collaborator";
+}
+impl ApiResult for Vec<Collaborator> {
+    const NAME: &'static str = "Vec<Collaborator>";
+}
 
-impl ApiResult for TeamCollaborator {}
-impl ApiResult for Vec<TeamCollaborator> {}
+impl ApiResult for TeamCollaborator {
+    const NAME: &'static str = "TeamCollaborator";
+}
+impl ApiResult for Vec<TeamCollaborator> {
+    const NAME: &'static str = "Vec<TeamCollaborator>";
+}
 
 pub use team_collaborator::TeamCollaborator;
 
@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for collaborators and it's properties goes here.
 use super::TeamCollaborator;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team App Collaborator Update
 ///
@@ -75,7 +75,7 @@ impl<'a> HerokuEndpoint<TeamCollaborator, (), TeamCollaboratorUpdateParams<'a>>
         Method::Post
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}/collaborators", self.app_id)
+        format!("teams/apps/{}/collaborators", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<TeamCollaboratorUpdateParams<'a>> {
         Some(self.params.clone())
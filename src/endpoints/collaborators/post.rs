@@ -1,7 +1,7 @@
 //Anything related to POST requests for collaborators and it's properties goes here.
 use super::{Collaborator, TeamCollaborator};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Collaborator Create
 ///
@@ -81,7 +81,7 @@ impl<'a> HerokuEndpoint<Collaborator, (), CollaboratorCreateParams<'a>> for Coll
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/collaborators", self.app_id)
+        format!("apps/{}/collaborators", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<CollaboratorCreateParams<'a>> {
         Some(self.params.clone())
@@ -184,7 +184,7 @@ impl<'a> HerokuEndpoint<TeamCollaborator, (), TeamCollaboratorCreateParams<'a>>
         Method::Post
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}/collaborators", self.app_id)
+        format!("teams/apps/{}/collaborators", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<TeamCollaboratorCreateParams<'a>> {
         Some(self.params.clone())
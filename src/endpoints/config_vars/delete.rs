@@ -1,6 +1,6 @@
 //Anything related to DELETE requests for config vars and it's variations goes here.
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 use std::collections::HashMap;
 
 /// Config Vars DELETE
@@ -59,7 +59,7 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>, (), HashMap<String, Opt
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/config-vars", self.app_id)
+        format!("apps/{}/config-vars", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<HashMap<String, Option<String>>> {
         Some(self.params.clone())
@@ -145,7 +145,8 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>, (), HashMap<String, Opt
     fn path(&self) -> String {
         format!(
             "pipelines/{}/stage/{}/config-vars",
-            self.pipeline_id, self.stage_id
+            encode_segment(self.pipeline_id),
+            encode_segment(self.stage_id)
         )
     }
     fn body(&self) -> Option<HashMap<String, Option<String>>> {
@@ -1,7 +1,7 @@
 //Anything related to GET requests for config vars and it's variations goes here.
 use std::collections::HashMap;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Config Vars Info for App
 ///
@@ -25,7 +25,7 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for AppConfigVarDetails
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/config-vars", self.app_id)
+        format!("apps/{}/config-vars", encode_segment(self.app_id))
     }
 }
 
@@ -74,7 +74,8 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for ReleaseConfigVarDet
     fn path(&self) -> String {
         format!(
             "apps/{}/releases/{}/config-vars",
-            self.app_id, self.release_id
+            encode_segment(self.app_id),
+            encode_segment(self.release_id)
         )
     }
 }
@@ -127,7 +128,8 @@ impl<'a> HerokuEndpoint<HashMap<String, Option<String>>> for PipelineConfigVarDe
     fn path(&self) -> String {
         format!(
             "pipelines/{}/stage/{}/config-vars",
-            self.pipeline_id, self.stage_id
+            encode_segment(self.pipeline_id),
+            encode_segment(self.stage_id)
         )
     }
 }
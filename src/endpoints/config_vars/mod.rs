@@ -18,8 +18,16 @@ pub use delete::{AppConfigVarDelete, PipelineConfigVarDelete};
 pub use get::{AppConfigVarDetails, PipelineConfigVarDetails, ReleaseConfigVarDetails};
 pub use patch::{AppConfigVarUpdate, PipelineConfigVarUpdate};
 
-impl ApiResult for HashMap<String, Option<String>> {}
-impl ApiResult for Vec<HashMap<String, Option<String>>> {}
+impl ApiResult for HashMap<String, Option<String>> {
+    const NAME: &'static str = "HashMap<String, Option<String>>";
+}
+impl ApiResult for Vec<HashMap<String, Option<String>>> {
+    const NAME: &'static str = "Vec<HashMap<String, Option<String>>>";
+}
 
-impl ApiResult for HashMap<String, String> {}
-impl ApiResult for Vec<HashMap<String, String>> {}
+impl ApiResult for HashMap<String, String> {
+    const NAME: &'static str = "HashMap<String, String>";
+}
+impl ApiResult for Vec<HashMap<String, String>> {
+    const NAME: &'static str = "Vec<HashMap<String, String>>";
+}
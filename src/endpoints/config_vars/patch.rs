@@ -1,6 +1,6 @@
 //Anything related to PATCH requests for config vars and it's variations goes here.
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 use std::collections::HashMap;
 
 /// Config Vars Update
@@ -61,7 +61,7 @@ impl<'a> HerokuEndpoint<HashMap<String, String>, (), HashMap<String, String>>
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/config-vars", self.app_id)
+        format!("apps/{}/config-vars", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<HashMap<String, String>> {
         Some(self.params.clone())
@@ -142,7 +142,8 @@ impl<'a> HerokuEndpoint<HashMap<String, String>, (), HashMap<String, String>>
     fn path(&self) -> String {
         format!(
             "pipelines/{}/stage/{}/config-vars",
-            self.pipeline_id, self.stage_id
+            encode_segment(self.pipeline_id),
+            encode_segment(self.stage_id)
         )
     }
     fn body(&self) -> Option<HashMap<String, String>> {
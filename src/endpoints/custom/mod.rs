@@ -3,8 +3,12 @@ use crate::framework::response::ApiResult;
 use serde::Serialize;
 use serde_json::Value;
 
-impl ApiResult for Value {}
-impl ApiResult for Vec<Value> {}
+impl ApiResult for Value {
+    const NAME: &'static str = "Value";
+}
+impl ApiResult for Vec<Value> {
+    const NAME: &'static str = "Vec<Value>";
+}
 
 /// CustomEndpoint
 /// 
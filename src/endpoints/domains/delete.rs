@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for domains and it's properties goes here.
 use super::Domain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Domain Delete
 ///
@@ -47,6 +47,10 @@ impl<'a> HerokuEndpoint<Domain> for DomainDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/domains/{}", self.app_id, self.domain_id)
+        format!(
+            "apps/{}/domains/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.domain_id)
+        )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to GET requests for domains and it's properties goes here.
 use super::Domain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Domain Info
 ///
@@ -47,7 +47,11 @@ impl<'a> HerokuEndpoint<Domain> for DomainDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/domains/{}", self.app_id, self.domain_id)
+        format!(
+            "apps/{}/domains/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.domain_id)
+        )
     }
 }
 
@@ -93,6 +97,6 @@ impl<'a> HerokuEndpoint<Vec<Domain>> for DomainList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/domains", self.app_id)
+        format!("apps/{}/domains", encode_segment(self.app_id))
     }
 }
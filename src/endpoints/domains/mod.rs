@@ -11,8 +11,12 @@ pub use delete::DomainDelete;
 pub use get::{DomainDetails, DomainList};
 pub use post::{DomainCreate, DomainCreateParams};
 
-impl ApiResult for Domain {}
-impl ApiResult for Vec<Domain> {}
+impl ApiResult for Domain {
+    const NAME: &'static str = "Domain";
+}
+impl ApiResult for Vec<Domain> {
+    const NAME: &'static str = "Vec<Domain>";
+}
 
 /// Domain
 ///
@@ -1,7 +1,7 @@
 //Anything related to POST requests for domains and it's properties goes here.
 use super::Domain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Domain Create
 ///
@@ -61,7 +61,7 @@ impl<'a> HerokuEndpoint<Domain, (), DomainCreateParams<'a>> for DomainCreate<'a>
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/domains", self.app_id)
+        format!("apps/{}/domains", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<DomainCreateParams<'a>> {
         Some(self.params.clone())
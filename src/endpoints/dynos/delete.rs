@@ -1,6 +1,6 @@
 //Anything related to DELETE requests for dynos and it's properties goes here.
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Dyno Restart
 ///
@@ -45,7 +45,11 @@ impl<'a> HerokuEndpoint for DynoRestart<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos/{}", self.app_id, self.dyno_id)
+        format!(
+            "apps/{}/dynos/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.dyno_id)
+        )
     }
 }
 
@@ -90,6 +94,6 @@ impl<'a> HerokuEndpoint for DynoAllRestart<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos", self.app_id)
+        format!("apps/{}/dynos", encode_segment(self.app_id))
     }
 }
@@ -2,7 +2,7 @@
 
 use super::{Dyno, DynoSize};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Dyno Info
 ///
@@ -48,7 +48,11 @@ impl<'a> HerokuEndpoint<Dyno> for DynoDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos/{}", self.app_id, self.dyno_id)
+        format!(
+            "apps/{}/dynos/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.dyno_id)
+        )
     }
 }
 
@@ -94,7 +98,7 @@ impl<'a> HerokuEndpoint<Vec<Dyno>> for DynoList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos", self.app_id)
+        format!("apps/{}/dynos", encode_segment(self.app_id))
     }
 }
 
@@ -183,6 +187,6 @@ impl<'a> HerokuEndpoint<DynoSize> for DynoSizeDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("dyno-sizes/{}", self.size_id)
+        format!("dyno-sizes/{}", encode_segment(self.size_id))
     }
 }
@@ -11,11 +11,19 @@ pub use delete::{DynoAllRestart, DynoRestart};
 pub use get::{DynoDetails, DynoList, DynoSizeDetails, DynoSizeList};
 pub use post::{DynoActionStop, DynoCreate, DynoCreateParams};
 
-impl ApiResult for Dyno {}
-impl ApiResult for Vec<Dyno> {}
+impl ApiResult for Dyno {
+    const NAME: &'static str = "Dyno";
+}
+impl ApiResult for Vec<Dyno> {
+    const NAME: &'static str = "Vec<Dyno>";
+}
 
-impl ApiResult for DynoSize {}
-impl ApiResult for Vec<DynoSize> {}
+impl ApiResult for DynoSize {
+    const NAME: &'static str = "DynoSize";
+}
+impl ApiResult for Vec<DynoSize> {
+    const NAME: &'static str = "Vec<DynoSize>";
+}
 
 pub use dyno_size::DynoSize;
 /// Heroku Dyno
@@ -2,7 +2,7 @@
 
 use super::Dyno;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 use std::collections::HashMap;
 
 /// Dyno Stop
@@ -49,7 +49,11 @@ impl<'a> HerokuEndpoint for DynoActionStop<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos/{}/actions/stop", self.app_id, self.dyno_id)
+        format!(
+            "apps/{}/dynos/{}/actions/stop",
+            encode_segment(self.app_id),
+            encode_segment(self.dyno_id)
+        )
     }
 }
 /// Dyno Create
@@ -208,7 +212,7 @@ impl<'a> HerokuEndpoint<Dyno, (), DynoCreateParams<'a>> for DynoCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/dynos", self.app_id)
+        format!("apps/{}/dynos", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<DynoCreateParams<'a>> {
         Some(self.params.clone())
@@ -2,7 +2,7 @@
 
 use super::Formation;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Formation Info
 ///
@@ -51,7 +51,11 @@ impl<'a> HerokuEndpoint<Formation> for FormationDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/formation/{}", self.app_id, self.formation_id)
+        format!(
+            "apps/{}/formation/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.formation_id)
+        )
     }
 }
 
@@ -96,6 +100,6 @@ impl<'a> HerokuEndpoint<Vec<Formation>> for FormationList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/formation", self.app_id)
+        format!("apps/{}/formation", encode_segment(self.app_id))
     }
 }
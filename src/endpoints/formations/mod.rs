@@ -10,8 +10,12 @@ pub mod put;
 pub use get::{FormationDetails, FormationList};
 pub use patch::{FormationUpdate, FormationUpdateParams};
 
-impl ApiResult for Formation {}
-impl ApiResult for Vec<Formation> {}
+impl ApiResult for Formation {
+    const NAME: &'static str = "Formation";
+}
+impl ApiResult for Vec<Formation> {
+    const NAME: &'static str = "Vec<Formation>";
+}
 
 /// Heroku Formation
 ///
@@ -2,7 +2,7 @@
 
 use super::Formation;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Formation Update
 ///
@@ -99,7 +99,11 @@ impl<'a> HerokuEndpoint<Formation, (), FormationUpdateParams<'a>> for FormationU
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("apps/{}/formation/{}", self.app_id, self.formation_id)
+        format!(
+            "apps/{}/formation/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.formation_id)
+        )
     }
     fn body(&self) -> Option<FormationUpdateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Log Drain Delete
 ///
@@ -47,6 +47,10 @@ impl<'a> HerokuEndpoint<LogDrain> for LogDrainDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("apps/{}/log-drains/{}", self.app_id, self.drain_id)
+        format!(
+            "apps/{}/log-drains/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.drain_id)
+        )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to GET requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Log Drain List
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Vec<LogDrain>> for LogDrainList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/log-drains", self.app_id)
+        format!("apps/{}/log-drains", encode_segment(self.app_id))
     }
 }
 
@@ -93,7 +93,11 @@ impl<'a> HerokuEndpoint<LogDrain> for LogDrainDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/log-drains/{}", self.app_id, self.drain_id)
+        format!(
+            "apps/{}/log-drains/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.drain_id)
+        )
     }
 }
 
@@ -139,6 +143,6 @@ impl<'a> HerokuEndpoint<Vec<LogDrain>> for LogDrainListByAddon<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("addons/{}/log-drains", self.addon_id)
+        format!("addons/{}/log-drains", encode_segment(self.addon_id))
     }
 }
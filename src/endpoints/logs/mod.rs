@@ -11,10 +11,16 @@ pub use get::{LogDrainDetails, LogDrainList, LogDrainListByAddon};
 pub use post::{LogDrainCreate, LogDrainCreateParams, LogSessionCreate, LogSessionCreateParams};
 pub use put::{LogDrainUpdate, LogDrainUpdateParams};
 
-impl ApiResult for LogDrain {}
-impl ApiResult for Vec<LogDrain> {}
+impl ApiResult for LogDrain {
+    const NAME: &'static str = "LogDrain";
+}
+impl ApiResult for Vec<LogDrain> {
+    const NAME: &'static str = "Vec<LogDrain>";
+}
 
-impl ApiResult for LogSession {}
+impl ApiResult for LogSession {
+    const NAME: &'static str = "LogSession";
+}
 
 pub use log_drains::LogDrain;
 pub use log_sessions::LogSession;
@@ -1,7 +1,7 @@
 //Anything related to POST requests for heroku logs and it's properties goes here.
 use super::{LogDrain, LogSession};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Log Drain Create
 ///
@@ -60,7 +60,7 @@ impl<'a> HerokuEndpoint<LogDrain, (), LogDrainCreateParams<'a>> for LogDrainCrea
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/log-drains", self.app_id)
+        format!("apps/{}/log-drains", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<LogDrainCreateParams<'a>> {
         Some(self.params.clone())
@@ -175,7 +175,7 @@ impl<'a> HerokuEndpoint<LogSession, (), LogSessionCreateParams<'a>> for LogSessi
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/log-sessions", self.app_id)
+        format!("apps/{}/log-sessions", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<LogSessionCreateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to PUT requests for heroku logs and it's properties goes here.
 use super::LogDrain;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Log Drain Update
 ///
@@ -63,7 +63,11 @@ impl<'a> HerokuEndpoint<LogDrain, (), LogDrainUpdateParams<'a>> for LogDrainUpda
         Method::Put
     }
     fn path(&self) -> String {
-        format!("addons/{}/log-drains/{}", self.addon_id, self.drain_id)
+        format!(
+            "addons/{}/log-drains/{}",
+            encode_segment(self.addon_id),
+            encode_segment(self.drain_id)
+        )
     }
     fn body(&self) -> Option<LogDrainUpdateParams<'a>> {
         Some(self.params.clone())
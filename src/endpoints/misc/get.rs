@@ -1,7 +1,7 @@
 //Anything related to GET requests for mixed endpoints goes here.
 use super::{Ratelimit, Region, Stack};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Region Info
 ///
@@ -46,7 +46,7 @@ impl<'a> HerokuEndpoint<Region> for RegionDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("regions/{}", self.region_id)
+        format!("regions/{}", encode_segment(self.region_id))
     }
 }
 
@@ -222,6 +222,6 @@ impl<'a> HerokuEndpoint<Stack> for StackDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("stacks/{}", self.stack_id)
+        format!("stacks/{}", encode_segment(self.stack_id))
     }
 }
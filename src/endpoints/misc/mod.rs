@@ -14,15 +14,27 @@ pub use region::Region;
 pub use sources::SourceBlob;
 pub use stack::Stack;
 
-impl ApiResult for Region {}
-impl ApiResult for Vec<Region> {}
+impl ApiResult for Region {
+    const NAME: &'static str = "Region";
+}
+impl ApiResult for Vec<Region> {
+    const NAME: &'static str = "Vec<Region>";
+}
 
-impl ApiResult for Ratelimit {}
+impl ApiResult for Ratelimit {
+    const NAME: &'static str = "Ratelimit";
+}
 
-impl ApiResult for Stack {}
-impl ApiResult for Vec<Stack> {}
+impl ApiResult for Stack {
+    const NAME: &'static str = "Stack";
+}
+impl ApiResult for Vec<Stack> {
+    const NAME: &'static str = "Vec<Stack>";
+}
 
-impl ApiResult for SourceBlob {}
+impl ApiResult for SourceBlob {
+    const NAME: &'static str = "SourceBlob";
+}
 
 mod region {
     use chrono::offset::Utc;
@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for oauth authorizations and it's properties goes here.
 use super::{OAuth, OAuthClient, OAuthToken};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// OAuth Authorization Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<OAuth> for OAuthDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("oauth/authorizations/{}", self.oauth_id)
+        format!("oauth/authorizations/{}", encode_segment(self.oauth_id))
     }
 }
 
@@ -91,7 +91,7 @@ impl<'a> HerokuEndpoint<OAuthClient, (), ()> for OAuthClientDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("oauth/clients/{}", self.client_id)
+        format!("oauth/clients/{}", encode_segment(self.client_id))
     }
 }
 
@@ -137,6 +137,6 @@ impl<'a> HerokuEndpoint<OAuthToken, (), ()> for OAuthTokenDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("oauth/tokens/{}", self.token_id)
+        format!("oauth/tokens/{}", encode_segment(self.token_id))
     }
 }
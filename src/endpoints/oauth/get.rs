@@ -1,7 +1,7 @@
 //Anything related to GET requests for oauth authorizations and it's properties goes here.
 use super::{OAuth, OAuthClient};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// OAuth Authorization Info
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<OAuth> for OAuthDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("oauth/authorizations/{}", self.oauth_id)
+        format!("oauth/authorizations/{}", encode_segment(self.oauth_id))
     }
 }
 
@@ -134,7 +134,7 @@ impl<'a> HerokuEndpoint<OAuthClient> for OAuthClientDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("oauth/clients/{}", self.client_id)
+        format!("oauth/clients/{}", encode_segment(self.client_id))
     }
 }
 
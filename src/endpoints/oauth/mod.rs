@@ -14,14 +14,26 @@ pub use post::{
     OAuthCreateParams, OAuthRegenerate, OAuthTokenCreate, OAuthTokenCreateParams,
 };
 
-impl ApiResult for auth::OAuth {}
-impl ApiResult for Vec<auth::OAuth> {}
+impl ApiResult for auth::OAuth {
+    const NAME: &'static str = "OAuth";
+}
+impl ApiResult for Vec<auth::OAuth> {
+    const NAME: &'static str = "Vec<OAuth>";
+}
 
-impl ApiResult for client::OAuthClient {}
-impl ApiResult for Vec<client::OAuthClient> {}
+impl ApiResult for client::OAuthClient {
+    const NAME: &'static str = "OAuthClient";
+}
+impl ApiResult for Vec<client::OAuthClient> {
+    const NAME: &'static str = "Vec<OAuthClient>";
+}
 
-impl ApiResult for token::OAuthToken {}
-impl ApiResult for Vec<token::OAuthToken> {}
+impl ApiResult for token::OAuthToken {
+    const NAME: &'static str = "OAuthToken";
+}
+impl ApiResult for Vec<token::OAuthToken> {
+    const NAME: &'static str = "Vec<OAuthToken>";
+}
 
 pub use auth::OAuth;
 pub use client::OAuthClient;
@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for oauth authorizations and it's properties goes here.
 use super::OAuthClient;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// OAuth Client Update
 ///
@@ -92,7 +92,7 @@ impl<'a> HerokuEndpoint<OAuthClient, (), OAuthClientUpdateParams<'a>> for OAuthC
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("oauth/clients/{}", self.client_id)
+        format!("oauth/clients/{}", encode_segment(self.client_id))
     }
     fn body(&self) -> Option<OAuthClientUpdateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to POST requests for oauth authorizations and it's properties goes here.
 use super::{OAuth, OAuthClient, OAuthToken};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// OAuth Authorization Create
 ///
@@ -157,7 +157,7 @@ impl<'a> HerokuEndpoint<OAuth> for OAuthRegenerate<'a> {
     fn path(&self) -> String {
         format!(
             "oauth/authorizations/{}/actions/regenerate-tokens",
-            self.oauth_id
+            encode_segment(self.oauth_id)
         )
     }
 }
@@ -270,7 +270,7 @@ impl<'a> HerokuEndpoint<OAuthClient> for OAuthClientRotateCredentials<'a> {
     fn path(&self) -> String {
         format!(
             "oauth/clients/{}/actions/rotate-credentials",
-            self.client_id
+            encode_segment(self.client_id)
         )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for pipelines and it's properties goes here.
 use super::{Pipeline, PipelineCoupling};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Pipeline Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Pipeline> for PipelineDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("pipelines/{}", self.pipeline_id)
+        format!("pipelines/{}", encode_segment(self.pipeline_id))
     }
 }
 
@@ -91,6 +91,6 @@ impl<'a> HerokuEndpoint<PipelineCoupling> for PipelineCouplingDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("pipeline-couplings/{}", self.coupling_id)
+        format!("pipeline-couplings/{}", encode_segment(self.coupling_id))
     }
 }
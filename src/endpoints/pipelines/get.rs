@@ -4,7 +4,7 @@ use super::{
     PipelinePromotionTarget, PipelineRelease, PipelineStack,
 };
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Pipeline Info
 ///
@@ -48,7 +48,7 @@ impl<'a> HerokuEndpoint<Pipeline> for PipelineDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}", self.pipeline_id)
+        format!("pipelines/{}", encode_segment(self.pipeline_id))
     }
 }
 
@@ -137,7 +137,10 @@ impl<'a> HerokuEndpoint<Vec<PipelineBuild>> for PipelineLatestBuildsList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/latest-builds", self.pipeline_id)
+        format!(
+            "pipelines/{}/latest-builds",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
 
@@ -183,7 +186,10 @@ impl<'a> HerokuEndpoint<Vec<PipelineCoupling>> for PipelineCouplingByPipelineLis
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/pipeline-couplings", self.pipeline_id)
+        format!(
+            "pipelines/{}/pipeline-couplings",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
 
@@ -272,7 +278,7 @@ impl<'a> HerokuEndpoint<Vec<PipelineCoupling>> for PipelineCouplingByTeamList<'a
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/pipeline-couplings", self.team_id)
+        format!("teams/{}/pipeline-couplings", encode_segment(self.team_id))
     }
 }
 
@@ -318,7 +324,7 @@ impl<'a> HerokuEndpoint<PipelineCoupling> for PipelineCouplingByAppDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/pipeline-couplings", self.app_id)
+        format!("apps/{}/pipeline-couplings", encode_segment(self.app_id))
     }
 }
 
@@ -407,7 +413,7 @@ impl<'a> HerokuEndpoint<PipelineCoupling> for PipelineCouplingDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipeline-couplings/{}", self.coupling_id)
+        format!("pipeline-couplings/{}", encode_segment(self.coupling_id))
     }
 }
 
@@ -453,7 +459,10 @@ impl<'a> HerokuEndpoint<Vec<PipelineDeployment>> for PipelineDeploymentList<'a>
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/latest-deployments", self.pipeline_id)
+        format!(
+            "pipelines/{}/latest-deployments",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
 
@@ -499,7 +508,7 @@ impl<'a> HerokuEndpoint<PipelinePromotion> for PipelinePromotionDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipeline-promotions/{}", self.promotion_id)
+        format!("pipeline-promotions/{}", encode_segment(self.promotion_id))
     }
 }
 
@@ -547,7 +556,7 @@ impl<'a> HerokuEndpoint<Vec<PipelinePromotionTarget>> for PipelinePromotionTarge
     fn path(&self) -> String {
         format!(
             "pipeline-promotions/{}/promotion-targets",
-            self.promotion_id
+            encode_segment(self.promotion_id)
         )
     }
 }
@@ -594,7 +603,10 @@ impl<'a> HerokuEndpoint<Vec<PipelineRelease>> for PipelineLatestReleaseList<'a>
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/latest-releases", self.pipeline_id)
+        format!(
+            "pipelines/{}/latest-releases",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
 
@@ -640,6 +652,9 @@ impl<'a> HerokuEndpoint<PipelineStack> for PipelineStackDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/pipeline-stack", self.pipeline_id)
+        format!(
+            "pipelines/{}/pipeline-stack",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
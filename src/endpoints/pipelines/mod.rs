@@ -32,32 +32,68 @@ pub use pipeline_releases::PipelineRelease;
 pub use pipeline_stack::PipelineStack;
 pub use pipeline_transfer::PipelineTransfer;
 
-impl ApiResult for Pipeline {}
-impl ApiResult for Vec<Pipeline> {}
+impl ApiResult for Pipeline {
+    const NAME: &'static str = "Pipeline";
+}
+impl ApiResult for Vec<Pipeline> {
+    const NAME: &'static str = "Vec<Pipeline>";
+}
 
-impl ApiResult for PipelineBuild {}
-impl ApiResult for Vec<PipelineBuild> {}
+impl ApiResult for PipelineBuild {
+    const NAME: &'static str = "PipelineBuild";
+}
+impl ApiResult for Vec<PipelineBuild> {
+    const NAME: &'static str = "Vec<PipelineBuild>";
+}
 
-impl ApiResult for PipelineCoupling {}
-impl ApiResult for Vec<PipelineCoupling> {}
+impl ApiResult for PipelineCoupling {
+    const NAME: &'static str = "PipelineCoupling";
+}
+impl ApiResult for Vec<PipelineCoupling> {
+    const NAME: &'static str = "Vec<PipelineCoupling>";
+}
 
-impl ApiResult for PipelineDeployment {}
-impl ApiResult for Vec<PipelineDeployment> {}
+impl ApiResult for PipelineDeployment {
+    const NAME: &'static str = "PipelineDeployment";
+}
+impl ApiResult for Vec<PipelineDeployment> {
+    const NAME: &'static str = "Vec<PipelineDeployment>";
+}
 
-impl ApiResult for PipelinePromotion {}
-impl ApiResult for Vec<PipelinePromotion> {}
+impl ApiResult for PipelinePromotion {
+    const NAME: &'static str = "PipelinePromotion";
+}
+impl ApiResult for Vec<PipelinePromotion> {
+    const NAME: &'static str = "Vec<PipelinePromotion>";
+}
 
-impl ApiResult for PipelinePromotionTarget {}
-impl ApiResult for Vec<PipelinePromotionTarget> {}
+impl ApiResult for PipelinePromotionTarget {
+    const NAME: &'static str = "PipelinePromotionTarget";
+}
+impl ApiResult for Vec<PipelinePromotionTarget> {
+    const NAME: &'static str = "Vec<PipelinePromotionTarget>";
+}
 
-impl ApiResult for PipelineRelease {}
-impl ApiResult for Vec<PipelineRelease> {}
+impl ApiResult for PipelineRelease {
+    const NAME: &'static str = "PipelineRelease";
+}
+impl ApiResult for Vec<PipelineRelease> {
+    const NAME: &'static str = "Vec<PipelineRelease>";
+}
 
-impl ApiResult for PipelineStack {}
-impl ApiResult for Vec<PipelineStack> {}
+impl ApiResult for PipelineStack {
+    const NAME: &'static str = "PipelineStack";
+}
+impl ApiResult for Vec<PipelineStack> {
+    const NAME: &'static str = "Vec<PipelineStack>";
+}
 
-impl ApiResult for PipelineTransfer {}
-impl ApiResult for Vec<PipelineTransfer> {}
+impl ApiResult for PipelineTransfer {
+    const NAME: &'static str = "PipelineTransfer";
+}
+impl ApiResult for Vec<PipelineTransfer> {
+    const NAME: &'static str = "Vec<PipelineTransfer>";
+}
 
 // pipeline submodule, anything from /pipelines goes here.
 mod pipeline {
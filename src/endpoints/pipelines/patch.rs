@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for pipelines and it's properties goes here.
 use super::{Pipeline, PipelineCoupling};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Pipeline Update
 ///
@@ -77,7 +77,7 @@ impl<'a> HerokuEndpoint<Pipeline, (), PipelineUpdateParams<'a>> for PipelineUpda
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("pipelines/{}", self.pipeline_id)
+        format!("pipelines/{}", encode_segment(self.pipeline_id))
     }
     fn body(&self) -> Option<PipelineUpdateParams<'a>> {
         Some(self.params.clone())
@@ -163,7 +163,7 @@ impl<'a> HerokuEndpoint<PipelineCoupling, (), PipelineCouplingUpdateParams<'a>>
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("pipeline-couplings/{}", self.coupling_id)
+        format!("pipeline-couplings/{}", encode_segment(self.coupling_id))
     }
     fn body(&self) -> Option<PipelineCouplingUpdateParams<'a>> {
         Some(self.params.clone())
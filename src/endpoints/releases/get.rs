@@ -2,7 +2,7 @@
 
 use super::Release;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Release List
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Vec<Release>> for ReleaseList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/releases", self.app_id)
+        format!("apps/{}/releases", encode_segment(self.app_id))
     }
 }
 
@@ -92,6 +92,10 @@ impl<'a> HerokuEndpoint<Release> for ReleaseInfo<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/releases/{}", self.app_id, self.release_id)
+        format!(
+            "apps/{}/releases/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.release_id)
+        )
     }
 }
@@ -7,8 +7,12 @@ pub mod post;
 pub use get::{ReleaseInfo, ReleaseList};
 pub use post::{ReleaseCreate, ReleaseCreateParams, ReleaseRollback, ReleaseRollbackParams};
 
-impl ApiResult for Release {}
-impl ApiResult for Vec<Release> {}
+impl ApiResult for Release {
+    const NAME: &'static str = "Release";
+}
+impl ApiResult for Vec<Release> {
+    const NAME: &'static str = "Vec<Release>";
+}
 
 /// Heroku Release
 ///
@@ -1,7 +1,7 @@
 //Anything related to creating apps and it's properties goes here.
 use super::Release;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Release Create
 ///
@@ -84,7 +84,7 @@ impl<'a> HerokuEndpoint<Release, (), ReleaseCreateParams<'a>> for ReleaseCreate<
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/releases", self.app_id)
+        format!("apps/{}/releases", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<ReleaseCreateParams<'a>> {
         Some(self.params.clone())
@@ -152,7 +152,7 @@ impl<'a> HerokuEndpoint<Release, (), ReleaseRollbackParams<'a>> for ReleaseRollb
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/releases", self.app_id)
+        format!("apps/{}/releases", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<ReleaseRollbackParams<'a>> {
         Some(self.params.clone())
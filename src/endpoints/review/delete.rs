@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for review app and it's properties goes here.
 use super::{ReviewApp, ReviewAppConfig};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Review App Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("review-apps/{}", self.review_id)
+        format!("review-apps/{}", encode_segment(self.review_id))
     }
 }
 
@@ -91,6 +91,9 @@ impl<'a> HerokuEndpoint<ReviewAppConfig> for ReviewAppConfigDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/review-app-config", self.pipeline_id)
+        format!(
+            "pipelines/{}/review-app-config",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
@@ -1,7 +1,7 @@
 //Anything related to GET requests for review app and it's properties goes here.
 use super::{ReviewApp, ReviewAppConfig};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Get Review App
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("review-apps/{}", self.review_id)
+        format!("review-apps/{}", encode_segment(self.review_id))
     }
 }
 
@@ -91,7 +91,7 @@ impl<'a> HerokuEndpoint<ReviewApp> for ReviewAppByAppDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/review-app", self.app_id)
+        format!("apps/{}/review-app", encode_segment(self.app_id))
     }
 }
 
@@ -137,7 +137,7 @@ impl<'a> HerokuEndpoint<Vec<ReviewApp>> for ReviewAppByPipelineList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/review-apps", self.pipeline_id)
+        format!("pipelines/{}/review-apps", encode_segment(self.pipeline_id))
     }
 }
 
@@ -183,6 +183,9 @@ impl<'a> HerokuEndpoint<ReviewAppConfig> for ReviewAppConfigDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/review-app-config", self.pipeline_id)
+        format!(
+            "pipelines/{}/review-app-config",
+            encode_segment(self.pipeline_id)
+        )
     }
 }
@@ -15,11 +15,19 @@ pub use post::{
     ReviewAppConfigEnable, ReviewAppConfigEnableParams, ReviewAppCreate, ReviewAppCreateParams,
 };
 
-impl ApiResult for ReviewApp {}
-impl ApiResult for Vec<ReviewApp> {}
+impl ApiResult for ReviewApp {
+    const NAME: &'static str = "ReviewApp";
+}
+impl ApiResult for Vec<ReviewApp> {
+    const NAME: &'static str = "Vec<ReviewApp>";
+}
 
-impl ApiResult for ReviewAppConfig {}
-impl ApiResult for Vec<ReviewAppConfig> {}
+impl ApiResult for ReviewAppConfig {
+    const NAME: &'static str = "ReviewAppConfig";
+}
+impl ApiResult for Vec<ReviewAppConfig> {
+    const NAME: &'static str = "Vec<ReviewAppConfig>";
+}
 
 pub use review_app::ReviewApp;
 pub use review_app_config::ReviewAppConfig;
@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for review app and it's properties goes here.
 use super::ReviewAppConfig;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Review App Configuration Update
 ///
@@ -162,7 +162,10 @@ impl<'a> HerokuEndpoint<ReviewAppConfig, (), ReviewAppConfigUpdateParams<'a>>
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/review-app-config", self.pipeline_id)
+        format!(
+            "pipelines/{}/review-app-config",
+            encode_segment(self.pipeline_id)
+        )
     }
     fn body(&self) -> Option<ReviewAppConfigUpdateParams<'a>> {
         Some(self.params.clone())
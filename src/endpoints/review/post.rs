@@ -2,7 +2,7 @@
 use super::{ReviewApp, ReviewAppConfig};
 use std::collections::HashMap;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Review App Create
 ///
@@ -334,7 +334,10 @@ impl<'a> HerokuEndpoint<ReviewAppConfig, (), ReviewAppConfigEnableParams<'a>>
         Method::Post
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/review-app-config", self.pipeline_id)
+        format!(
+            "pipelines/{}/review-app-config",
+            encode_segment(self.pipeline_id)
+        )
     }
     fn body(&self) -> Option<ReviewAppConfigEnableParams<'a>> {
         Some(self.params.clone())
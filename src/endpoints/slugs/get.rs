@@ -1,7 +1,7 @@
 //Anything related to GET requests for slugs and it's variations goes here.
 use super::Slug;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Slug Info
 ///
@@ -47,6 +47,10 @@ impl<'a> HerokuEndpoint<Slug> for SlugDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("apps/{}/slugs/{}", self.app_id, self.slug_id)
+        format!(
+            "apps/{}/slugs/{}",
+            encode_segment(self.app_id),
+            encode_segment(self.slug_id)
+        )
     }
 }
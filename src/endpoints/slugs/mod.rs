@@ -13,8 +13,12 @@ pub mod put;
 pub use get::SlugDetails;
 pub use post::{SlugCreate, SlugCreateParams};
 
-impl ApiResult for Slug {}
-impl ApiResult for Vec<Slug> {}
+impl ApiResult for Slug {
+    const NAME: &'static str = "Slug";
+}
+impl ApiResult for Vec<Slug> {
+    const NAME: &'static str = "Vec<Slug>";
+}
 
 /// Slug
 ///
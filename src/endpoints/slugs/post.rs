@@ -1,7 +1,7 @@
 //Anything related to POST requests for slugs and it's variations goes here.
 use super::Slug;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 use std::collections::HashMap;
 
 /// Create Slug
@@ -140,7 +140,7 @@ impl<'a> HerokuEndpoint<Slug, (), SlugCreateParams<'a>> for SlugCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("apps/{}/slugs", self.app_id)
+        format!("apps/{}/slugs", encode_segment(self.app_id))
     }
     fn body(&self) -> Option<SlugCreateParams<'a>> {
         Some(self.params.clone())
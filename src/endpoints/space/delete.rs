@@ -1,7 +1,7 @@
 //Anything related to DELETE requests for spaces goes here.
 use super::{Space, VPN};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Space Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Space> for SpaceDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("spaces/{}", self.space_id)
+        format!("spaces/{}", encode_segment(self.space_id))
     }
 }
 
@@ -93,6 +93,10 @@ impl<'a> HerokuEndpoint<VPN> for VPNDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("spaces/{}/vpn-connections/{}", self.space_id, self.vpn_id)
+        format!(
+            "spaces/{}/vpn-connections/{}",
+            encode_segment(self.space_id),
+            encode_segment(self.vpn_id)
+        )
     }
 }
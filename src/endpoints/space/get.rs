@@ -1,7 +1,7 @@
 //Anything related to GET requests for spaces goes here.
 use super::{InboundRuleset, OutboundRuleset, Space, SpaceAccess, SpaceNAT, VPN};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Space Info
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Space> for SpaceDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}", self.space_id)
+        format!("spaces/{}", encode_segment(self.space_id))
     }
 }
 
@@ -139,7 +139,11 @@ impl<'a> HerokuEndpoint<SpaceAccess> for SpaceAccessDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/members/{}", self.space_id, self.account_id)
+        format!(
+            "spaces/{}/members/{}",
+            encode_segment(self.space_id),
+            encode_segment(self.account_id)
+        )
     }
 }
 
@@ -185,7 +189,7 @@ impl<'a> HerokuEndpoint<Vec<SpaceAccess>> for SpaceAccessList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/members", self.space_id)
+        format!("spaces/{}/members", encode_segment(self.space_id))
     }
 }
 
@@ -231,7 +235,7 @@ impl<'a> HerokuEndpoint<SpaceNAT> for SpaceNATDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/nat", self.space_id)
+        format!("spaces/{}/nat", encode_segment(self.space_id))
     }
 }
 
@@ -277,7 +281,7 @@ impl<'a> HerokuEndpoint<InboundRuleset> for InboundRulesetCurrent<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/inbound-ruleset", self.space_id)
+        format!("spaces/{}/inbound-ruleset", encode_segment(self.space_id))
     }
 }
 
@@ -330,7 +334,8 @@ impl<'a> HerokuEndpoint<InboundRuleset> for InboundRulesetDetails<'a> {
     fn path(&self) -> String {
         format!(
             "spaces/{}/inbound-rulesets/{}",
-            self.space_id, self.ruleset_id
+            encode_segment(self.space_id),
+            encode_segment(self.ruleset_id)
         )
     }
 }
@@ -377,7 +382,7 @@ impl<'a> HerokuEndpoint<Vec<InboundRuleset>> for InboundRulesetList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/inbound-rulesets", self.space_id)
+        format!("spaces/{}/inbound-rulesets", encode_segment(self.space_id))
     }
 }
 
@@ -423,7 +428,7 @@ impl<'a> HerokuEndpoint<OutboundRuleset> for OutboundRulesetCurrent<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/outbound-ruleset", self.space_id)
+        format!("spaces/{}/outbound-ruleset", encode_segment(self.space_id))
     }
 }
 
@@ -476,7 +481,8 @@ impl<'a> HerokuEndpoint<OutboundRuleset> for OutboundRulesetDetails<'a> {
     fn path(&self) -> String {
         format!(
             "spaces/{}/outbound-rulesets/{}",
-            self.space_id, self.ruleset_id
+            encode_segment(self.space_id),
+            encode_segment(self.ruleset_id)
         )
     }
 }
@@ -523,7 +529,7 @@ impl<'a> HerokuEndpoint<Vec<OutboundRuleset>> for OutboundRulesetList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/outbound-rulesets", self.space_id)
+        format!("spaces/{}/outbound-rulesets", encode_segment(self.space_id))
     }
 }
 
@@ -569,7 +575,7 @@ impl<'a> HerokuEndpoint<Vec<VPN>> for VPNList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/vpn-connections", self.space_id)
+        format!("spaces/{}/vpn-connections", encode_segment(self.space_id))
     }
 }
 
@@ -617,6 +623,10 @@ impl<'a> HerokuEndpoint<VPN> for VPNDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("spaces/{}/vpn-connections/{}", self.space_id, self.vpn_id)
+        format!(
+            "spaces/{}/vpn-connections/{}",
+            encode_segment(self.space_id),
+            encode_segment(self.vpn_id)
+        )
     }
 }
@@ -23,26 +23,54 @@ pub use put::{
 
 pub use delete::{SpaceDelete, VPNDelete};
 
-impl ApiResult for Space {}
-impl ApiResult for Vec<Space> {}
+impl ApiResult for Space {
+    const NAME: &'static str = "Space";
+}
+impl ApiResult for Vec<Space> {
+    const NAME: &'static str = "Vec<Space>";
+}
 
-impl ApiResult for SpaceAccess {}
-impl ApiResult for Vec<SpaceAccess> {}
+impl ApiResult for SpaceAccess {
+    const NAME: &'static str = "SpaceAccess";
+}
+impl ApiResult for Vec<SpaceAccess> {
+    const NAME: &'static str = "Vec<SpaceAccess>";
+}
 
-impl ApiResult for SpaceNAT {}
-impl ApiResult for Vec<SpaceNAT> {}
+impl ApiResult for SpaceNAT {
+    const NAME: &'static str = "SpaceNAT";
+}
+impl ApiResult for Vec<SpaceNAT> {
+    const NAME: &'static str = "Vec<SpaceNAT>";
+}
 
-impl ApiResult for SpaceTransfer {}
-impl ApiResult for Vec<SpaceTransfer> {}
+impl ApiResult for SpaceTransfer {
+    const NAME: &'static str = "SpaceTransfer";
+}
+impl ApiResult for Vec<SpaceTransfer> {
+    const NAME: &'static str = "Vec<SpaceTransfer>";
+}
 
-impl ApiResult for InboundRuleset {}
-impl ApiResult for Vec<InboundRuleset> {}
+impl ApiResult for InboundRuleset {
+    const NAME: &'static str = "InboundRuleset";
+}
+impl ApiResult for Vec<InboundRuleset> {
+    const NAME: &'static str = "Vec<InboundRuleset>";
+}
 
-impl ApiResult for OutboundRuleset {}
-impl ApiResult for Vec<OutboundRuleset> {}
+impl ApiResult for OutboundRuleset {
+    const NAME: &'static str = "OutboundRuleset";
+}
+impl ApiResult for Vec<OutboundRuleset> {
+    const NAME: &'static str = "Vec<OutboundRuleset>";
+}
 
-impl ApiResult for VPN {}
-impl ApiResult for Vec<VPN> {}
+impl ApiResult for VPN {
+    const NAME: &'static str = "VPN";
+}
+impl ApiResult for Vec<VPN> {
+    const NAME: &'static str = "Vec<VPN>";
+}
 
 pub use inbound_ruleset::InboundRuleset;
 pub use outbound_ruleset::OutboundRuleset;
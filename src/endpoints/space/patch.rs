@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for spaces goes here.
 use super::{Space, SpaceAccess};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Space Update
 ///
@@ -82,7 +82,7 @@ impl<'a> HerokuEndpoint<Space, (), SpaceUpdateParams<'a>> for SpaceUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("spaces/{}", self.space_id)
+        format!("spaces/{}", encode_segment(self.space_id))
     }
     fn body(&self) -> Option<SpaceUpdateParams<'a>> {
         Some(self.params.clone())
@@ -157,7 +157,11 @@ impl<'a> HerokuEndpoint<SpaceAccess, (), SpaceAccessUpdateParams<'a>> for SpaceA
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("spaces/{}/members/{}", self.space_id, self.account_id)
+        format!(
+            "spaces/{}/members/{}",
+            encode_segment(self.space_id),
+            encode_segment(self.account_id)
+        )
     }
     fn body(&self) -> Option<SpaceAccessUpdateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to POST requests for spaces goes here.
 use super::{Space, SpaceTransfer, VPN};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Space Create
 ///
@@ -190,7 +190,7 @@ impl<'a> HerokuEndpoint<SpaceTransfer, (), SpaceTransferCreateParams<'a>>
         Method::Post
     }
     fn path(&self) -> String {
-        format!("spaces/{}/transfer", self.space_id)
+        format!("spaces/{}/transfer", encode_segment(self.space_id))
     }
     fn body(&self) -> Option<SpaceTransferCreateParams<'a>> {
         Some(self.params.clone())
@@ -268,7 +268,7 @@ impl<'a> HerokuEndpoint<VPN, (), VPNCreateParams<'a>> for VPNCreate<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("spaces/{}/vpn-connections", self.space_id)
+        format!("spaces/{}/vpn-connections", encode_segment(self.space_id))
     }
     fn body(&self) -> Option<VPNCreateParams<'a>> {
         Some(self.params.clone())
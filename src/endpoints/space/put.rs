@@ -1,7 +1,7 @@
 //Anything related to PUT requests for spaces goes here.
 use super::{InboundRuleset, OutboundRuleset};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Inbound Ruleset Create
 ///
@@ -84,7 +84,7 @@ impl<'a> HerokuEndpoint<InboundRuleset, (), InboundRulesetCreateParams<'a>>
         Method::Put
     }
     fn path(&self) -> String {
-        format!("spaces/{}/inbound-ruleset", self.space_id)
+        format!("spaces/{}/inbound-ruleset", encode_segment(self.space_id))
     }
     fn body(&self) -> Option<InboundRulesetCreateParams<'a>> {
         Some(self.params.clone())
@@ -191,7 +191,7 @@ impl<'a> HerokuEndpoint<OutboundRuleset, (), OutboundRulesetCreateParams<'a>>
         Method::Put
     }
     fn path(&self) -> String {
-        format!("spaces/{}/inbound-ruleset", self.space_id)
+        format!("spaces/{}/outbound-ruleset", encode_segment(self.space_id))
     }
     fn body(&self) -> Option<OutboundRulesetCreateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to GET requests for Teams and it's variations goes here.
 use super::{Team, TeamInvitation, TeamMember};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team Delete
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Team> for TeamDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("teams/{}", self.team_id)
+        format!("teams/{}", encode_segment(self.team_id))
     }
 }
 
@@ -96,7 +96,11 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationRevoke<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("teams/{}/invitations/{}", self.team_id, self.invitation_id)
+        format!(
+            "teams/{}/invitations/{}",
+            encode_segment(self.team_id),
+            encode_segment(self.invitation_id)
+        )
     }
 }
 
@@ -144,6 +148,10 @@ impl<'a> HerokuEndpoint<TeamMember> for TeamMemberDelete<'a> {
         Method::Delete
     }
     fn path(&self) -> String {
-        format!("teams/{}/members/{}", self.team_id, self.member_id)
+        format!(
+            "teams/{}/members/{}",
+            encode_segment(self.team_id),
+            encode_segment(self.member_id)
+        )
     }
 }
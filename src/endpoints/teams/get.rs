@@ -4,7 +4,7 @@ use super::{
     TeamPreferences,
 };
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team Info
 ///
@@ -48,7 +48,7 @@ impl<'a> HerokuEndpoint<Team> for TeamDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}", self.team_id)
+        format!("teams/{}", encode_segment(self.team_id))
     }
 }
 
@@ -136,7 +136,10 @@ impl<'a> HerokuEndpoint<Vec<Team>> for TeamListByEA<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("enterprise-accounts/{}/teams", self.account_id)
+        format!(
+            "enterprise-accounts/{}/teams",
+            encode_segment(self.account_id)
+        )
     }
 }
 
@@ -181,7 +184,7 @@ impl<'a> HerokuEndpoint<TeamApp> for TeamAppDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}", self.app_id)
+        format!("teams/apps/{}", encode_segment(self.app_id))
     }
 }
 
@@ -226,7 +229,7 @@ impl<'a> HerokuEndpoint<Vec<TeamApp>> for TeamAppList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}", self.team_id)
+        format!("teams/apps/{}", encode_segment(self.team_id))
     }
 }
 
@@ -315,7 +318,7 @@ impl<'a> HerokuEndpoint<Vec<TeamFeature>> for TeamFeatureList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/features", self.team_id)
+        format!("teams/{}/features", encode_segment(self.team_id))
     }
 }
 
@@ -366,7 +369,11 @@ impl<'a> HerokuEndpoint<TeamFeature> for TeamFeatureDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/features/{}", self.team_id, self.feature_id)
+        format!(
+            "teams/{}/features/{}",
+            encode_segment(self.team_id),
+            encode_segment(self.feature_id)
+        )
     }
 }
 
@@ -412,7 +419,7 @@ impl<'a> HerokuEndpoint<Vec<TeamInvitation>> for TeamInvitationList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/invitations", self.team_id)
+        format!("teams/{}/invitations", encode_segment(self.team_id))
     }
 }
 
@@ -458,7 +465,7 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/invitations/{}", self.token_id)
+        format!("teams/invitations/{}", encode_segment(self.token_id))
     }
 }
 
@@ -504,7 +511,7 @@ impl<'a> HerokuEndpoint<Vec<TeamInvoice>> for TeamInvoiceList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/invoices", self.team_id)
+        format!("teams/{}/invoices", encode_segment(self.team_id))
     }
 }
 
@@ -555,7 +562,11 @@ impl<'a> HerokuEndpoint<TeamInvoice> for TeamInvoiceDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/invoices/{}", self.team_id, self.invoice_id)
+        format!(
+            "teams/{}/invoices/{}",
+            encode_segment(self.team_id),
+            encode_segment(self.invoice_id)
+        )
     }
 }
 
@@ -601,7 +612,7 @@ impl<'a> HerokuEndpoint<Vec<TeamMember>> for TeamMemberList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/members", self.team_id)
+        format!("teams/{}/members", encode_segment(self.team_id))
     }
 }
 
@@ -649,7 +660,11 @@ impl<'a> HerokuEndpoint<Vec<TeamApp>> for TeamMemberAppsList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/members/{}/apps", self.team_id, self.member_id)
+        format!(
+            "teams/{}/members/{}/apps",
+            encode_segment(self.team_id),
+            encode_segment(self.member_id)
+        )
     }
 }
 
@@ -695,6 +710,6 @@ impl<'a> HerokuEndpoint<TeamPreferences> for TeamPreferenceList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("teams/{}/preferences", self.id)
+        format!("teams/{}/preferences", encode_segment(self.id))
     }
 }
@@ -27,28 +27,58 @@ pub use put::{
     TeamMemberCreateorUpdateParams,
 };
 
-impl ApiResult for Team {}
-impl ApiResult for Vec<Team> {}
+impl ApiResult for Team {
+    const NAME: &'static str = "Team";
+}
+impl ApiResult for Vec<Team> {
+    const NAME: &'static str = "Vec<Team>";
+}
 
-impl ApiResult for TeamApp {}
-impl ApiResult for Vec<TeamApp> {}
+impl ApiResult for TeamApp {
+    const NAME: &'static str = "TeamApp";
+}
+impl ApiResult for Vec<TeamApp> {
+    const NAME: &'static str = "Vec<TeamApp>";
+}
 
-impl ApiResult for Vec<TeamAppPermission> {}
+impl ApiResult for Vec<TeamAppPermission> {
+    const NAME: &'static str = "Vec<TeamAppPermission>";
+}
 
-impl ApiResult for TeamFeature {}
-impl ApiResult for Vec<TeamFeature> {}
+impl ApiResult for TeamFeature {
+    const NAME: &'static str = "TeamFeature";
+}
+impl ApiResult for Vec<TeamFeature> {
+    const NAME: &'static str = "Vec<TeamFeature>";
+}
 
-impl ApiResult for TeamInvitation {}
-impl ApiResult for Vec<TeamInvitation> {}
+impl ApiResult for TeamInvitation {
+    const NAME: &'static str = "TeamInvitation";
+}
+impl ApiResult for Vec<TeamInvitation> {
+    const NAME: &'static str = "Vec<TeamInvitation>";
+}
 
-impl ApiResult for TeamInvoice {}
-impl ApiResult for Vec<TeamInvoice> {}
+impl ApiResult for TeamInvoice {
+    const NAME: &'static str = "TeamInvoice";
+}
+impl ApiResult for Vec<TeamInvoice> {
+    const NAME: &'static str = "Vec<TeamInvoice>";
+}
 
-impl ApiResult for TeamMember {}
-impl ApiResult for Vec<TeamMember> {}
+impl ApiResult for TeamMember {
+    const NAME: &'static str = "TeamMember";
+}
+impl ApiResult for Vec<TeamMember> {
+    const NAME: &'static str = "Vec<TeamMember>";
+}
 
-impl ApiResult for TeamPreferences {}
-impl ApiResult for Vec<TeamPreferences> {}
+impl ApiResult for TeamPreferences {
+    const NAME: &'static str = "TeamPreferences";
+}
+impl ApiResult for Vec<TeamPreferences> {
+    const NAME: &'static str = "Vec<TeamPreferences>";
+}
 
 pub use team::Team;
 pub use team_app::TeamApp;
@@ -1,7 +1,7 @@
 //Anything related to PATCH requests for Teams and it's variations goes here.
 use super::{Team, TeamApp, TeamMember, TeamPreferences};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team Update
 ///
@@ -92,7 +92,7 @@ impl<'a> HerokuEndpoint<Team, (), TeamUpdateParams<'a>> for TeamUpdate<'a> {
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/{}", self.team_id)
+        format!("teams/{}", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamUpdateParams<'a>> {
         Some(self.params.clone())
@@ -156,7 +156,7 @@ impl<'a> HerokuEndpoint<Team, (), TeamAppUpdateLockedParams> for TeamAppUpdateLo
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}", self.team_id)
+        format!("teams/apps/{}", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamAppUpdateLockedParams> {
         Some(self.params.clone())
@@ -228,7 +228,7 @@ impl<'a> HerokuEndpoint<TeamApp, (), TeamAppTransferParams<'a>> for TeamAppTrans
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/apps/{}", self.team_id)
+        format!("teams/apps/{}", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamAppTransferParams<'a>> {
         Some(self.params.clone())
@@ -320,7 +320,7 @@ impl<'a> HerokuEndpoint<TeamMember, (), TeamMemberUpdateParams<'a>> for TeamMemb
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/{}/members", self.team_id)
+        format!("teams/{}/members", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamMemberUpdateParams<'a>> {
         Some(self.params.clone())
@@ -401,7 +401,7 @@ impl<'a> HerokuEndpoint<TeamPreferences, (), TeamPreferenceUpdateParams> for Tea
         Method::Patch
     }
     fn path(&self) -> String {
-        format!("teams/{}/preferences", self.id)
+        format!("teams/{}/preferences", encode_segment(self.id))
     }
     fn body(&self) -> Option<TeamPreferenceUpdateParams> {
         Some(self.params.clone())
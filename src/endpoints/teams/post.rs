@@ -1,7 +1,7 @@
 //Anything related to POST requests for Teams and it's variations goes here.
 use super::{Team, TeamApp, TeamInvitation, TeamMember};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team Create
 ///
@@ -301,7 +301,10 @@ impl<'a> HerokuEndpoint<Team, (), TeamCreateByEAParams<'a>> for TeamCreateByEA<'
         Method::Post
     }
     fn path(&self) -> String {
-        format!("enterprise-accounts/{}/teams", self.account_id)
+        format!(
+            "enterprise-accounts/{}/teams",
+            encode_segment(self.account_id)
+        )
     }
     fn body(&self) -> Option<TeamCreateByEAParams<'a>> {
         Some(self.params.clone())
@@ -516,7 +519,7 @@ impl<'a> HerokuEndpoint<TeamInvitation> for TeamInvitationAccept<'a> {
         Method::Post
     }
     fn path(&self) -> String {
-        format!("teams/invitations/{}/accept", self.token_id)
+        format!("teams/invitations/{}/accept", encode_segment(self.token_id))
     }
 }
 
@@ -605,7 +608,7 @@ impl<'a> HerokuEndpoint<TeamMember, (), TeamMemberCreateParams<'a>> for TeamMemb
         Method::Post
     }
     fn path(&self) -> String {
-        format!("teams/{}/members", self.team_id)
+        format!("teams/{}/members", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamMemberCreateParams<'a>> {
         Some(self.params.clone())
@@ -1,7 +1,7 @@
 //Anything related to PUT requests for Teams and it's variations goes here.
 use super::{TeamInvitation, TeamMember};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Team Invitation Create
 ///
@@ -92,7 +92,7 @@ impl<'a> HerokuEndpoint<TeamInvitation, (), TeamInvitationCreateParams<'a>>
         Method::Put
     }
     fn path(&self) -> String {
-        format!("teams/{}/invitations", self.team_id)
+        format!("teams/{}/invitations", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamInvitationCreateParams<'a>> {
         Some(self.params.clone())
@@ -190,7 +190,7 @@ impl<'a> HerokuEndpoint<TeamMember, (), TeamMemberCreateorUpdateParams<'a>>
         Method::Put
     }
     fn path(&self) -> String {
-        format!("teams/{}/members", self.team_id)
+        format!("teams/{}/members", encode_segment(self.team_id))
     }
     fn body(&self) -> Option<TeamMemberCreateorUpdateParams<'a>> {
         Some(self.params.clone())
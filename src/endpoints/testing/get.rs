@@ -1,7 +1,7 @@
 //Anything related to GET requests for Heroku tests goes here.
 use super::{TestCase, TestNode, TestRun};
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Test Case List
 ///
@@ -45,7 +45,7 @@ impl<'a> HerokuEndpoint<Vec<TestCase>> for TestCaseList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("test-runs/{}/test-cases", self.run_id)
+        format!("test-runs/{}/test-cases", encode_segment(self.run_id))
     }
 }
 
@@ -91,7 +91,7 @@ impl<'a> HerokuEndpoint<Vec<TestNode>> for TestNodeList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("test-runs/{}/test-nodes", self.run_id)
+        format!("test-runs/{}/test-nodes", encode_segment(self.run_id))
     }
 }
 
@@ -137,7 +137,7 @@ impl<'a> HerokuEndpoint<TestRun> for TestRunDetails<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("test-runs/{}", self.run_id)
+        format!("test-runs/{}", encode_segment(self.run_id))
     }
 }
 
@@ -183,7 +183,7 @@ impl<'a> HerokuEndpoint<Vec<TestRun>> for TestRunList<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/test-runs", self.pipeline_id)
+        format!("pipelines/{}/test-runs", encode_segment(self.pipeline_id))
     }
 }
 
@@ -234,6 +234,10 @@ impl<'a> HerokuEndpoint<TestRun> for TestRunDetailsByPipeline<'a> {
         Method::Get
     }
     fn path(&self) -> String {
-        format!("pipelines/{}/test-runs/{}", self.pipeline_id, self.run_id)
+        format!(
+            "pipelines/{}/test-runs/{}",
+            encode_segment(self.pipeline_id),
+            encode_segment(self.run_id)
+        )
     }
 }
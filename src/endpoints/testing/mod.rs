@@ -10,14 +10,26 @@ pub use get::{TestCaseList, TestNodeList, TestRunDetails, TestRunDetailsByPipeli
 pub use patch::{TestRunUpdate, TestRunUpdateParams};
 pub use post::{TestRunCreate, TestRunCreateParams};
 
-impl ApiResult for TestCase {}
-impl ApiResult for Vec<TestCase> {}
+impl ApiResult for TestCase {
+    const NAME: &'static str = "TestCase";
+}
+impl ApiResult for Vec<TestCase> {
+    const NAME: &'static str = "Vec<TestCase>";
+}
 
-impl ApiResult for TestNode {}
-impl ApiResult for Vec<TestNode> {}
+impl ApiResult for TestNode {
+    const NAME: &'static str = "TestNode";
+}
+impl ApiResult for Vec<TestNode> {
+    const NAME: &'static str = "Vec<TestNode>";
+}
 
-impl ApiResult for TestRun {}
-impl ApiResult for Vec<TestRun> {}
+impl ApiResult for TestRun {
+    const NAME: &'static str = "TestRun";
+}
+impl ApiResult for Vec<TestRun> {
+    const NAME: &'static str = "Vec<TestRun>";
+}
 
 pub use test_case::TestCase;
 pub use test_node::TestNode;
@@ -1,7 +1,7 @@
 //Anything related to POST requests for Heroku tests goes here.
 use super::TestRun;
 
-use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::endpoint::{encode_segment, HerokuEndpoint, Method};
 
 /// Test Run Update
 ///
@@ -63,7 +63,7 @@ impl<'a> HerokuEndpoint<TestRun, (), TestRunUpdateParams<'a>> for TestRunUpdate<
         Method::Post
     }
     fn path(&self) -> String {
-        format!("test-runs/{}", self.run_id)
+        format!("test-runs/{}", encode_segment(self.run_id))
     }
     fn body(&self) -> Option<TestRunUpdateParams<'a>> {
         Some(self.params.clone())
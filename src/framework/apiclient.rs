@@ -1,9 +1,13 @@
-//! This module contains the synchronous (blocking) API client.
+//! This module contains the synchronous (blocking) API client trait, and the asynchronous one when the `async` feature is enabled.
 use crate::framework::{
-    endpoint::HerokuEndpoint,
-    response::{ApiResponse, ApiResult},
+    endpoint::{ExtraHeaders, HerokuEndpoint},
+    range,
+    response::{parse_response, parse_response_with_meta, ApiResponse, ApiResult, WithMeta},
 };
+use http::header::{HeaderValue, RANGE};
 use serde::Serialize;
+#[cfg(feature = "async")]
+use std::future::Future;
 
 /// Synchronous heroku client
 pub trait HerokuApiClient {
@@ -19,15 +23,143 @@ pub trait HerokuApiClient {
         QueryType: Serialize,
         BodyType: Serialize;
 
-    /// This returns a Result<reqwest::blocking::Response, heroku_rs::framework::response::error::HerokuApiFailure>
+    /// This returns a parsed Result<WithMeta<T>, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// Like `request`, but the parsed body comes with the status and the Heroku headers of the response,
+    /// e.g. the `Request-Id` to quote to Heroku support, or `RateLimit-Remaining` to keep an eye on the quota.
+    ///
+    /// By default the response of `request_raw` is parsed.
+    fn request_with_meta<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<WithMeta<ResultType>>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        parse_response_with_meta(self.request_raw(endpoint)?)
+    }
+
+    /// This returns a Result<http::Response<Vec<u8>>, heroku_rs::framework::response::error::HerokuApiFailure>
     ///
     /// This is primarily used for debugging and testing, but can be used if this works better for your use-case.
+    /// The response has its whole body, whatever its status; parse it with [`parse_response`](../response/fn.parse_response.html).
+    /// `HttpApiClient` keeps the url it was received from in its extensions, `response.extensions().get::<url::Url>()`.
     fn request_raw<ResultType, QueryType, BodyType>(
         &self,
         endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
-    ) -> ApiResponse<reqwest::blocking::Response>
+    ) -> ApiResponse<http::Response<Vec<u8>>>
     where
         ResultType: ApiResult,
         QueryType: Serialize,
         BodyType: Serialize;
+
+    /// This returns every item of a list endpoint, e.g. `AppList`, as a parsed Result<Vec<T>, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// Heroku returns at most 200 items per request, so this keeps requesting the next page through the `Next-Range` header until there is none left,
+    /// and returns the concatenated items.
+    ///
+    /// By default every page is requested with `request_raw`.
+    fn request_all<ItemType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    ) -> ApiResponse<Vec<ItemType>>
+    where
+        Vec<ItemType>: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut items = Vec::new();
+        let mut page_range: Option<HeaderValue> = None;
+
+        loop {
+            let response = match &page_range {
+                Some(page_range) => self.request_raw::<Vec<ItemType>, QueryType, BodyType>(
+                    &endpoint.with_header(RANGE, page_range.clone()),
+                )?,
+                None => self.request_raw(endpoint)?,
+            };
+            let next = range::next_range(response.status(), response.headers());
+            let page: Vec<ItemType> = parse_response(response)?;
+            items.extend(page);
+
+            match next {
+                Some(next) if page_range.as_ref() != Some(&next) => page_range = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+/// Asynchronous heroku client
+///
+/// This is the async counterpart of [`HerokuApiClient`](trait.HerokuApiClient.html), it takes the same endpoints.
+///
+/// The request is fully built before the returned future is polled, so the future does not borrow the endpoint
+/// and can be sent across threads, e.g. with `tokio::spawn`.
+///
+/// Its methods return `impl Future`, which needs Rust 1.75, the `rust-version` of this crate.
+#[cfg(feature = "async")]
+pub trait AsyncHerokuApiClient {
+    /// This returns a future resolving to a parsed Result<T, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// Use this as the main method to interact with the Heroku API
+    fn request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<ResultType>> + Send
+    where
+        ResultType: ApiResult + Send,
+        QueryType: Serialize,
+        BodyType: Serialize;
+
+    /// This returns a future resolving to a parsed Result<WithMeta<T>, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// Like `request`, but the parsed body comes with the status and the Heroku headers of the response,
+    /// e.g. the `Request-Id` to quote to Heroku support, or `RateLimit-Remaining` to keep an eye on the quota.
+    ///
+    /// By default the response of `request_raw` is parsed.
+    fn request_with_meta<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<WithMeta<ResultType>>> + Send
+    where
+        ResultType: ApiResult + Send,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let response = self.request_raw(endpoint);
+
+        async move { parse_response_with_meta(response.await?) }
+    }
+
+    /// This returns a future resolving to a Result<http::Response<Vec<u8>>, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// This is primarily used for debugging and testing, but can be used if this works better for your use-case.
+    /// `AsyncHttpApiClient` keeps the url it was received from in its extensions, `response.extensions().get::<url::Url>()`.
+    fn request_raw<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<http::Response<Vec<u8>>>> + Send
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize;
+
+    /// This returns a future resolving to every item of a list endpoint, e.g. `AppList`, as a parsed Result<Vec<T>, heroku_rs::framework::response::error::HerokuApiFailure>
+    ///
+    /// Heroku returns at most 200 items per request, so this keeps requesting the next page through the `Next-Range` header until there is none left,
+    /// and returns the concatenated items.
+    fn request_all<ItemType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<Vec<ItemType>>> + Send
+    where
+        Vec<ItemType>: ApiResult,
+        ItemType: Send,
+        QueryType: Serialize,
+        BodyType: Serialize;
 }
@@ -0,0 +1,354 @@
+//! This module contains the asynchronous API client, enabled by the `async` feature.
+#[cfg(feature = "tracing")]
+use crate::framework::trace;
+use crate::framework::{
+    apiclient::AsyncHerokuApiClient,
+    auth::Credentials,
+    endpoint::HerokuEndpoint,
+    pipeline::{Next, Pages, Pipeline},
+    request::clone_request,
+    reqwest_utils::{
+        async_client, into_async_http_response, into_async_reqwest_request, transport_failure,
+    },
+    response::{set_url, ApiResponse, ApiResult, HerokuApiFailure, WithMeta},
+    transport::Transport,
+    ApiEnvironment, HttpApiClientConfig,
+};
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The asynchronous client used to make requests to Heroku.
+///
+/// This is the async counterpart of [`HttpApiClient`](struct.HttpApiClient.html), built on top of `reqwest::Client`.
+/// It calls the same middlewares, and sends the requests with the `transport` of its configuration when there is one.
+///
+/// Like it, clones and sub-clients share the connection pool, the credentials, the rate limiter and the cache.
+#[derive(Clone)]
+pub struct AsyncHttpApiClient {
+    /// Builds the requests, and runs them through the cache, the middlewares and the retries
+    pipeline: Pipeline,
+    /// The async client
+    http_client: reqwest::Client,
+    /// Sends the requests instead of the async client, on the blocking thread pool of tokio
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl AsyncHttpApiClient {
+    /// # Example 1:
+    /// Creating a simple async client with the defaults. This has the production Heroku endpoint, 30 seconds timeout and the standard api key authentication.
+    /// ```rust
+    /// use heroku_rs::prelude::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///    let api_client = AsyncHttpApiClient::create("API_KEY")?;
+    ///
+    ///    // you can start making requests here, e.g. `api_client.request(&AppList::new()).await`
+    ///
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn create(token: &str) -> Result<AsyncHttpApiClient, HerokuApiFailure> {
+        let credentials = Credentials::UserAuthToken {
+            token: String::from(token),
+        };
+        AsyncHttpApiClient::new(
+            credentials,
+            HttpApiClientConfig::default(),
+            ApiEnvironment::Production,
+        )
+    }
+
+    /// # Example 2:
+    /// Creating a custom async client in which you can specify the custom endpoint, timeouts and custom credentials.
+    ///
+    /// This takes the same [`HttpApiClientConfig`](struct.HttpApiClientConfig.html) as the blocking client.
+    /// ```rust
+    /// use heroku_rs::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///    let credentials = Credentials::UserAuthToken {
+    ///         token: String::from("API_KEY"),
+    ///    };
+    ///
+    ///   let api_client = AsyncHttpApiClient::new(
+    ///    credentials,
+    ///    HttpApiClientConfig {
+    ///        http_timeout: Duration::from_secs(10),
+    ///        ..HttpApiClientConfig::default()
+    ///    },
+    ///    ApiEnvironment::Custom(url::Url::parse("https://api.custom-somewhere.com/").unwrap()))?;
+    ///
+    ///    // you can start making requests here with api_client
+    ///
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        credentials: Credentials,
+        config: HttpApiClientConfig,
+        environment: ApiEnvironment,
+    ) -> Result<AsyncHttpApiClient, HerokuApiFailure> {
+        let http_client = async_client(&config)?;
+
+        Ok(AsyncHttpApiClient {
+            http_client,
+            transport: config.transport.clone(),
+            pipeline: Pipeline::new(credentials, config, environment),
+        })
+    }
+
+    /// The credentials the client sends now, e.g. after it refreshed an OAuth token.
+    pub fn credentials(&self) -> Credentials {
+        self.pipeline.credentials.current().clone()
+    }
+
+    /// Replace the credentials of this client, of its clones and of the sub-clients sharing them.
+    ///
+    /// Requests already sent keep the previous credentials, the following ones use these.
+    pub fn set_credentials(&self, credentials: Credentials) {
+        self.pipeline.credentials.replace(credentials);
+    }
+
+    /// A client sending the requests to another environment, with the same connection pool and credentials.
+    pub fn with_environment(&self, environment: ApiEnvironment) -> AsyncHttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.environment = environment;
+        client
+    }
+
+    /// A client with its own credentials, e.g. those of another account, and the same connection pool.
+    ///
+    /// It has no response cache, since the cached responses belong to the account of this client.
+    pub fn with_credentials(&self, credentials: Credentials) -> AsyncHttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.credentials =
+            Arc::new(self.pipeline.credentials.with_credentials(credentials));
+        client.pipeline.cache = None;
+        client
+    }
+
+    /// A client also sending these headers with every request, unless the endpoint sets them.
+    pub fn with_headers(&self, headers: http::HeaderMap) -> AsyncHttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.headers.extend(headers);
+        client
+    }
+
+    /// Send the request and parse the response, going through the response cache if there is one.
+    async fn send<ResultType: ApiResult>(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> ApiResponse<WithMeta<ResultType>> {
+        let lookup = self.pipeline.cache_lookup(&mut request);
+        let response = self.execute(request).await?;
+        self.pipeline.parse(lookup, response)
+    }
+
+    /// Send the request in a `heroku_request` span recording its outcome.
+    #[cfg(feature = "tracing")]
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        use tracing::Instrument;
+
+        let span = trace::request_span(&request);
+        let start = std::time::Instant::now();
+        let result = self.dispatch(request).instrument(span.clone()).await;
+        trace::record_result(&span, &result, start.elapsed());
+        result
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.dispatch(request).await
+    }
+
+    /// Send the request through the middlewares.
+    async fn dispatch(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        if !self.pipeline.has_middlewares() {
+            return self.execute_with_retries(request).await;
+        }
+
+        let (called, answer) = self.pipeline.before_send(&mut request);
+        let sent = clone_request(&request);
+        let mut response = match answer {
+            Some(response) => response,
+            None => self.execute_with_retries(request).await?,
+        };
+        self.pipeline.after_receive(called, &sent, &mut response);
+        Ok(response)
+    }
+
+    /// Send the request, waiting for the rate limiter and retrying it as long as the retry policy allows.
+    ///
+    /// OAuth credentials are refreshed before they expire, and once if Heroku rejects them.
+    async fn execute_with_retries(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        let mut attempts = self.pipeline.attempts(&request);
+
+        loop {
+            if self.refresh_credentials(None).await? {
+                self.pipeline
+                    .credentials
+                    .authenticate(request.headers_mut());
+            }
+
+            let (retry_request, wait) = attempts.start(&request);
+            if wait > Duration::from_secs(0) {
+                tokio::time::sleep(wait).await;
+            }
+            let response = self.http_send(request).await?;
+
+            request = match attempts.next(response, retry_request) {
+                Next::Done(response) => return Ok(response),
+                Next::Reauthenticate(mut retry_request) => {
+                    self.refresh_credentials(Some(retry_request.headers()))
+                        .await?;
+                    self.pipeline
+                        .credentials
+                        .authenticate(retry_request.headers_mut());
+                    retry_request
+                }
+                Next::Retry(retry_request, backoff) => {
+                    tokio::time::sleep(backoff).await;
+                    retry_request
+                }
+            };
+        }
+    }
+
+    /// Send the request with the transport or the reqwest client, without retrying it.
+    async fn http_send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        if let Some(transport) = &self.transport {
+            // Transports block, they must not hold up the other tasks of the runtime.
+            let transport = transport.clone();
+            let uri = request.uri().clone();
+            let mut response = tokio::task::spawn_blocking(move || transport.send(request))
+                .await
+                .map_err(|error| HerokuApiFailure::Transport(Box::new(error)))??;
+            set_url(&mut response, &uri);
+            return Ok(response);
+        }
+
+        let request = into_async_reqwest_request(request)?;
+        let response = self
+            .http_client
+            .execute(request)
+            .await
+            .map_err(transport_failure)?;
+        into_async_http_response(response).await
+    }
+
+    /// Refresh OAuth credentials about to expire, or OAuth credentials Heroku `rejected` in the headers of a request.
+    ///
+    /// Concurrent requests refresh them once, the others wait for the new token.
+    /// Returns whether the credentials changed.
+    #[cfg(feature = "oauth")]
+    async fn refresh_credentials(
+        &self,
+        rejected: Option<&http::HeaderMap>,
+    ) -> Result<bool, HerokuApiFailure> {
+        if self.pipeline.credentials.oauth_refresh(rejected).is_none() {
+            return Ok(false);
+        }
+        let _refreshing = self.pipeline.credentials.lock_refresh_async().await;
+        // Another request may have refreshed the credentials while this one waited.
+        if let Some(refresh) = self.pipeline.credentials.oauth_refresh(rejected) {
+            let request = self.pipeline.refresh_request(&refresh)?;
+            self.pipeline
+                .refreshed(&refresh, self.http_send(request).await?)?;
+        }
+        Ok(true)
+    }
+
+    #[cfg(not(feature = "oauth"))]
+    async fn refresh_credentials(
+        &self,
+        _rejected: Option<&http::HeaderMap>,
+    ) -> Result<bool, HerokuApiFailure> {
+        Ok(false)
+    }
+}
+
+impl AsyncHerokuApiClient for AsyncHttpApiClient {
+    /// Asynchronously send a request to the Heroku API.
+    fn request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<ResultType>> + Send
+    where
+        ResultType: ApiResult + Send,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let request = self.pipeline.build_request(endpoint);
+
+        async move { Ok(self.send(request?).await?.result) }
+    }
+
+    fn request_with_meta<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<WithMeta<ResultType>>> + Send
+    where
+        ResultType: ApiResult + Send,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let request = self.pipeline.build_request(endpoint);
+
+        async move { self.send(request?).await }
+    }
+
+    fn request_raw<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<http::Response<Vec<u8>>>> + Send
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let request = self.pipeline.build_request(endpoint);
+
+        async move { self.execute(request?).await }
+    }
+
+    fn request_all<ItemType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    ) -> impl Future<Output = ApiResponse<Vec<ItemType>>> + Send
+    where
+        Vec<ItemType>: ApiResult,
+        ItemType: Send,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let request = self.pipeline.build_request(endpoint);
+
+        async move {
+            let mut pages = Pages::new(request?);
+            let mut items = Vec::new();
+            while let Some(request) = pages.next_request() {
+                pages.receive(self.execute(request).await?, &mut items)?;
+            }
+
+            Ok(items)
+        }
+    }
+}
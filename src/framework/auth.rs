@@ -1,15 +1,367 @@
+#[cfg(feature = "oauth")]
+use crate::endpoints::oauth::{
+    post::{Client, Grant, RefreshToken},
+    OAuthToken, OAuthTokenCreate, OAuthTokenCreateParams,
+};
+use crate::framework::netrc::{Netrc, NetrcError};
+#[cfg(feature = "reqwest")]
 use reqwest::blocking::RequestBuilder;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+#[cfg(feature = "oauth")]
+use std::sync::{Mutex, MutexGuard};
+#[cfg(feature = "oauth")]
+use std::time::{Duration, SystemTime};
+use std::{env, fmt, fs, io};
 
-/// Credentials enum, currently only supporting token authentication
-#[derive(Debug)]
+/// The environment variable the Heroku CLI reads the API key from.
+const HEROKU_API_KEY: &str = "HEROKU_API_KEY";
+/// The machine the Heroku CLI writes its credentials for in `.netrc`.
+const HEROKU_API_HOST: &str = "api.heroku.com";
+/// How long before its expiry an OAuth access token is refreshed.
+#[cfg(feature = "oauth")]
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Called with the new credentials after the client refreshed an OAuth access token, e.g. to persist them.
+pub type TokenRefreshCallback = Arc<dyn Fn(&Credentials) + Send + Sync>;
+
+/// Credentials enum, either a token, HTTP Basic authentication or a refreshable OAuth token
+///
+/// # Example:
+///
+/// Loading the credentials the way the Heroku CLI does, from `HEROKU_API_KEY` or `~/.netrc`.
+/// ```rust,no_run
+/// use heroku_rs::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let api_client = HttpApiClient::new(
+///        Credentials::load()?,
+///        HttpApiClientConfig::default(),
+///        ApiEnvironment::Production,
+///    )?;
+///
+///    Ok(())
+/// }
+/// ```
+///
+/// It is `#[non_exhaustive]`: the `OAuth` variant only exists with the `oauth` feature, so a `match` needs a wildcard arm.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum Credentials {
+    /// An API key or OAuth token, sent as `Authorization: Bearer <token>`
     UserAuthToken { token: String },
+    /// The account email and API key, sent as `Authorization: Basic <base64 of email:api_key>`
+    Basic { email: String, api_key: String },
+    /// An OAuth access token, sent as `Authorization: Bearer <access_token>`.
+    ///
+    /// The client refreshes it with `OAuthTokenCreate` and the `refresh_token` grant shortly before `expires_at`,
+    /// or when Heroku rejects it with `401 Unauthorized`. See `HttpApiClientConfig::on_token_refresh` to persist the new token.
+    #[cfg(feature = "oauth")]
+    OAuth {
+        /// the token sent with every request
+        access_token: String,
+        /// the token used to get a new access token
+        refresh_token: String,
+        /// the secret of the OAuth client the tokens were issued to
+        client_secret: String,
+        /// when the access token expires, `None` if unknown
+        expires_at: Option<SystemTime>,
+    },
 }
 
 impl Credentials {
     pub fn headers(&self) -> Vec<(&'static str, String)> {
         match self {
             Self::UserAuthToken { token } => vec![("Authorization", format!("Bearer {}", token))],
+            Self::Basic { email, api_key } => vec![(
+                "Authorization",
+                format!("Basic {}", base64::encode(format!("{}:{}", email, api_key))),
+            )],
+            #[cfg(feature = "oauth")]
+            Self::OAuth { access_token, .. } => {
+                vec![("Authorization", format!("Bearer {}", access_token))]
+            }
+        }
+    }
+
+    /// OAuth credentials from a token returned by Heroku, e.g. by `OAuthTokenCreate`.
+    #[cfg(feature = "oauth")]
+    pub fn oauth(token: &OAuthToken, client_secret: &str) -> Credentials {
+        Credentials::OAuth {
+            access_token: token.access_token.token.clone(),
+            refresh_token: token.refresh_token.token.clone(),
+            client_secret: client_secret.to_string(),
+            expires_at: expires_at(token.access_token.expires_in),
+        }
+    }
+
+    /// Load the credentials like the Heroku CLI: from the `HEROKU_API_KEY` environment variable,
+    /// or else from the `api.heroku.com` entry of the netrc file.
+    pub fn load() -> Result<Credentials, CredentialsError> {
+        match Credentials::from_env() {
+            Some(credentials) => Ok(credentials),
+            None => Credentials::from_netrc(),
+        }
+    }
+
+    /// The API key of the `HEROKU_API_KEY` environment variable, if it is set.
+    pub fn from_env() -> Option<Credentials> {
+        Credentials::from_env_with(|name| env::var(name).ok())
+    }
+
+    /// The API key of the `HEROKU_API_KEY` variable, read with `lookup` instead of from the process environment,
+    /// e.g. from a `.env` file or a map in tests.
+    pub fn from_env_with<F: FnOnce(&str) -> Option<String>>(lookup: F) -> Option<Credentials> {
+        lookup(HEROKU_API_KEY)
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .map(|token| Credentials::UserAuthToken { token })
+    }
+
+    /// The API key of the `api.heroku.com` entry in `~/.netrc`, or `~/_netrc` if there is no `~/.netrc`.
+    pub fn from_netrc() -> Result<Credentials, CredentialsError> {
+        match default_netrc_path() {
+            Some(path) => Credentials::from_netrc_file(path),
+            None => Err(CredentialsError::NotFound),
+        }
+    }
+
+    /// The API key of the `api.heroku.com` entry in the netrc file at `path`.
+    pub fn from_netrc_file<P: AsRef<Path>>(path: P) -> Result<Credentials, CredentialsError> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).map_err(|error| CredentialsError::Io(path.into(), error))?;
+        let netrc =
+            Netrc::parse(&content).map_err(|error| CredentialsError::Netrc(path.into(), error))?;
+
+        netrc
+            .machine(HEROKU_API_HOST)
+            .and_then(|machine| machine.password.clone())
+            .map(|token| Credentials::UserAuthToken { token })
+            .ok_or(CredentialsError::NotFound)
+    }
+}
+
+/// The time a token lasting `expires_in` seconds from now expires.
+#[cfg(feature = "oauth")]
+fn expires_at(expires_in: Option<i64>) -> Option<SystemTime> {
+    expires_in.map(|seconds| SystemTime::now() + Duration::from_secs(seconds.max(0) as u64))
+}
+
+/// The netrc file of the user, `.netrc` or `_netrc` in the home directory.
+fn default_netrc_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)?;
+
+    [".netrc", "_netrc"]
+        .iter()
+        .map(|name| home.join(name))
+        .find(|path| path.is_file())
+}
+
+/// The error returned when credentials could not be loaded.
+#[derive(Debug)]
+pub enum CredentialsError {
+    /// `HEROKU_API_KEY` is not set, and there is no netrc file or no `api.heroku.com` password in it
+    NotFound,
+    /// The netrc file could not be read
+    Io(PathBuf, io::Error),
+    /// The netrc file is malformed
+    Netrc(PathBuf, NetrcError),
+}
+
+impl Error for CredentialsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CredentialsError::NotFound => None,
+            CredentialsError::Io(_, error) => Some(error),
+            CredentialsError::Netrc(_, error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialsError::NotFound => write!(
+                f,
+                "No Heroku credentials found, set {} or log in with the Heroku CLI",
+                HEROKU_API_KEY
+            ),
+            CredentialsError::Io(path, error) => {
+                write!(f, "Could not read {}: {}", path.display(), error)
+            }
+            CredentialsError::Netrc(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+/// The credentials of a client, replaced when an OAuth access token is refreshed.
+pub(crate) struct CredentialStore {
+    credentials: RwLock<Credentials>,
+    #[cfg_attr(not(feature = "oauth"), allow(dead_code))]
+    on_refresh: Option<TokenRefreshCallback>,
+    /// Held by the request refreshing the credentials, the concurrent ones wait for its token.
+    #[cfg(feature = "oauth")]
+    refreshing: Mutex<()>,
+    /// The same for the requests of an async client, which must not block while they wait.
+    #[cfg(all(feature = "oauth", feature = "async"))]
+    refreshing_async: tokio::sync::Mutex<()>,
+}
+
+impl CredentialStore {
+    pub(crate) fn new(
+        credentials: Credentials,
+        on_refresh: Option<TokenRefreshCallback>,
+    ) -> CredentialStore {
+        CredentialStore {
+            credentials: RwLock::new(credentials),
+            on_refresh,
+            #[cfg(feature = "oauth")]
+            refreshing: Mutex::new(()),
+            #[cfg(all(feature = "oauth", feature = "async"))]
+            refreshing_async: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// A store for other credentials, telling the same callback about their refreshes.
+    pub(crate) fn with_credentials(&self, credentials: Credentials) -> CredentialStore {
+        CredentialStore::new(credentials, self.on_refresh.clone())
+    }
+
+    /// Replace the credentials, e.g. with a rotated token.
+    pub(crate) fn replace(&self, credentials: Credentials) {
+        *self
+            .credentials
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = credentials;
+    }
+
+    /// The credentials to send now.
+    pub(crate) fn current(&self) -> RwLockReadGuard<'_, Credentials> {
+        // Credentials are replaced whole, so a poisoned lock is still usable.
+        self.credentials
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Replace the authentication headers of a request with the current credentials.
+    pub(crate) fn authenticate(&self, headers: &mut http::HeaderMap) {
+        for (name, value) in self.current().headers() {
+            if let Ok(value) = http::HeaderValue::from_str(&value) {
+                headers.insert(name, value);
+            }
+        }
+    }
+
+    /// Whether the credentials can be refreshed after Heroku rejected them.
+    pub(crate) fn refreshable(&self) -> bool {
+        match *self.current() {
+            #[cfg(feature = "oauth")]
+            Credentials::OAuth { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether these request headers hold the current credentials.
+    #[cfg(feature = "oauth")]
+    fn authenticated(&self, headers: &http::HeaderMap) -> bool {
+        self.current()
+            .headers()
+            .into_iter()
+            .all(|(name, value)| headers.get(name).is_some_and(|sent| sent == value.as_str()))
+    }
+
+    /// Wait for the refresh of another request to be over, and keep the next one to this caller.
+    #[cfg(feature = "oauth")]
+    pub(crate) fn lock_refresh(&self) -> MutexGuard<'_, ()> {
+        // The lock guards no data, a poisoned lock is still usable.
+        self.refreshing
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// The same as `lock_refresh`, waiting without blocking the thread.
+    #[cfg(all(feature = "oauth", feature = "async"))]
+    pub(crate) async fn lock_refresh_async(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.refreshing_async.lock().await
+    }
+
+    /// The refresh to do for OAuth credentials about to expire,
+    /// or for OAuth credentials Heroku `rejected` in the headers of a request.
+    ///
+    /// Call it again once the refresh is locked, the credentials may have been refreshed by another request meanwhile.
+    #[cfg(feature = "oauth")]
+    pub(crate) fn oauth_refresh(&self, rejected: Option<&http::HeaderMap>) -> Option<OAuthRefresh> {
+        // Credentials already replaced since the request was sent do not need a refresh.
+        let rejected = rejected.is_some_and(|headers| self.authenticated(headers));
+        match &*self.current() {
+            Credentials::OAuth {
+                refresh_token,
+                client_secret,
+                expires_at,
+                ..
+            } => {
+                let expiring = expires_at
+                    .map(|expires_at| expires_at <= SystemTime::now() + REFRESH_MARGIN)
+                    .unwrap_or(false);
+                Some(OAuthRefresh {
+                    refresh_token: refresh_token.clone(),
+                    client_secret: client_secret.clone(),
+                })
+                .filter(|_| rejected || expiring)
+            }
+            _ => None,
+        }
+    }
+
+    /// Replace the credentials with the refreshed token, and tell the callback about them.
+    #[cfg(feature = "oauth")]
+    pub(crate) fn refreshed(&self, refresh: &OAuthRefresh, token: &OAuthToken) {
+        // Heroku may keep the same refresh token and leave it out.
+        let refresh_token = Some(token.refresh_token.token.clone())
+            .filter(|token| !token.is_empty())
+            .unwrap_or_else(|| refresh.refresh_token.clone());
+        let credentials = Credentials::OAuth {
+            access_token: token.access_token.token.clone(),
+            refresh_token,
+            client_secret: refresh.client_secret.clone(),
+            expires_at: expires_at(token.access_token.expires_in),
+        };
+
+        self.replace(credentials.clone());
+        if let Some(on_refresh) = &self.on_refresh {
+            on_refresh(&credentials);
+        }
+    }
+}
+
+/// What is needed to refresh an OAuth access token.
+#[cfg(feature = "oauth")]
+pub(crate) struct OAuthRefresh {
+    refresh_token: String,
+    client_secret: String,
+}
+
+#[cfg(feature = "oauth")]
+impl OAuthRefresh {
+    /// The `OAuthTokenCreate` request with the `refresh_token` grant.
+    pub(crate) fn endpoint(&self) -> OAuthTokenCreate<'_> {
+        OAuthTokenCreate {
+            params: OAuthTokenCreateParams {
+                client: Client {
+                    secret: &self.client_secret,
+                },
+                grant: Grant {
+                    code: "",
+                    type_field: "refresh_token",
+                },
+                refresh_token: RefreshToken {
+                    token: &self.refresh_token,
+                },
+            },
         }
     }
 }
@@ -18,6 +370,7 @@ pub trait AuthClient {
     fn auth(self, credentials: &Credentials) -> Self;
 }
 
+#[cfg(feature = "reqwest")]
 impl AuthClient for RequestBuilder {
     fn auth(mut self, credentials: &Credentials) -> Self {
         for (k, v) in credentials.headers() {
@@ -26,3 +379,13 @@ impl AuthClient for RequestBuilder {
         self
     }
 }
+
+#[cfg(feature = "async")]
+impl AuthClient for reqwest::RequestBuilder {
+    fn auth(mut self, credentials: &Credentials) -> Self {
+        for (k, v) in credentials.headers() {
+            self = self.header(k, v);
+        }
+        self
+    }
+}
@@ -0,0 +1,147 @@
+//! Module for caching responses with their `ETag`, so that polling an unchanged resource costs a `304 Not Modified`.
+use http::header::{IF_NONE_MATCH, RANGE};
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A response body stored with the `ETag` Heroku gave it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// the `ETag` header of the response, sent back as `If-None-Match`
+    pub etag: String,
+    /// the raw JSON body of the response
+    pub body: Vec<u8>,
+}
+
+/// ResponseCache
+///
+/// A store for [`CachedResponse`](struct.CachedResponse.html)s, keyed by the method and URL of the request.
+///
+/// When a client has a cache, its `GET` requests are sent with the `If-None-Match` header of the cached response,
+/// and when Heroku answers with `304 Not Modified`, the cached body is parsed instead.
+///
+/// Implement this to keep responses somewhere else than in memory, e.g. on disk or in a shared cache.
+/// The keys include the URL but not the credentials, so don't share a cache between clients of different accounts.
+pub trait ResponseCache: Send + Sync {
+    /// The response cached for `key`, if there is one.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    /// Cache `response` for `key`, replacing the previous one.
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// MemoryCache
+///
+/// A [`ResponseCache`](trait.ResponseCache.html) keeping the responses in memory, for as long as it lives.
+///
+/// # Example:
+///
+/// Caching the responses of a client polling an app.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use std::sync::Arc;
+///
+/// let config = HttpApiClientConfig {
+///     cache: Some(Arc::new(MemoryCache::new())),
+///     ..HttpApiClientConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    responses: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryCache {
+    /// Create an empty cache.
+    pub fn new() -> MemoryCache {
+        MemoryCache::default()
+    }
+
+    /// The number of cached responses.
+    pub fn len(&self) -> usize {
+        self.responses().len()
+    }
+
+    /// Whether no response is cached.
+    pub fn is_empty(&self) -> bool {
+        self.responses().is_empty()
+    }
+
+    /// Forget every cached response.
+    pub fn clear(&self) {
+        self.responses().clear();
+    }
+
+    fn responses(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedResponse>> {
+        // A map is never left half updated, so a poisoned lock is still usable.
+        self.responses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ResponseCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.responses().get(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        self.responses().insert(key.to_string(), response);
+    }
+}
+
+/// The cache entry of one request.
+pub(crate) struct CacheLookup<'a> {
+    cache: &'a dyn ResponseCache,
+    key: String,
+    cached: Option<CachedResponse>,
+}
+
+impl<'a> CacheLookup<'a> {
+    /// Look the request up, adding `If-None-Match` to its headers if a response is cached.
+    ///
+    /// Only `GET` requests are cached, `None` is returned for the others.
+    pub(crate) fn start(
+        cache: &'a dyn ResponseCache,
+        method: &Method,
+        url: &str,
+        headers: &mut HeaderMap,
+    ) -> Option<CacheLookup<'a>> {
+        if method != Method::GET {
+            return None;
+        }
+
+        // Different ranges of a list are different responses.
+        let key = match headers.get(RANGE).and_then(|range| range.to_str().ok()) {
+            Some(range) => format!("{} {} {}", method, url, range),
+            None => format!("{} {}", method, url),
+        };
+        let cached = cache.get(&key);
+        if let Some(etag) = cached
+            .as_ref()
+            .and_then(|cached| HeaderValue::from_str(&cached.etag).ok())
+        {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+
+        Some(CacheLookup { cache, key, cached })
+    }
+
+    /// The cached body, if the response says it has not changed.
+    pub(crate) fn not_modified(&mut self, status: StatusCode) -> Option<Vec<u8>> {
+        if status != StatusCode::NOT_MODIFIED {
+            return None;
+        }
+        self.cached.take().map(|cached| cached.body)
+    }
+
+    /// Cache the body of a successful response with its `ETag`.
+    pub(crate) fn store(&self, etag: &str, body: &[u8]) {
+        self.cache.put(
+            &self.key,
+            CachedResponse {
+                etag: etag.to_string(),
+                body: body.to_vec(),
+            },
+        );
+    }
+}
@@ -0,0 +1,448 @@
+//! Module for cassettes, recording the requests of a client and their responses to a JSON file, and replaying them offline.
+//!
+//! A cassette is a [`Middleware`](../middleware/trait.Middleware.html): record it once against Heroku,
+//! commit the file, and replay it in CI without credentials or network.
+//!
+//! Request headers are never recorded, so the `Authorization` header stays out of the file.
+//! Config var values, and the `token`, `secret`, `password` and `api_key` fields of the bodies are replaced by `[SCRUBBED]`,
+//! along with any value given to [`Cassette::scrub`](struct.Cassette.html#method.scrub).
+use crate::framework::middleware::Middleware;
+use http::header::{HeaderName, HeaderValue, CONTENT_TYPE};
+use http::{HeaderMap, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::{fmt, fs, io};
+
+/// The value replacing the secrets in a cassette.
+pub const SCRUBBED: &str = "[SCRUBBED]";
+
+/// Fields of the bodies whose values are secrets.
+const SECRET_FIELDS: &[&str] = &["api_key", "password", "secret", "token"];
+
+/// Response headers which are not recorded.
+const SKIPPED_HEADERS: &[&str] = &["content-length", "set-cookie"];
+
+/// Whether a cassette records the requests or replays them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send the requests and write them to the cassette with their responses
+    Record,
+    /// Answer the requests with the responses of the cassette, without sending them
+    Replay,
+}
+
+/// A request of a cassette, without its headers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// the HTTP method, e.g. `GET`
+    pub method: String,
+    /// the path of the url, e.g. `/apps/my-app`
+    pub path: String,
+    /// the query of the url, if there is one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// the JSON body, if there is one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A response of a cassette.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// the HTTP status
+    pub status: u16,
+    /// the headers, e.g. `Request-Id` or `Next-Range`
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// the JSON body, a string if it is not JSON and `null` if it is empty
+    #[serde(default)]
+    pub body: Value,
+}
+
+/// A request of a cassette with its response.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// the request sent by the client
+    pub request: RecordedRequest,
+    /// the response Heroku answered with
+    pub response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// Cassette
+///
+/// Records the requests of a client to a JSON file, or replays them from it.
+///
+/// When recording, the interactions are kept in memory and written to the file by [`save`](#method.save),
+/// or when the cassette is dropped with the last client using it; an error writing it then is only logged, with `tracing`.
+/// A response which cannot be recorded, e.g. with a header which is not UTF-8, is replaced by a `cassette_invalid` error.
+/// When replaying, each request is answered by the first recorded interaction with the same method, path, query and body,
+/// which is then used up. A request which was not recorded fails with a `cassette_miss` error.
+///
+/// # Example:
+///
+/// Recording when `RECORD` is set, and replaying otherwise.
+/// ```rust,no_run
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::cassette::Cassette;
+/// use std::sync::Arc;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let path = "tests/cassettes/rate_limit.json";
+///    let cassette = match std::env::var("RECORD") {
+///        Ok(token) => {
+///            let mut cassette = Cassette::record(path);
+///            cassette.scrub(&token);
+///            cassette
+///        }
+///        Err(_) => Cassette::replay(path)?,
+///    };
+///
+///    let cassette = Arc::new(cassette);
+///    let api_client = HttpApiClient::new(
+///        Credentials::UserAuthToken { token: std::env::var("RECORD").unwrap_or_default() },
+///        HttpApiClientConfig {
+///            middlewares: vec![cassette.clone()],
+///            ..HttpApiClientConfig::default()
+///        },
+///        ApiEnvironment::Production,
+///    )?;
+///
+///    let ratelimit = api_client.request(&RatelimitDetails::new())?;
+///
+///    cassette.save()?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    secrets: Vec<String>,
+    /// The interactions recorded so far, or the ones left to replay
+    interactions: Mutex<Vec<Interaction>>,
+    /// Whether interactions were recorded since the file was last written
+    unsaved: AtomicBool,
+}
+
+impl Cassette {
+    /// Record to the file at `path`, replacing it.
+    pub fn record<P: AsRef<Path>>(path: P) -> Cassette {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            secrets: Vec::new(),
+            interactions: Mutex::new(Vec::new()),
+            unsaved: AtomicBool::new(false),
+        }
+    }
+
+    /// Replay the file at `path`.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Cassette, CassetteError> {
+        let path = path.as_ref().to_path_buf();
+        let content = fs::read(&path).map_err(|error| CassetteError::Io(path.clone(), error))?;
+        let file: CassetteFile = serde_json::from_slice(&content)
+            .map_err(|error| CassetteError::Json(path.clone(), error))?;
+
+        Ok(Cassette {
+            path,
+            mode: CassetteMode::Replay,
+            secrets: Vec::new(),
+            interactions: Mutex::new(file.interactions),
+            unsaved: AtomicBool::new(false),
+        })
+    }
+
+    /// # scrub: a secret replaced by `[SCRUBBED]` wherever it appears, e.g. the API key or an app's database url
+    ///
+    /// When replaying, the requests are scrubbed the same way before being matched.
+    pub fn scrub(&mut self, secret: &str) -> &mut Self {
+        if !secret.is_empty() {
+            self.secrets.push(secret.to_string());
+        }
+        self
+    }
+
+    /// Whether the cassette records or replays.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The interactions recorded so far, or the ones left to replay.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().clone()
+    }
+
+    /// Write the recorded interactions to the file.
+    ///
+    /// This does nothing when replaying, the file is left as it was.
+    pub fn save(&self) -> Result<(), CassetteError> {
+        if self.mode == CassetteMode::Replay {
+            return Ok(());
+        }
+
+        self.unsaved.store(false, Ordering::SeqCst);
+        let file = CassetteFile {
+            interactions: self.interactions(),
+        };
+        let content = serde_json::to_vec_pretty(&file)
+            .map_err(|error| CassetteError::Json(self.path.clone(), error))?;
+        fs::write(&self.path, content).map_err(|error| {
+            self.unsaved.store(true, Ordering::SeqCst);
+            CassetteError::Io(self.path.clone(), error)
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Interaction>> {
+        // The interactions are pushed or removed whole, so a poisoned lock is still usable.
+        self.interactions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn recorded_request(&self, request: &Request<Vec<u8>>) -> RecordedRequest {
+        let uri = request.uri();
+        let path = self.scrub_text(uri.path());
+        let is_config_vars = is_config_vars(&path);
+        RecordedRequest {
+            method: request.method().to_string(),
+            path,
+            query: uri.query().map(|query| self.scrub_text(query)),
+            body: Some(request.body())
+                .filter(|body| !body.is_empty())
+                .map(|body| self.scrub_body(body, is_config_vars)),
+        }
+    }
+
+    fn recorded_response(
+        &self,
+        path: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<RecordedResponse, String> {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| match value.to_str() {
+                Ok(value) => Ok((name.as_str().to_string(), self.scrub_text(value))),
+                Err(_) => Err(format!("the {} header is not UTF-8", name)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(RecordedResponse {
+            status: status.as_u16(),
+            headers,
+            body: if body.is_empty() {
+                Value::Null
+            } else {
+                self.scrub_body(body, is_config_vars(path))
+            },
+        })
+    }
+
+    /// The response answering a request with a recorded one.
+    fn replayed_response(&self, recorded: RecordedResponse) -> Result<Response<Vec<u8>>, String> {
+        let body = match recorded.body {
+            Value::Null => String::new(),
+            Value::String(text) => text,
+            body => body.to_string(),
+        };
+        let mut response = Response::new(body.into_bytes());
+        let status = recorded.status;
+        *response.status_mut() = StatusCode::from_u16(status)
+            .map_err(|_| format!("the status {} is invalid", status))?;
+        for (name, value) in &recorded.headers {
+            let invalid = || format!("the {} header is invalid", name);
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?;
+            let value = HeaderValue::from_str(value).map_err(|_| invalid())?;
+            response.headers_mut().append(name, value);
+        }
+        Ok(response)
+    }
+
+    /// A Heroku error response with this `id`, failing the request for the cassette.
+    fn error_response(&self, id: &str, message: &str) -> Response<Vec<u8>> {
+        let body = serde_json::json!({
+            "id": id,
+            "message": format!("Cassette {}: {}", self.path.display(), message),
+        });
+        let mut response = Response::new(body.to_string().into_bytes());
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+
+    fn scrub_text(&self, text: &str) -> String {
+        self.secrets.iter().fold(text.to_string(), |text, secret| {
+            text.replace(secret.as_str(), SCRUBBED)
+        })
+    }
+
+    /// The body as JSON with its secrets scrubbed, or as a string if it is not JSON.
+    fn scrub_body(&self, body: &[u8], is_config_vars: bool) -> Value {
+        let mut value = serde_json::from_slice(body)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(body).into_owned()));
+        if is_config_vars {
+            if let Value::Object(config_vars) = &mut value {
+                for config_var in config_vars.values_mut().filter(|value| !value.is_null()) {
+                    *config_var = Value::String(SCRUBBED.to_string());
+                }
+            }
+        }
+        self.scrub_value(&mut value);
+        value
+    }
+
+    fn scrub_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => *text = self.scrub_text(text),
+            Value::Array(values) => values.iter_mut().for_each(|value| self.scrub_value(value)),
+            Value::Object(fields) => {
+                for (name, value) in fields.iter_mut() {
+                    if SECRET_FIELDS.contains(&name.as_str()) && value.is_string() {
+                        *value = Value::String(SCRUBBED.to_string());
+                    } else {
+                        self.scrub_value(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Middleware for Cassette {
+    fn before_send(&self, request: &mut Request<Vec<u8>>) -> Option<Response<Vec<u8>>> {
+        if self.mode == CassetteMode::Record {
+            return None;
+        }
+
+        let recorded = self.recorded_request(request);
+        let response = {
+            let mut interactions = self.lock();
+            interactions
+                .iter()
+                .position(|interaction| interaction.request == recorded)
+                .map(|position| interactions.remove(position).response)
+        };
+
+        let response = match response {
+            Some(response) => self.replayed_response(response).unwrap_or_else(|error| {
+                self.error_response(
+                    "cassette_invalid",
+                    &format!(
+                        "the response to {} {} cannot be replayed, {}",
+                        recorded.method, recorded.path, error
+                    ),
+                )
+            }),
+            None => self.error_response(
+                "cassette_miss",
+                &format!(
+                    "no recorded interaction for {} {}",
+                    recorded.method, recorded.path
+                ),
+            ),
+        };
+        Some(response)
+    }
+
+    fn after_receive(&self, request: &Request<Vec<u8>>, response: &mut Response<Vec<u8>>) {
+        if self.mode == CassetteMode::Replay {
+            return;
+        }
+
+        let recorded_request = self.recorded_request(request);
+        let recorded_response = self.recorded_response(
+            &recorded_request.path,
+            response.status(),
+            response.headers(),
+            response.body(),
+        );
+        match recorded_response {
+            Ok(recorded_response) => {
+                self.lock().push(Interaction {
+                    request: recorded_request,
+                    response: recorded_response,
+                });
+                self.unsaved.store(true, Ordering::SeqCst);
+            }
+            Err(error) => {
+                *response = self.error_response(
+                    "cassette_invalid",
+                    &format!(
+                        "the response to {} {} cannot be recorded, {}",
+                        recorded_request.method, recorded_request.path, error
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        if !self.unsaved.load(Ordering::SeqCst) {
+            return;
+        }
+        // Panicking in drop would abort a test which is already panicking, the error is only logged.
+        // `save` returns it to the callers who want it.
+        if let Err(_error) = self.save() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %_error, "could not save the cassette");
+        }
+    }
+}
+
+/// Whether the bodies of the requests to `path` are config vars, whose values are all secrets.
+fn is_config_vars(path: &str) -> bool {
+    path.split('/').any(|segment| segment == "config-vars")
+}
+
+/// The error returned when a cassette cannot be read or written.
+#[derive(Debug)]
+pub enum CassetteError {
+    /// The file could not be read or written
+    Io(PathBuf, io::Error),
+    /// The file is not a valid cassette
+    Json(PathBuf, serde_json::Error),
+}
+
+impl Error for CassetteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CassetteError::Io(_, error) => Some(error),
+            CassetteError::Json(_, error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for CassetteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CassetteError::Io(path, error) => {
+                write!(
+                    f,
+                    "Could not access the cassette {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            CassetteError::Json(path, error) => {
+                write!(f, "Invalid cassette {}: {}", path.display(), error)
+            }
+        }
+    }
+}
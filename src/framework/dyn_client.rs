@@ -0,0 +1,226 @@
+//! Module for an object-safe client, to keep a `Box<dyn DynHerokuApiClient>` and choose the implementation at runtime.
+use crate::framework::{
+    apiclient::HerokuApiClient,
+    endpoint::{HerokuEndpoint, Method},
+    range::Range,
+    response::{parse_response, ApiResponse, ApiResult, Empty, HerokuApiFailure},
+};
+use http::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// DynRequest
+///
+/// An endpoint with its types erased: its query and body as JSON, and everything else it sends.
+///
+/// It is an endpoint itself, for whatever result type the response is parsed into.
+/// It owns everything and can be serialized, e.g. to persist it, see [`OwnedEndpoint`](../owned/struct.OwnedEndpoint.html) to keep its result type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DynRequest {
+    /// the method of the endpoint
+    pub method: Method,
+    /// the path of the endpoint, e.g. `apps/my-app`
+    pub path: String,
+    /// the query of the endpoint, as a JSON object without its `null` fields
+    pub query: Option<Value>,
+    /// the body of the endpoint, as JSON
+    pub body: Option<Value>,
+    /// the `Content-Type` of the body
+    pub content_type: String,
+    /// the `Accept` header
+    pub version: String,
+    /// the `User-Agent` header
+    pub agent: String,
+    /// the `Range` header of a list endpoint
+    pub range: Option<Range>,
+    /// the extra headers of the endpoint
+    #[serde(with = "header_pairs")]
+    pub headers: HeaderMap,
+}
+
+impl DynRequest {
+    /// Erase the types of an endpoint.
+    ///
+    /// Fails with `HerokuApiFailure::Request` if its query or body cannot be serialized.
+    pub fn new<ResultType, QueryType, BodyType>(
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> Result<DynRequest, HerokuApiFailure>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut query = endpoint.query().map(to_json).transpose()?;
+        // A missing optional parameter is left out of the query string, not sent empty.
+        if let Some(Value::Object(fields)) = &mut query {
+            *fields = std::mem::take(fields)
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .collect();
+        }
+
+        Ok(DynRequest {
+            method: endpoint.method(),
+            path: endpoint.path(),
+            query,
+            body: endpoint.body().map(to_json).transpose()?,
+            content_type: endpoint.content_type().to_string(),
+            version: endpoint.version().to_string(),
+            agent: endpoint.agent().to_string(),
+            range: endpoint.range(),
+            headers: endpoint.headers(),
+        })
+    }
+}
+
+impl<ResultType: ApiResult> HerokuEndpoint<ResultType, Value, Value> for DynRequest {
+    fn method(&self) -> Method {
+        self.method
+    }
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+    fn query(&self) -> Option<Value> {
+        self.query.clone()
+    }
+    fn body(&self) -> Option<Value> {
+        self.body.clone()
+    }
+    fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    fn version(&self) -> &str {
+        &self.version
+    }
+    fn agent(&self) -> &str {
+        &self.agent
+    }
+    fn range(&self) -> Option<Range> {
+        self.range.clone()
+    }
+    fn headers(&self) -> HeaderMap {
+        self.headers.clone()
+    }
+}
+
+/// DynHerokuApiClient
+///
+/// An object-safe Heroku client, sending type-erased requests and returning the raw responses.
+///
+/// Every `HerokuApiClient` is one, e.g. `HttpApiClient` or `MockApiClient`,
+/// and a `dyn DynHerokuApiClient` is a `HerokuApiClient` again: typed endpoints are sent and parsed the usual way.
+///
+/// # Example:
+///
+/// Choosing the client at runtime, between Heroku and an offline one.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::dyn_client::DynRequest;
+/// use heroku_rs::framework::response::HerokuApiFailure;
+///
+/// struct Offline;
+///
+/// impl DynHerokuApiClient for Offline {
+///     fn send_request(&self, request: &DynRequest) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+///         let (status, body) = match request.path.as_str() {
+///             "account/rate-limits" => (200, r#"{"remaining": 2400}"#),
+///             _ => (404, r#"{"id": "not_found", "message": "Not found."}"#),
+///         };
+///         Ok(http::Response::builder().status(status).body(body.as_bytes().to_vec()).unwrap())
+///     }
+/// }
+///
+/// struct Deployer {
+///     api_client: Box<dyn DynHerokuApiClient>,
+/// }
+///
+/// impl Deployer {
+///     fn remaining_calls(&self) -> Result<i64, HerokuApiFailure> {
+///         Ok(self.api_client.request(&RatelimitDetails::new())?.remaining)
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let offline = true;
+///    let api_client: Box<dyn DynHerokuApiClient> = if offline {
+///        Box::new(Offline)
+///    } else {
+///        Box::new(HttpApiClient::create("API_KEY")?)
+///    };
+///
+///    let deployer = Deployer { api_client };
+///    assert_eq!(deployer.remaining_calls()?, 2400);
+///    Ok(())
+/// }
+/// ```
+pub trait DynHerokuApiClient: Send + Sync {
+    /// Send the request and return the whole response, whatever its status.
+    fn send_request(
+        &self,
+        request: &DynRequest,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure>;
+}
+
+impl<Client: HerokuApiClient + Send + Sync> DynHerokuApiClient for Client {
+    fn send_request(
+        &self,
+        request: &DynRequest,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.request_raw::<Empty, Value, Value>(request)
+    }
+}
+
+impl<'a> HerokuApiClient for dyn DynHerokuApiClient + 'a {
+    fn request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<ResultType>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        parse_response(self.send_request(&DynRequest::new(endpoint)?)?)
+    }
+
+    fn request_raw<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<http::Response<Vec<u8>>>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        self.send_request(&DynRequest::new(endpoint)?)
+    }
+}
+
+fn to_json<T: Serialize>(value: T) -> Result<Value, HerokuApiFailure> {
+    serde_json::to_value(value).map_err(|error| HerokuApiFailure::Request(Box::new(error)))
+}
+
+/// (De)serialize a header map as a list of name and value pairs, keeping repeated headers.
+mod header_pairs {
+    use http::header::{HeaderMap, HeaderName, HeaderValue};
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(headers: &HeaderMap, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut pairs = Vec::with_capacity(headers.len());
+        for (name, value) in headers {
+            let value = value.to_str().map_err(ser::Error::custom)?;
+            pairs.push((name.as_str(), value));
+        }
+        pairs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HeaderMap, D::Error> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in Vec::<(String, String)>::deserialize(deserializer)? {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(de::Error::custom)?;
+            let value = HeaderValue::from_str(&value).map_err(de::Error::custom)?;
+            headers.append(name, value);
+        }
+        Ok(headers)
+    }
+}
@@ -1,10 +1,29 @@
-use crate::framework::response::{ApiResult, Empty};
+use crate::framework::range::Range;
+use crate::framework::response::{ApiResult, Empty, HerokuApiFailure};
 use crate::framework::ApiEnvironment;
-use serde::Serialize;
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use url::Url;
 
+/// The characters percent-encoded in a path segment, the ones which would end it or change the url.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
 /// HTTP methods used on this crate.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Method {
     Get,
     Post,
@@ -13,6 +32,18 @@ pub enum Method {
     Patch,
 }
 
+impl From<Method> for http::Method {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => http::Method::GET,
+            Method::Post => http::Method::POST,
+            Method::Delete => http::Method::DELETE,
+            Method::Put => http::Method::PUT,
+            Method::Patch => http::Method::PATCH,
+        }
+    }
+}
+
 /// Heroku Endpoint trait by default has a empty struct and void query types and body types
 ///
 /// This trait is responsible for the majority of the functionality of this crate.
@@ -30,8 +61,9 @@ where
     fn body(&self) -> Option<BodyType> {
         None
     }
-    fn url(&self, environment: &ApiEnvironment) -> Url {
-        Url::from(environment).join(&self.path()).unwrap()
+    /// The url of the endpoint in the environment, an error if the path does not make a valid url.
+    fn url(&self, environment: &ApiEnvironment) -> Result<Url, HerokuApiFailure> {
+        endpoint_url(environment, &self.path())
     }
     fn content_type(&self) -> &str {
         "application/json"
@@ -42,4 +74,190 @@ where
     fn agent(&self) -> &str {
         "heroku_rs"
     }
+    /// The `Range` header to send, used by list endpoints to sort and limit the results.
+    fn range(&self) -> Option<Range> {
+        None
+    }
+    /// Extra headers to send, e.g. `Heroku-Two-Factor-Code` or `If-Match`.
+    /// They replace the headers of the same name set from the other methods.
+    fn headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+}
+
+/// A borrowed endpoint is an endpoint too, e.g. to send the `&dyn HerokuEndpoint` given to a client `with_header`.
+impl<'a, ResultType, QueryType, BodyType> HerokuEndpoint<ResultType, QueryType, BodyType>
+    for &'a (dyn HerokuEndpoint<ResultType, QueryType, BodyType> + 'a)
+where
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    fn method(&self) -> Method {
+        (**self).method()
+    }
+    fn path(&self) -> String {
+        (**self).path()
+    }
+    fn query(&self) -> Option<QueryType> {
+        (**self).query()
+    }
+    fn body(&self) -> Option<BodyType> {
+        (**self).body()
+    }
+    fn url(&self, environment: &ApiEnvironment) -> Result<Url, HerokuApiFailure> {
+        (**self).url(environment)
+    }
+    fn content_type(&self) -> &str {
+        (**self).content_type()
+    }
+    fn version(&self) -> &str {
+        (**self).version()
+    }
+    fn agent(&self) -> &str {
+        (**self).agent()
+    }
+    fn range(&self) -> Option<Range> {
+        (**self).range()
+    }
+    fn headers(&self) -> HeaderMap {
+        (**self).headers()
+    }
+}
+
+/// An endpoint sent with extra headers.
+///
+/// This is created through [`ExtraHeaders::with_header`](trait.ExtraHeaders.html#method.with_header)
+/// or [`ExtraHeaders::with_headers`](trait.ExtraHeaders.html#method.with_headers).
+pub struct EndpointWithHeaders<E> {
+    /// the wrapped endpoint
+    pub endpoint: E,
+    /// the headers to send with it, on top of the endpoint's own
+    pub headers: HeaderMap,
+}
+
+impl<E, ResultType, QueryType, BodyType> HerokuEndpoint<ResultType, QueryType, BodyType>
+    for EndpointWithHeaders<E>
+where
+    E: HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    fn query(&self) -> Option<QueryType> {
+        self.endpoint.query()
+    }
+    fn body(&self) -> Option<BodyType> {
+        self.endpoint.body()
+    }
+    fn url(&self, environment: &ApiEnvironment) -> Result<Url, HerokuApiFailure> {
+        self.endpoint.url(environment)
+    }
+    fn content_type(&self) -> &str {
+        self.endpoint.content_type()
+    }
+    fn version(&self) -> &str {
+        self.endpoint.version()
+    }
+    fn agent(&self) -> &str {
+        self.endpoint.agent()
+    }
+    fn range(&self) -> Option<Range> {
+        self.endpoint.range()
+    }
+    fn headers(&self) -> HeaderMap {
+        let mut headers = self.endpoint.headers();
+        headers.extend(self.headers.clone());
+        headers
+    }
+}
+
+/// Implemented for every endpoint, to send it with extra headers for a single call.
+///
+/// # Example:
+///
+/// Deleting an app of an account protected by two-factor authentication.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use http::header::{HeaderName, HeaderValue};
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let endpoint = AppDelete::new("APP_ID")
+///     .with_header(
+///         HeaderName::from_static("heroku-two-factor-code"),
+///         HeaderValue::from_static("123456"),
+///     );
+/// let response = api_client.request(&endpoint);
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+/// ```
+pub trait ExtraHeaders<ResultType, QueryType, BodyType>:
+    HerokuEndpoint<ResultType, QueryType, BodyType> + Sized
+where
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    /// Send the endpoint with one more header.
+    fn with_header(self, name: HeaderName, value: HeaderValue) -> EndpointWithHeaders<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, value);
+        self.with_headers(headers)
+    }
+
+    /// Send the endpoint with more headers.
+    fn with_headers(self, headers: HeaderMap) -> EndpointWithHeaders<Self> {
+        EndpointWithHeaders {
+            endpoint: self,
+            headers,
+        }
+    }
+}
+
+impl<E, ResultType, QueryType, BodyType> ExtraHeaders<ResultType, QueryType, BodyType> for E
+where
+    E: HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+}
+
+/// Percent-encode an identifier for the path of an endpoint, so a `/`, `?`, `#` or `%` in it stays part of its segment.
+///
+/// e.g. `format!("apps/{}", encode_segment(app_id))`
+pub fn encode_segment(segment: &str) -> Cow<'_, str> {
+    utf8_percent_encode(segment, PATH_SEGMENT).into()
+}
+
+/// Join the path of an endpoint to the url of the environment.
+///
+/// A `.` or `..` segment is rejected, the url would point to another endpoint once normalized.
+pub(crate) fn endpoint_url(
+    environment: &ApiEnvironment,
+    path: &str,
+) -> Result<Url, HerokuApiFailure> {
+    let path_only = path.split(&['?', '#'][..]).next().unwrap_or(path);
+    let dot_segment = path_only.split('/').any(|segment| {
+        let segment = segment.to_ascii_lowercase().replace("%2e", ".");
+        segment == "." || segment == ".."
+    });
+    if dot_segment {
+        let message = format!("The path {} has a `.` or `..` segment", path);
+        return Err(HerokuApiFailure::Request(message.into()));
+    }
+
+    environment
+        .base_url()
+        .and_then(|base| base.join(path))
+        .map_err(|error| HerokuApiFailure::Request(Box::new(error)))
 }
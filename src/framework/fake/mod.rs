@@ -0,0 +1,197 @@
+//! Module for a fake Heroku Platform API, a stateful local server to run the endpoints against in tests.
+//!
+//! The fake keeps apps, their config vars, formation and releases in memory,
+//! and answers with the same JSON and the same errors as `api.heroku.com`:
+//! an app created with `AppCreate` is listed by `AppList`, `AppConfigVarUpdate` changes `AppConfigVarDetails`
+//! and adds a release, `FormationUpdate` changes `FormationList`, and `ReleaseCreate` deploys or rolls back.
+//!
+//! Unknown apps, process types, releases and routes answer `404 not_found`,
+//! invalid names and missing parameters answer `422 invalid_params`, and requests without credentials `401 unauthorized`.
+mod state;
+
+use crate::framework::ApiEnvironment;
+use http::{Method, StatusCode};
+use state::State;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// FakeHeroku
+///
+/// A fake Heroku Platform API listening on a local port, for as long as it is not dropped.
+///
+/// Point a client at it with [`environment`](#method.environment), the endpoints run against it unmodified.
+/// Any credentials are accepted, and every fake starts without apps.
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::endpoints::apps::{AppCreate, AppCreateParams, AppList};
+/// use heroku_rs::framework::fake::FakeHeroku;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let heroku = FakeHeroku::start()?;
+///    let api_client = HttpApiClient::new(
+///        Credentials::UserAuthToken { token: String::from("FAKE_API_KEY") },
+///        HttpApiClientConfig::default(),
+///        heroku.environment(),
+///    )?;
+///
+///    let app = api_client.request(&AppCreate {
+///        params: AppCreateParams { name: Some("my-app"), region: None, stack: None },
+///    })?;
+///    let apps = api_client.request(&AppList {})?;
+///
+///    assert_eq!(apps[0].name, app.name);
+///    Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FakeHeroku {
+    address: SocketAddr,
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeHeroku {
+    /// Start a fake on a free local port, without apps.
+    pub fn start() -> io::Result<FakeHeroku> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+        let state = Arc::new(Mutex::new(State::new("fake@example.com")));
+
+        let stop = stopped.clone();
+        let thread = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(mut stream) = stream {
+                    serve(&mut stream, &state);
+                }
+            }
+        });
+
+        Ok(FakeHeroku {
+            address,
+            stopped,
+            thread: Some(thread),
+        })
+    }
+
+    /// The base url of the fake, e.g. `http://127.0.0.1:49152/`.
+    pub fn url(&self) -> url::Url {
+        url::Url::parse(&format!("http://{}/", self.address))
+            .expect("a socket address is a valid host")
+    }
+
+    /// The environment to create a client against the fake with.
+    pub fn environment(&self) -> ApiEnvironment {
+        ApiEnvironment::Custom(self.url())
+    }
+}
+
+impl Drop for FakeHeroku {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // The accept loop only checks the flag between connections, so wake it up with one.
+        let _ = TcpStream::connect(self.address);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A request read from a connection.
+struct FakeRequest {
+    method: Method,
+    path: String,
+    authorized: bool,
+    body: Vec<u8>,
+}
+
+fn serve(stream: &mut TcpStream, state: &Mutex<State>) {
+    let request = match read_request(stream) {
+        Some(request) => request,
+        None => return,
+    };
+
+    let (status, body) = if !request.authorized {
+        (
+            StatusCode::UNAUTHORIZED,
+            serde_json::json!({
+                "id": "unauthorized",
+                "message": "There were no credentials in your `Authorization` header.",
+            }),
+        )
+    } else {
+        // The state is changed whole by each request, so a poisoned lock is still usable.
+        let mut state = state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.handle(&request.method, &request.path, &request.body)
+    };
+    write_response(stream, status, &body.to_string());
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<FakeRequest> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = Method::from_bytes(parts.next()?.as_bytes()).ok()?;
+    let target = parts.next()?;
+    let path = target.split('?').next().unwrap_or(target).to_string();
+
+    let (mut length, mut authorized) = (0, false);
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().ok()?;
+            } else if name.eq_ignore_ascii_case("authorization") {
+                authorized = !value.trim().is_empty();
+            }
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    Some(FakeRequest {
+        method,
+        path,
+        authorized,
+        body,
+    })
+}
+
+fn write_response(stream: &mut TcpStream, status: StatusCode, body: &str) {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nRateLimit-Remaining: 4500\r\nRequest-Id: {}\r\nConnection: close\r\n\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or(""),
+        body.len(),
+        next_request_id(),
+    );
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(body.as_bytes());
+    let _ = stream.flush();
+}
+
+/// A unique `Request-Id`, shaped like the ones Heroku answers with.
+fn next_request_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    format!(
+        "fa4e0000-0000-4000-8000-{:012x}",
+        NEXT.fetch_add(1, Ordering::SeqCst)
+    )
+}
@@ -0,0 +1,494 @@
+//! The resources of the fake server, and the routes changing them.
+use crate::endpoints::{apps, formations, releases};
+use http::{Method, StatusCode};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// The response to a request, a status and a JSON body.
+pub(crate) type Reply = (StatusCode, Value);
+
+/// The message Heroku answers unknown routes with.
+const UNKNOWN_ROUTE: &str = "The requested API endpoint was not found. Are you using the right HTTP verb (i.e. `GET` vs. `POST`), and did you specify your intended version with the `Accept` header?";
+
+/// An app with the resources belonging to it.
+struct FakeApp {
+    app: apps::App,
+    config_vars: BTreeMap<String, String>,
+    formation: Vec<formations::Formation>,
+    /// The releases, oldest first, with the config vars they were made with
+    releases: Vec<(releases::Release, BTreeMap<String, String>)>,
+}
+
+/// Everything the fake server knows about.
+pub(crate) struct State {
+    email: String,
+    apps: Vec<FakeApp>,
+    next_id: u64,
+}
+
+impl State {
+    pub(crate) fn new(email: &str) -> State {
+        State {
+            email: email.to_string(),
+            apps: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Answer the request, changing the resources it is about.
+    pub(crate) fn handle(&mut self, method: &Method, path: &str, body: &[u8]) -> Reply {
+        let body: Value = if body.is_empty() {
+            Value::Object(Map::new())
+        } else {
+            match serde_json::from_slice(body) {
+                Ok(body) => body,
+                Err(_) => {
+                    return error(
+                        StatusCode::BAD_REQUEST,
+                        "bad_request",
+                        "Request body malformed.",
+                    )
+                }
+            }
+        };
+        let segments: Vec<&str> = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let result = match (method, segments.as_slice()) {
+            (&Method::GET, ["account", "rate-limits"]) => Ok(ok(&json!({ "remaining": 4500 }))),
+            (&Method::GET, ["apps"]) => Ok(ok(&self
+                .apps
+                .iter()
+                .map(|app| &app.app)
+                .collect::<Vec<_>>())),
+            (&Method::POST, ["apps"]) => self.create_app(&body),
+            (&Method::GET, ["apps", app]) => self.app(app).map(|app| ok(&app.app)),
+            (&Method::PATCH, ["apps", app]) => self.update_app(app, &body),
+            (&Method::DELETE, ["apps", app]) => self.delete_app(app),
+            (&Method::GET, ["apps", app, "config-vars"]) => {
+                self.app(app).map(|app| ok(&app.config_vars))
+            }
+            (&Method::PATCH, ["apps", app, "config-vars"]) => self.update_config_vars(app, &body),
+            (&Method::GET, ["apps", app, "formation"]) => {
+                self.app(app).map(|app| ok(&app.formation))
+            }
+            (&Method::GET, ["apps", app, "formation", process_type]) => {
+                self.formation(app, process_type).map(ok)
+            }
+            (&Method::PATCH, ["apps", app, "formation", process_type]) => {
+                self.update_formation(app, process_type, &body)
+            }
+            (&Method::GET, ["apps", app, "releases"]) => self.app(app).map(|app| {
+                ok(&app
+                    .releases
+                    .iter()
+                    .map(|(release, _)| release)
+                    .collect::<Vec<_>>())
+            }),
+            (&Method::POST, ["apps", app, "releases"]) => self.create_release(app, &body),
+            (&Method::GET, ["apps", app, "releases", release]) => {
+                self.release(app, release).map(ok)
+            }
+            (&Method::GET, ["apps", app, "releases", release, "config-vars"]) => self
+                .app(app)
+                .and_then(|app| find_release(app, release))
+                .map(|(_, config_vars)| ok(config_vars)),
+            _ => Err(error(StatusCode::NOT_FOUND, "not_found", UNKNOWN_ROUTE)),
+        };
+        result.unwrap_or_else(|reply| reply)
+    }
+
+    fn create_app(&mut self, body: &Value) -> Result<Reply, Reply> {
+        let name = match string(body, "name") {
+            Some(name) => {
+                self.check_name(name)?;
+                name.to_string()
+            }
+            None => format!("fake-app-{}", self.next_id + 1),
+        };
+        let id = self.id();
+        let region = string(body, "region").unwrap_or("us");
+        let stack = string(body, "stack").unwrap_or("heroku-20");
+        let now = now();
+
+        let app = apps::App {
+            acm: false,
+            archived_at: None,
+            buildpack_provided_description: None,
+            build_stack: apps::BuildStack {
+                id: self.id(),
+                name: stack.to_string(),
+            },
+            created_at: now.clone(),
+            git_url: format!("https://git.heroku.com/{}.git", name),
+            id,
+            internal_routing: None,
+            maintenance: false,
+            name: name.clone(),
+            owner: apps::Owner {
+                email: self.email.clone(),
+                id: self.id(),
+            },
+            organization: None,
+            team: None,
+            region: apps::Region {
+                id: self.id(),
+                name: region.to_string(),
+            },
+            released_at: Some(now.clone()),
+            repo_size: None,
+            slug_size: None,
+            space: None,
+            stack: apps::Stack {
+                id: self.id(),
+                name: stack.to_string(),
+            },
+            updated_at: now.clone(),
+            web_url: format!("https://{}.herokuapp.com/", name),
+        };
+        let formation = formations::Formation {
+            app: formations::App {
+                id: app.id.clone(),
+                name: name.clone(),
+            },
+            command: String::new(),
+            created_at: now.clone(),
+            id: self.id(),
+            quantity: 0,
+            size: String::from("standard-1X"),
+            r#type: String::from("web"),
+            updated_at: now,
+        };
+
+        self.apps.push(FakeApp {
+            app,
+            config_vars: BTreeMap::new(),
+            formation: vec![formation],
+            releases: Vec::new(),
+        });
+        let index = self.apps.len() - 1;
+        self.add_release(index, None, "Initial release");
+        Ok((StatusCode::CREATED, to_value(&self.apps[index].app)))
+    }
+
+    fn update_app(&mut self, app: &str, body: &Value) -> Result<Reply, Reply> {
+        let index = self.app_index(app)?;
+        if let Some(name) = string(body, "name") {
+            if name != self.apps[index].app.name {
+                self.check_name(name)?;
+            }
+        }
+
+        let fake = &mut self.apps[index];
+        if let Some(name) = string(body, "name") {
+            for formation in fake.formation.iter_mut() {
+                formation.app.name = name.to_string();
+            }
+            for (release, _) in fake.releases.iter_mut() {
+                release.app.name = name.to_string();
+            }
+        }
+
+        let app = &mut fake.app;
+        if let Some(name) = string(body, "name") {
+            app.name = name.to_string();
+            app.git_url = format!("https://git.heroku.com/{}.git", name);
+            app.web_url = format!("https://{}.herokuapp.com/", name);
+        }
+        if let Some(maintenance) = body.get("maintenance").and_then(Value::as_bool) {
+            app.maintenance = maintenance;
+        }
+        if let Some(stack) = string(body, "build_stack") {
+            app.build_stack.name = stack.to_string();
+        }
+        app.updated_at = now();
+        Ok(ok(&*app))
+    }
+
+    fn delete_app(&mut self, app: &str) -> Result<Reply, Reply> {
+        let index = self.app_index(app)?;
+        Ok(ok(&self.apps.remove(index).app))
+    }
+
+    fn update_config_vars(&mut self, app: &str, body: &Value) -> Result<Reply, Reply> {
+        let index = self.app_index(app)?;
+        let changes = match body.as_object() {
+            Some(changes) if !changes.is_empty() => changes,
+            _ => {
+                return Err(error(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "invalid_params",
+                    "Config vars must be a JSON object of names and values.",
+                ))
+            }
+        };
+
+        let (mut set, mut removed) = (Vec::new(), Vec::new());
+        let config_vars = &mut self.apps[index].config_vars;
+        for (name, value) in changes {
+            match value {
+                Value::Null => {
+                    config_vars.remove(name);
+                    removed.push(name.as_str());
+                }
+                Value::String(value) => {
+                    config_vars.insert(name.clone(), value.clone());
+                    set.push(name.as_str());
+                }
+                value => {
+                    config_vars.insert(name.clone(), value.to_string());
+                    set.push(name.as_str());
+                }
+            }
+        }
+
+        let description = [("Set", set), ("Remove", removed)]
+            .iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(action, names)| {
+                let plural = if names.len() > 1 { "s" } else { "" };
+                format!("{} {} config var{}", action, names.join(", "), plural)
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        self.add_release(index, None, &description);
+        Ok(ok(&self.apps[index].config_vars))
+    }
+
+    fn update_formation(
+        &mut self,
+        app: &str,
+        process_type: &str,
+        body: &Value,
+    ) -> Result<Reply, Reply> {
+        let index = self.app_index(app)?;
+        let quantity = match body.get("quantity") {
+            None | Some(Value::Null) => None,
+            Some(quantity) => match quantity.as_i64().filter(|quantity| *quantity >= 0) {
+                Some(quantity) => Some(quantity as i32),
+                None => {
+                    return Err(error(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        "invalid_params",
+                        "Quantity must be a positive number.",
+                    ))
+                }
+            },
+        };
+
+        let formation = formation_mut(&mut self.apps[index], process_type)?;
+        if let Some(quantity) = quantity {
+            formation.quantity = quantity;
+        }
+        if let Some(size) = string(body, "size") {
+            formation.size = size.to_string();
+        }
+        formation.updated_at = now();
+        Ok(ok(&*formation))
+    }
+
+    fn create_release(&mut self, app: &str, body: &Value) -> Result<Reply, Reply> {
+        let index = self.app_index(app)?;
+
+        // A rollback is a new release with the slug and config vars of an older one.
+        if let Some(target) = string(body, "release") {
+            let (slug, config_vars, version) = {
+                let (release, config_vars) = find_release(&self.apps[index], target)?;
+                (release.slug.clone(), config_vars.clone(), release.version)
+            };
+            self.apps[index].config_vars = config_vars;
+            let release = self.add_release(
+                index,
+                slug.map(|slug| slug.id),
+                &format!("Rollback to v{}", version),
+            );
+            return Ok((StatusCode::CREATED, to_value(&release)));
+        }
+
+        let slug = match string(body, "slug") {
+            Some(slug) => slug.to_string(),
+            None => {
+                return Err(error(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "invalid_params",
+                    "Slug can't be blank.",
+                ))
+            }
+        };
+        let description = match string(body, "description") {
+            Some(description) => description.to_string(),
+            None => format!("Deploy {}", slug.chars().take(8).collect::<String>()),
+        };
+        let release = self.add_release(index, Some(slug), &description);
+        Ok((StatusCode::CREATED, to_value(&release)))
+    }
+
+    /// Add a release to the app, with the current config vars.
+    fn add_release(
+        &mut self,
+        index: usize,
+        slug: Option<String>,
+        description: &str,
+    ) -> releases::Release {
+        let id = self.id();
+        let user = releases::User {
+            id: self.id(),
+            email: self.email.clone(),
+        };
+        let now = now();
+        let app = &mut self.apps[index];
+        // A new release keeps the slug of the previous one when it does not deploy another.
+        let slug = slug.or_else(|| {
+            app.releases
+                .last()
+                .and_then(|(release, _)| release.slug.as_ref())
+                .map(|slug| slug.id.clone())
+        });
+
+        for (release, _) in app.releases.iter_mut() {
+            release.current = false;
+        }
+        let release = releases::Release {
+            addon_plan_names: Vec::new(),
+            app: releases::App {
+                id: app.app.id.clone(),
+                name: app.app.name.clone(),
+            },
+            created_at: now.clone(),
+            current: true,
+            description: description.to_string(),
+            id,
+            output_stream_url: None,
+            slug: slug.map(|id| releases::Slug { id }),
+            status: String::from("succeeded"),
+            updated_at: now.clone(),
+            user,
+            version: app.releases.len() as i64 + 1,
+        };
+        app.releases
+            .push((release.clone(), app.config_vars.clone()));
+        app.app.released_at = Some(now);
+        release
+    }
+
+    fn app(&self, app: &str) -> Result<&FakeApp, Reply> {
+        self.app_index(app).map(|index| &self.apps[index])
+    }
+
+    fn formation(&self, app: &str, process_type: &str) -> Result<&formations::Formation, Reply> {
+        let app = self.app(app)?;
+        app.formation
+            .iter()
+            .find(|formation| formation.r#type == process_type || formation.id == process_type)
+            .ok_or_else(|| process_type_not_found(process_type))
+    }
+
+    fn release(&self, app: &str, release: &str) -> Result<&releases::Release, Reply> {
+        find_release(self.app(app)?, release).map(|(release, _)| release)
+    }
+
+    /// The position of the app with this name or id.
+    fn app_index(&self, app: &str) -> Result<usize, Reply> {
+        self.apps
+            .iter()
+            .position(|fake| fake.app.name == app || fake.app.id == app)
+            .ok_or_else(|| {
+                error(
+                    StatusCode::NOT_FOUND,
+                    "not_found",
+                    "Couldn't find that app.",
+                )
+            })
+    }
+
+    /// Check that a new app name is valid and free.
+    fn check_name(&self, name: &str) -> Result<(), Reply> {
+        let valid = name.len() >= 3
+            && name.len() <= 30
+            && name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name.ends_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "invalid_params",
+                "Name must start with a letter, end with a letter or digit and can only contain lowercase letters, digits, and dashes.",
+            ));
+        }
+        if self.apps.iter().any(|app| app.app.name == name) {
+            return Err(error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "invalid_params",
+                "Name is already taken",
+            ));
+        }
+        Ok(())
+    }
+
+    /// A new unique identifier, shaped like the UUIDs Heroku uses.
+    fn id(&mut self) -> String {
+        self.next_id += 1;
+        format!("{:08x}-0000-4000-8000-{:012x}", self.next_id, self.next_id)
+    }
+}
+
+fn find_release<'a>(
+    app: &'a FakeApp,
+    release: &str,
+) -> Result<&'a (releases::Release, BTreeMap<String, String>), Reply> {
+    app.releases
+        .iter()
+        .find(|(candidate, _)| candidate.id == release || candidate.version.to_string() == release)
+        .ok_or_else(|| {
+            error(
+                StatusCode::NOT_FOUND,
+                "not_found",
+                "Couldn't find that release.",
+            )
+        })
+}
+
+fn formation_mut<'a>(
+    app: &'a mut FakeApp,
+    process_type: &str,
+) -> Result<&'a mut formations::Formation, Reply> {
+    app.formation
+        .iter_mut()
+        .find(|formation| formation.r#type == process_type || formation.id == process_type)
+        .ok_or_else(|| process_type_not_found(process_type))
+}
+
+fn process_type_not_found(process_type: &str) -> Reply {
+    error(
+        StatusCode::NOT_FOUND,
+        "not_found",
+        &format!("Couldn't find that process type ({}).", process_type),
+    )
+}
+
+fn ok<T: Serialize + ?Sized>(value: &T) -> Reply {
+    (StatusCode::OK, to_value(value))
+}
+
+fn to_value<T: Serialize + ?Sized>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// A Heroku error, e.g. `{"id": "not_found", "message": "Couldn't find that app."}`.
+fn error(status: StatusCode, id: &str, message: &str) -> Reply {
+    (status, json!({ "id": id, "message": message }))
+}
+
+/// The string field `name` of the body, `None` if it is missing or `null`.
+fn string<'a>(body: &'a Value, name: &str) -> Option<&'a str> {
+    body.get(name).and_then(Value::as_str)
+}
+
+fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
@@ -0,0 +1,58 @@
+//! Module for middlewares, hooks that see every request of a client and its response.
+//!
+//! They add behaviour to every endpoint without wrapping them, e.g. audit logging, correlation ids or latency metrics.
+//! The requests and responses are plain `http` types with their whole body, whatever transport sends them.
+
+/// Middleware
+///
+/// Hooks called by [`HttpApiClient`](../struct.HttpApiClient.html) around every request,
+/// registered with the `middlewares` of [`HttpApiClientConfig`](../struct.HttpApiClientConfig.html).
+///
+/// `before_send` hooks run in the order of the middlewares, with the request built from the endpoint,
+/// and `after_receive` hooks run in the reverse order, with the final response, once retries are over.
+/// When a `before_send` hook returns a response, the request is not sent: the following middlewares are skipped,
+/// and the `after_receive` hooks of the previous ones get that response.
+///
+/// # Example:
+///
+/// Logging every call which changes something.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use std::sync::Arc;
+///
+/// struct AuditLog;
+///
+/// impl Middleware for AuditLog {
+///     fn after_receive(&self, request: &http::Request<Vec<u8>>, response: &mut http::Response<Vec<u8>>) {
+///         if request.method() != http::Method::GET {
+///             println!("{} {}: {}", request.method(), request.uri(), response.status());
+///         }
+///     }
+/// }
+///
+/// let config = HttpApiClientConfig {
+///     middlewares: vec![Arc::new(AuditLog)],
+///     ..HttpApiClientConfig::default()
+/// };
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent, to change it or to answer it instead of Heroku.
+    ///
+    /// Return a response, e.g. built with `http::Response::builder()`, to skip sending the request.
+    fn before_send(
+        &self,
+        _request: &mut http::Request<Vec<u8>>,
+    ) -> Option<http::Response<Vec<u8>>> {
+        None
+    }
+
+    /// Called with the response to the request, before it is parsed.
+    ///
+    /// It is not called when the request could not be sent.
+    fn after_receive(
+        &self,
+        _request: &http::Request<Vec<u8>>,
+        _response: &mut http::Response<Vec<u8>>,
+    ) {
+    }
+}
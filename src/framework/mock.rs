@@ -0,0 +1,223 @@
+//! Module for a mock client, to unit test code written against `HerokuApiClient` without a server.
+use crate::framework::{
+    apiclient::HerokuApiClient,
+    endpoint::{HerokuEndpoint, Method},
+    response::{parse_response, ApiResponse, ApiResult, HerokuApiFailure},
+};
+use http::StatusCode;
+use serde::Serialize;
+use std::sync::{Mutex, MutexGuard};
+
+/// The canned response of a [`MockApiClient`](struct.MockApiClient.html) expectation.
+#[derive(Debug)]
+pub enum MockResponse {
+    /// A response with this status and JSON body, parsed like a response from Heroku
+    Json(StatusCode, String),
+    /// A failure returned as is
+    Failure(HerokuApiFailure),
+}
+
+impl MockResponse {
+    /// A `200 OK` response with this JSON body.
+    pub fn json(body: &str) -> MockResponse {
+        MockResponse::Json(StatusCode::OK, body.to_string())
+    }
+
+    /// A failed response with a Heroku error, e.g. `error(StatusCode::NOT_FOUND, "not_found", "Couldn't find that app.")`.
+    pub fn error(status: StatusCode, id: &str, message: &str) -> MockResponse {
+        let body = serde_json::json!({ "id": id, "message": message });
+        MockResponse::Json(status, body.to_string())
+    }
+}
+
+impl From<HerokuApiFailure> for MockResponse {
+    fn from(failure: HerokuApiFailure) -> Self {
+        MockResponse::Failure(failure)
+    }
+}
+
+/// A request received by a [`MockApiClient`](struct.MockApiClient.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockCall {
+    /// the method of the endpoint
+    pub method: Method,
+    /// the path of the endpoint, e.g. `apps/my-app`
+    pub path: String,
+    /// the query of the endpoint, as JSON
+    pub query: Option<serde_json::Value>,
+    /// the body of the endpoint, as JSON
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+struct Expectation {
+    method: Method,
+    path: String,
+    response: MockResponse,
+}
+
+/// MockApiClient
+///
+/// A [`HerokuApiClient`](../apiclient/trait.HerokuApiClient.html) answering with canned responses instead of calling Heroku.
+///
+/// Each expected method and path is answered once, in the order they were registered,
+/// and every request is recorded with its query and body.
+/// A request which was not expected panics, to fail the test which made it.
+///
+/// `request_all` answers with the items of a single response, the canned responses have no `Next-Range` to follow.
+///
+/// # Example:
+///
+/// Testing a function generic over the client.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::mock::{MockApiClient, MockResponse};
+///
+/// fn remaining_calls<C: HerokuApiClient>(api_client: &C) -> i64 {
+///     api_client.request(&RatelimitDetails::new()).map(|ratelimit| ratelimit.remaining).unwrap_or(0)
+/// }
+///
+/// let api_client = MockApiClient::new();
+/// api_client.expect(Method::Get, "account/rate-limits", MockResponse::json(r#"{"remaining": 2400}"#));
+///
+/// assert_eq!(remaining_calls(&api_client), 2400);
+/// assert_eq!(api_client.calls()[0].path, "account/rate-limits");
+/// api_client.verify();
+/// ```
+#[derive(Debug, Default)]
+pub struct MockApiClient {
+    expectations: Mutex<Vec<Expectation>>,
+    calls: Mutex<Vec<MockCall>>,
+}
+
+impl MockApiClient {
+    /// Create a client expecting no request.
+    pub fn new() -> MockApiClient {
+        MockApiClient::default()
+    }
+
+    /// Answer the next request to `path` with `method` with `response`.
+    ///
+    /// The path is the one of the endpoint, e.g. `apps/my-app/releases`, a leading `/` is ignored.
+    pub fn expect(&self, method: Method, path: &str, response: MockResponse) -> &Self {
+        lock(&self.expectations).push(Expectation {
+            method,
+            path: path.trim_start_matches('/').to_string(),
+            response,
+        });
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        lock(&self.calls).clone()
+    }
+
+    /// Panic if an expected request was not made.
+    pub fn verify(&self) {
+        let expectations = lock(&self.expectations);
+        if !expectations.is_empty() {
+            panic!(
+                "MockApiClient: expected requests were not made: {}",
+                describe(&expectations)
+            );
+        }
+    }
+
+    /// Record the request and take the response expected for it, panicking if there is none.
+    fn respond<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<http::Response<Vec<u8>>>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let call = MockCall {
+            method: endpoint.method(),
+            path: endpoint.path().trim_start_matches('/').to_string(),
+            query: endpoint.query().map(to_json),
+            body: endpoint.body().map(to_json),
+        };
+
+        let response = {
+            let mut expectations = lock(&self.expectations);
+            let position = expectations.iter().position(|expectation| {
+                expectation.method == call.method && expectation.path == call.path
+            });
+            match position {
+                Some(position) => expectations.remove(position).response,
+                None => {
+                    let expected = describe(&expectations);
+                    drop(expectations);
+                    panic!(
+                        "MockApiClient: unexpected request {:?} {}, expected: {}",
+                        call.method, call.path, expected
+                    );
+                }
+            }
+        };
+        lock(&self.calls).push(call);
+
+        match response {
+            MockResponse::Json(status, body) => {
+                let response = http::Response::builder()
+                    .status(status)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(body.into_bytes())
+                    .unwrap_or_default();
+                Ok(response)
+            }
+            MockResponse::Failure(failure) => Err(failure),
+        }
+    }
+}
+
+impl HerokuApiClient for MockApiClient {
+    fn request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<ResultType>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        parse_response(self.respond(endpoint)?)
+    }
+
+    fn request_raw<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<http::Response<Vec<u8>>>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        self.respond(endpoint)
+    }
+}
+
+fn to_json<T: Serialize>(value: T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+fn describe(expectations: &[Expectation]) -> String {
+    if expectations.is_empty() {
+        return String::from("nothing");
+    }
+    expectations
+        .iter()
+        .map(|expectation| format!("{:?} {}", expectation.method, expectation.path))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panicking test may poison the lock, the expectations and calls are still consistent.
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
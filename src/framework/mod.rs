@@ -1,27 +1,81 @@
 //! Module for for authentication, api clients and response parsing.
 
 pub mod apiclient;
+#[cfg(feature = "async")]
+mod async_client;
 pub mod auth;
+pub mod cache;
+pub mod cassette;
+pub mod dyn_client;
 pub mod endpoint;
+#[cfg(feature = "fake")]
+pub mod fake;
+pub mod middleware;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod netrc;
+#[cfg(feature = "oauth")]
+pub mod oauth_flow;
+pub mod owned;
+mod pipeline;
+pub mod proxy;
+pub mod range;
+pub mod ratelimit;
+pub mod request;
+#[cfg(feature = "reqwest")]
 mod reqwest_utils;
 pub mod response;
+pub mod retry;
+pub mod transport;
+#[cfg(feature = "tracing")]
+mod trace;
 
-use crate::framework::{apiclient::HerokuApiClient, auth::AuthClient, response::match_response};
-use failure::Fallible;
-use reqwest_utils::match_reqwest_method;
+use crate::framework::{
+    apiclient::HerokuApiClient,
+    cache::ResponseCache,
+    middleware::Middleware,
+    pipeline::{Next, Pages, Pipeline},
+    proxy::ProxyConfig,
+    ratelimit::RateLimiter,
+    request::clone_request,
+    response::{set_url, HerokuApiFailure, WithMeta},
+    retry::RetryPolicy,
+    transport::Transport,
+};
 use serde::Serialize;
+use std::sync::Arc;
+use std::thread;
 use std::time::Duration;
 
-#[derive(Debug)]
+#[cfg(feature = "async")]
+pub use async_client::AsyncHttpApiClient;
+
+#[derive(Clone, Debug)]
 pub enum ApiEnvironment {
     Production,
     Custom(url::Url),
 }
 
+/// The url of the Heroku Platform API.
+const PRODUCTION_URL: &str = "https://api.heroku.com/";
+
+impl ApiEnvironment {
+    /// The url the paths of the endpoints are joined to.
+    pub fn base_url(&self) -> Result<url::Url, url::ParseError> {
+        match self {
+            ApiEnvironment::Production => url::Url::parse(PRODUCTION_URL),
+            ApiEnvironment::Custom(url) => Ok(url.clone()),
+        }
+    }
+}
+
 impl<'a> From<&'a ApiEnvironment> for url::Url {
     fn from(environment: &ApiEnvironment) -> Self {
         match environment {
-            ApiEnvironment::Production => url::Url::parse("https://api.heroku.com/").unwrap(),
+            // The production url is a valid constant, parsing it cannot fail.
+            ApiEnvironment::Production => {
+                url::Url::parse(PRODUCTION_URL).expect("the production url is valid")
+            }
             ApiEnvironment::Custom(url) => url.clone(),
         }
     }
@@ -30,13 +84,38 @@ impl<'a> From<&'a ApiEnvironment> for url::Url {
 /// The client used to make requests to Heroku.
 ///
 /// This struct contains the synchronous client.
+///
+/// It is a cheap handle: clones and sub-clients share the connection pool, the credentials, the rate limiter and the cache,
+/// and it can be sent to and used from any thread.
+///
+/// # Example:
+///
+/// Rotating the token in place, and a sub-client for a staging environment.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use std::thread;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let api_client = HttpApiClient::create("API_KEY")?;
+///
+///    let worker = api_client.clone();
+///    thread::spawn(move || {
+///        // worker.request(&AppList {}) ...
+///    });
+///
+///    // every clone sends the new token from now on, e.g. after an OAuthRegenerate
+///    api_client.set_credentials(Credentials::UserAuthToken { token: String::from("NEW_API_KEY") });
+///
+///    let staging = api_client.with_environment(ApiEnvironment::Custom(url::Url::parse("https://api.staging.example.com/")?));
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone)]
 pub struct HttpApiClient {
-    /// The base endpoint to target. By default will be heroku
-    environment: ApiEnvironment,
-    /// The authentication credential
-    credentials: auth::Credentials,
-    /// The blocking client
-    http_client: reqwest::blocking::Client,
+    /// Builds the requests, and runs them through the cache, the middlewares and the retries
+    pipeline: Pipeline,
+    /// Sends the requests, a `reqwest::blocking::Client` unless the configuration has another transport
+    transport: Arc<dyn Transport>,
 }
 
 /// Configuration for the API client. Allows users to customize its behaviour.
@@ -45,7 +124,47 @@ pub struct HttpApiClientConfig {
     /// By default this duration will be 30 seconds because that's the max timeout before Heroku terminates the request
     pub http_timeout: Duration,
     /// A default set of HTTP headers which will be sent with each API request.
+    ///
+    /// The timeout, the default headers and the connection settings below configure the default reqwest transport,
+    /// they are left to a custom `transport`.
     pub default_headers: http::HeaderMap,
+    /// The maximum time to establish a connection, included in `http_timeout`. By default there is no separate limit.
+    pub connect_timeout: Option<Duration>,
+    /// How long an idle connection is kept open to be reused. By default 90 seconds, `None` keeps it forever.
+    pub pool_idle_timeout: Option<Duration>,
+    /// The maximum number of idle connections kept open per host. By default there is no limit.
+    pub pool_max_idle_per_host: usize,
+    /// The proxy to send the requests through, see [`ProxyConfig`](proxy/struct.ProxyConfig.html).
+    /// By default the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables are used.
+    pub proxy: Option<ProxyConfig>,
+    /// Root certificates trusted on top of the system ones, e.g. the one of a TLS-intercepting proxy.
+    /// Read them with `reqwest::Certificate::from_pem`. By default there are none. Only with the `reqwest` feature.
+    #[cfg(feature = "reqwest")]
+    pub root_certificates: Vec<reqwest::Certificate>,
+    /// Appended to the `User-Agent` header of every request, e.g. `my-deploy-tool/1.2.0` sends `heroku_rs my-deploy-tool/1.2.0`.
+    /// By default there is none.
+    pub user_agent: Option<String>,
+    /// Which requests are retried when Heroku rate limits them or fails temporarily, see [`RetryPolicy`](retry/struct.RetryPolicy.html).
+    /// By default idempotent requests are attempted up to 3 times.
+    pub retry_policy: RetryPolicy,
+    /// A token bucket which throttles requests before Heroku's rate limit is reached, see [`RateLimiter`](ratelimit/struct.RateLimiter.html).
+    /// It can be shared by multiple clients. By default there is none.
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// A store for responses and their `ETag`, see [`ResponseCache`](cache/trait.ResponseCache.html).
+    /// When set, `GET` requests are sent with `If-None-Match` and a `304 Not Modified` is answered from the cache.
+    /// It can be shared by multiple clients of the same account. By default there is none.
+    pub cache: Option<Arc<dyn ResponseCache>>,
+    /// Called with the new credentials when the client refreshed an OAuth access token, see [`Credentials::OAuth`](auth/enum.Credentials.html).
+    /// Use it to persist the new token. By default there is none.
+    pub on_token_refresh: Option<auth::TokenRefreshCallback>,
+    /// Hooks called before every request is sent and after its response is received, see [`Middleware`](middleware/trait.Middleware.html).
+    /// The `before_send` hooks run in this order, the `after_receive` ones in reverse. By default there are none.
+    pub middlewares: Vec<Arc<dyn Middleware>>,
+    /// The HTTP client sending the requests, see [`Transport`](transport/trait.Transport.html).
+    /// By default it is a [`ReqwestTransport`](transport/struct.ReqwestTransport.html) built with the timeout and the default headers,
+    /// without the `reqwest` feature there is no default and the client needs one.
+    /// `AsyncHttpApiClient` calls it on the blocking thread pool of tokio, and by default sends the requests with an async reqwest client.
+    pub transport: Option<Arc<dyn Transport>>,
 }
 
 impl Default for HttpApiClientConfig {
@@ -53,6 +172,19 @@ impl Default for HttpApiClientConfig {
         HttpApiClientConfig {
             http_timeout: Duration::from_secs(30),
             default_headers: http::HeaderMap::default(),
+            connect_timeout: None,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: usize::MAX,
+            proxy: None,
+            #[cfg(feature = "reqwest")]
+            root_certificates: Vec::new(),
+            user_agent: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            cache: None,
+            on_token_refresh: None,
+            middlewares: Vec::new(),
+            transport: None,
         }
     }
 }
@@ -71,22 +203,14 @@ impl HttpApiClient {
     ///    Ok(())
     /// }
     /// ```
-    pub fn create(token: &str) -> Fallible<HttpApiClient> {
+    pub fn create(token: &str) -> Result<HttpApiClient, HerokuApiFailure> {
         let credentials: auth::Credentials = auth::Credentials::UserAuthToken {
             token: String::from(token),
         };
         let config: HttpApiClientConfig = HttpApiClientConfig::default();
         let environment: ApiEnvironment = ApiEnvironment::Production;
-        let http_client = reqwest::blocking::Client::builder()
-            .timeout(config.http_timeout)
-            .default_headers(config.default_headers)
-            .build()?;
 
-        Ok(HttpApiClient {
-            environment,
-            credentials,
-            http_client,
-        })
+        HttpApiClient::new(credentials, config, environment)
     }
 
     /// # Example 2:
@@ -108,7 +232,7 @@ impl HttpApiClient {
     ///    credentials,
     ///    HttpApiClientConfig {
     ///        http_timeout: Duration::from_secs(10),
-    ///        default_headers: http::HeaderMap::default(),
+    ///        ..HttpApiClientConfig::default()
     ///    },
     ///    ApiEnvironment::Custom(url::Url::parse("https://api.custom-somewhere.com/").unwrap()))?;
     ///
@@ -122,18 +246,54 @@ impl HttpApiClient {
         credentials: auth::Credentials,
         config: HttpApiClientConfig,
         environment: ApiEnvironment,
-    ) -> Fallible<HttpApiClient> {
-        let http_client = reqwest::blocking::Client::builder()
-            .timeout(config.http_timeout)
-            .default_headers(config.default_headers)
-            .build()?;
+    ) -> Result<HttpApiClient, HerokuApiFailure> {
+        let transport = match config.transport.clone() {
+            Some(transport) => transport,
+            None => default_transport(&config)?,
+        };
 
         Ok(HttpApiClient {
-            environment,
-            credentials,
-            http_client,
+            pipeline: Pipeline::new(credentials, config, environment),
+            transport,
         })
     }
+
+    /// The credentials the client sends now, e.g. after it refreshed an OAuth token.
+    pub fn credentials(&self) -> auth::Credentials {
+        self.pipeline.credentials.current().clone()
+    }
+
+    /// Replace the credentials of this client, of its clones and of the sub-clients sharing them.
+    ///
+    /// Requests already sent keep the previous credentials, the following ones use these.
+    pub fn set_credentials(&self, credentials: auth::Credentials) {
+        self.pipeline.credentials.replace(credentials);
+    }
+
+    /// A client sending the requests to another environment, with the same connection pool and credentials.
+    pub fn with_environment(&self, environment: ApiEnvironment) -> HttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.environment = environment;
+        client
+    }
+
+    /// A client with its own credentials, e.g. those of another account, and the same connection pool.
+    ///
+    /// It has no response cache, since the cached responses belong to the account of this client.
+    pub fn with_credentials(&self, credentials: auth::Credentials) -> HttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.credentials =
+            Arc::new(self.pipeline.credentials.with_credentials(credentials));
+        client.pipeline.cache = None;
+        client
+    }
+
+    /// A client also sending these headers with every request, unless the endpoint sets them.
+    pub fn with_headers(&self, headers: http::HeaderMap) -> HttpApiClient {
+        let mut client = self.clone();
+        client.pipeline.headers.extend(headers);
+        client
+    }
 }
 
 impl<'a> HerokuApiClient for HttpApiClient {
@@ -147,58 +307,198 @@ impl<'a> HerokuApiClient for HttpApiClient {
         QueryType: Serialize,
         BodyType: Serialize,
     {
-        // Build the request
-        let mut request = self
-            .http_client
-            .request(
-                match_reqwest_method(endpoint.method()),
-                endpoint.url(&self.environment),
-            )
-            .query(&endpoint.query());
-
-        if let Some(body) = endpoint.body() {
-            request = request.body(serde_json::to_string(&body).unwrap());
-            request = request.header(reqwest::header::CONTENT_TYPE, endpoint.content_type());
-        }
-
-        request = request.header(reqwest::header::ACCEPT, endpoint.version());
-        request = request.header(reqwest::header::USER_AGENT, endpoint.agent());
-        request = request.auth(&self.credentials);
+        let response = self.send(self.pipeline.build_request(endpoint)?)?;
 
-        let response = request.send()?;
+        Ok(response.result)
+    }
 
-        match_response(response)
+    /// Synchronously send a request to the Heroku API, keeping the response metadata.
+    fn request_with_meta<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn endpoint::HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> response::ApiResponse<WithMeta<ResultType>>
+    where
+        ResultType: response::ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        self.send(self.pipeline.build_request(endpoint)?)
     }
 
     fn request_raw<ResultType, QueryType, BodyType>(
         &self,
         endpoint: &dyn endpoint::HerokuEndpoint<ResultType, QueryType, BodyType>,
-    ) -> response::ApiResponse<reqwest::blocking::Response>
+    ) -> response::ApiResponse<http::Response<Vec<u8>>>
     where
         ResultType: response::ApiResult,
         QueryType: Serialize,
         BodyType: Serialize,
     {
-        // Build the raw request
-        let mut request = self
-            .http_client
-            .request(
-                match_reqwest_method(endpoint.method()),
-                endpoint.url(&self.environment),
-            )
-            .query(&endpoint.query());
-
-        // Add body if one was passed
-        if let Some(body) = endpoint.body() {
-            request = request.body(serde_json::to_string(&body).unwrap());
-            request = request.header(reqwest::header::CONTENT_TYPE, endpoint.content_type());
+        self.execute(self.pipeline.build_request(endpoint)?)
+    }
+
+    /// Synchronously request every page of a list endpoint, following the `Next-Range` header.
+    fn request_all<ItemType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn endpoint::HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    ) -> response::ApiResponse<Vec<ItemType>>
+    where
+        Vec<ItemType>: response::ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut pages = Pages::new(self.pipeline.build_request(endpoint)?);
+        let mut items = Vec::new();
+        while let Some(request) = pages.next_request() {
+            pages.receive(self.execute(request)?, &mut items)?;
         }
 
-        request = request.header(reqwest::header::ACCEPT, endpoint.version());
-        request = request.header(reqwest::header::USER_AGENT, endpoint.agent());
-        request = request.auth(&self.credentials);
+        Ok(items)
+    }
+}
+
+impl HttpApiClient {
+    /// Send the request and parse the response, going through the response cache if there is one.
+    fn send<ResultType: response::ApiResult>(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> response::ApiResponse<WithMeta<ResultType>> {
+        let lookup = self.pipeline.cache_lookup(&mut request);
+        let response = self.execute(request)?;
+        self.pipeline.parse(lookup, response)
+    }
+
+    /// Send the request in a `heroku_request` span recording its outcome.
+    #[cfg(feature = "tracing")]
+    fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        let span = trace::request_span(&request);
+        let start = std::time::Instant::now();
+        let result = span.in_scope(|| self.dispatch(request));
+        trace::record_result(&span, &result, start.elapsed());
+        result
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.dispatch(request)
+    }
+
+    /// Send the request through the middlewares.
+    fn dispatch(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        if !self.pipeline.has_middlewares() {
+            return self.execute_with_retries(request);
+        }
 
-        let response = request.send()?;
+        let (called, answer) = self.pipeline.before_send(&mut request);
+        let sent = clone_request(&request);
+        let mut response = match answer {
+            Some(response) => response,
+            None => self.execute_with_retries(request)?,
+        };
+        self.pipeline.after_receive(called, &sent, &mut response);
         Ok(response)
     }
+
+    /// Send the request, waiting for the rate limiter and retrying it as long as the retry policy allows.
+    ///
+    /// OAuth credentials are refreshed before they expire, and once if Heroku rejects them.
+    fn execute_with_retries(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        let mut attempts = self.pipeline.attempts(&request);
+
+        loop {
+            if self.refresh_credentials(None)? {
+                self.pipeline
+                    .credentials
+                    .authenticate(request.headers_mut());
+            }
+
+            let (retry_request, wait) = attempts.start(&request);
+            thread::sleep(wait);
+            let response = self.transport_send(request)?;
+
+            request = match attempts.next(response, retry_request) {
+                Next::Done(response) => return Ok(response),
+                Next::Reauthenticate(mut retry_request) => {
+                    self.refresh_credentials(Some(retry_request.headers()))?;
+                    self.pipeline
+                        .credentials
+                        .authenticate(retry_request.headers_mut());
+                    retry_request
+                }
+                Next::Retry(retry_request, backoff) => {
+                    thread::sleep(backoff);
+                    retry_request
+                }
+            };
+        }
+    }
+
+    /// Send the request with the transport, without retrying it.
+    fn transport_send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        let uri = request.uri().clone();
+        let mut response = self.transport.send(request)?;
+        set_url(&mut response, &uri);
+        Ok(response)
+    }
+
+    /// Refresh OAuth credentials about to expire, or OAuth credentials Heroku `rejected` in the headers of a request.
+    ///
+    /// Concurrent requests refresh them once, the others wait for the new token.
+    /// Returns whether the credentials changed.
+    #[cfg(feature = "oauth")]
+    fn refresh_credentials(
+        &self,
+        rejected: Option<&http::HeaderMap>,
+    ) -> Result<bool, HerokuApiFailure> {
+        if self.pipeline.credentials.oauth_refresh(rejected).is_none() {
+            return Ok(false);
+        }
+        let _refreshing = self.pipeline.credentials.lock_refresh();
+        // Another request may have refreshed the credentials while this one waited.
+        if let Some(refresh) = self.pipeline.credentials.oauth_refresh(rejected) {
+            let request = self.pipeline.refresh_request(&refresh)?;
+            self.pipeline
+                .refreshed(&refresh, self.transport_send(request)?)?;
+        }
+        Ok(true)
+    }
+
+    #[cfg(not(feature = "oauth"))]
+    fn refresh_credentials(
+        &self,
+        _rejected: Option<&http::HeaderMap>,
+    ) -> Result<bool, HerokuApiFailure> {
+        Ok(false)
+    }
+}
+
+/// The transport of a configuration without one, sending the requests with reqwest.
+#[cfg(feature = "reqwest")]
+fn default_transport(config: &HttpApiClientConfig) -> Result<Arc<dyn Transport>, HerokuApiFailure> {
+    let client = reqwest_utils::blocking_client(config)?;
+    Ok(Arc::new(transport::ReqwestTransport::new(client)))
+}
+
+#[cfg(not(feature = "reqwest"))]
+fn default_transport(
+    _config: &HttpApiClientConfig,
+) -> Result<Arc<dyn Transport>, HerokuApiFailure> {
+    Err(HerokuApiFailure::Client(
+        "there is no default transport without the `reqwest` feature, set the `transport` of the configuration".into(),
+    ))
 }
@@ -0,0 +1,226 @@
+//! Module for reading `.netrc` files, where the Heroku CLI keeps its credentials.
+//!
+//! After `heroku login`, the CLI writes an entry for `api.heroku.com` to `~/.netrc` (`~/_netrc` on Windows),
+//! with the account email as `login` and the API token as `password`.
+use std::error::Error;
+use std::fmt;
+
+/// The credentials of a machine in a `.netrc` file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NetrcMachine {
+    /// the `login` of the machine, the account email for Heroku
+    pub login: Option<String>,
+    /// the `password` of the machine, the API token for Heroku
+    pub password: Option<String>,
+    /// the `account` of the machine, unused by Heroku
+    pub account: Option<String>,
+}
+
+/// Netrc
+///
+/// The parsed content of a `.netrc` file.
+///
+/// [See the netrc format](https://www.gnu.org/software/inetutils/manual/html_node/The-_002enetrc-file.html)
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::framework::netrc::Netrc;
+///
+/// let netrc = Netrc::parse("machine api.heroku.com\n  login me@example.com\n  password API_KEY\n").unwrap();
+/// let heroku = netrc.machine("api.heroku.com").unwrap();
+///
+/// assert_eq!(heroku.password.as_deref(), Some("API_KEY"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Netrc {
+    /// the machines, in the order of the file
+    pub machines: Vec<(String, NetrcMachine)>,
+    /// the `default` entry, used for machines which are not listed
+    pub default: Option<NetrcMachine>,
+}
+
+/// The error returned when a `.netrc` file is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetrcError {
+    /// line of the file where the error is
+    pub line: usize,
+    /// what is wrong
+    pub message: String,
+}
+
+impl Netrc {
+    /// Parse the content of a `.netrc` file.
+    ///
+    /// Comments starting with `#` and `macdef` macros are skipped, values can be quoted with `"`.
+    pub fn parse(content: &str) -> Result<Netrc, NetrcError> {
+        let mut netrc = Netrc::default();
+        let mut tokens = Tokens::new(content);
+        // The entry being read, `None` for the default one.
+        let mut current: Option<(Option<String>, NetrcMachine)> = None;
+
+        while let Some(token) = tokens.next() {
+            match token.as_str() {
+                "machine" => {
+                    netrc.push(current.take());
+                    let name = tokens.value("machine")?;
+                    current = Some((Some(name), NetrcMachine::default()));
+                }
+                "default" => {
+                    netrc.push(current.take());
+                    current = Some((None, NetrcMachine::default()));
+                }
+                "login" | "password" | "account" => {
+                    let value = tokens.value(&token)?;
+                    let machine = match &mut current {
+                        Some((_, machine)) => machine,
+                        None => {
+                            return Err(tokens.error(format!("`{}` outside of a machine", token)))
+                        }
+                    };
+                    match token.as_str() {
+                        "login" => machine.login = Some(value),
+                        "password" => machine.password = Some(value),
+                        _ => machine.account = Some(value),
+                    }
+                }
+                "macdef" => {
+                    tokens.value("macdef")?;
+                    tokens.skip_macro();
+                }
+                other => return Err(tokens.error(format!("unexpected `{}`", other))),
+            }
+        }
+        netrc.push(current);
+
+        Ok(netrc)
+    }
+
+    /// The credentials of `name`, or the `default` ones if it is not listed.
+    pub fn machine(&self, name: &str) -> Option<&NetrcMachine> {
+        self.machines
+            .iter()
+            .find(|(machine, _)| machine == name)
+            .map(|(_, machine)| machine)
+            .or(self.default.as_ref())
+    }
+
+    fn push(&mut self, entry: Option<(Option<String>, NetrcMachine)>) {
+        match entry {
+            Some((Some(name), machine)) => self.machines.push((name, machine)),
+            Some((None, machine)) => self.default = Some(machine),
+            None => {}
+        }
+    }
+}
+
+/// Splits a `.netrc` file into whitespace separated tokens.
+struct Tokens<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(content: &'a str) -> Tokens<'a> {
+        Tokens {
+            chars: content.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    /// The value following `keyword`.
+    fn value(&mut self, keyword: &str) -> Result<String, NetrcError> {
+        self.next()
+            .ok_or_else(|| self.error(format!("missing value after `{}`", keyword)))
+    }
+
+    /// Skip the body of a `macdef`, which ends at the first empty line.
+    fn skip_macro(&mut self) {
+        let mut previous = None;
+        for c in self.chars.by_ref() {
+            if c == '\n' {
+                self.line += 1;
+                if previous == Some('\n') {
+                    return;
+                }
+            }
+            if c != '\r' {
+                previous = Some(c);
+            }
+        }
+    }
+
+    fn error(&self, message: String) -> NetrcError {
+        NetrcError {
+            line: self.line,
+            message,
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c == '#' {
+                while let Some(&c) = self.chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    self.chars.next();
+                }
+            } else if c.is_whitespace() {
+                if c == '\n' {
+                    self.line += 1;
+                }
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.skip_whitespace_and_comments();
+        let mut token = String::new();
+
+        if self.chars.peek() == Some(&'"') {
+            self.chars.next();
+            while let Some(c) = self.chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => token.extend(self.chars.next()),
+                    c => {
+                        if c == '\n' {
+                            self.line += 1;
+                        }
+                        token.push(c);
+                    }
+                }
+            }
+            return Some(token);
+        }
+
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            token.push(c);
+            self.chars.next();
+        }
+        Some(token).filter(|token| !token.is_empty())
+    }
+}
+
+impl Error for NetrcError {}
+
+impl fmt::Display for NetrcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid netrc file, line {}: {}",
+            self.line, self.message
+        )
+    }
+}
@@ -0,0 +1,387 @@
+//! Module for the OAuth web flow, to get a token acting on behalf of a Heroku user.
+//!
+//! The user opens the [`authorize_url`](struct.OAuthFlow.html#method.authorize_url) in a browser and grants access,
+//! Heroku then redirects the browser to the `redirect_uri` of the OAuth client with a `code`,
+//! which is exchanged for an access token with `OAuthTokenCreate`.
+//!
+//! [See the Heroku docs for more information about the web flow](https://devcenter.heroku.com/articles/oauth#web-application-authorization)
+use crate::endpoints::oauth::{
+    post::{Client, Grant, RefreshToken},
+    OAuthClient, OAuthToken, OAuthTokenCreate, OAuthTokenCreateParams,
+};
+use crate::framework::{
+    request::build_unauthenticated_request,
+    response::{parse_response, ApiResponse},
+    transport::Transport,
+    ApiEnvironment,
+};
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{fmt, io, thread};
+use url::Url;
+
+/// How long a connection to the listener may stay silent before it is dropped, e.g. a browser preconnect.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+/// How often the listener is polled for a new connection.
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(20);
+
+/// OAuthFlow
+///
+/// The authorization code flow of an OAuth client.
+///
+/// # Example:
+///
+/// Getting a token for the user running the tool, with an OAuth client whose redirect uri is `http://localhost:8080/callback`.
+/// ```rust,no_run
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::{oauth_flow::OAuthFlow, transport::ReqwestTransport};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET").scopes(&["read", "write"]).build();
+///    let listener = std::net::TcpListener::bind("127.0.0.1:8080")?;
+///
+///    println!("Open {} to authorize the tool", flow.authorize_url());
+///    let code = flow.receive_code(&listener)?;
+///
+///    let token = flow.exchange(&ReqwestTransport::default(), &code)?;
+///    let credentials = Credentials::oauth(&token, "CLIENT_SECRET");
+///
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OAuthFlow {
+    /// identifier of the OAuth client
+    pub client_id: String,
+    /// secret of the OAuth client
+    pub client_secret: String,
+    /// the scopes to request, e.g. `identity`, `read`, `write` or `global`
+    pub scopes: Vec<String>,
+    /// the random value sent to Heroku and expected back with the redirect, to reject forged redirects
+    pub state: String,
+    /// the base url of the Heroku identity server, `https://id.heroku.com/` by default
+    pub identity_url: Url,
+    /// the API the code is exchanged with, Heroku's by default
+    pub api_environment: ApiEnvironment,
+    /// how long `receive_code` waits for the redirect, 5 minutes by default, `None` to wait forever
+    pub timeout: Option<Duration>,
+}
+
+impl OAuthFlow {
+    /// Create a flow for an OAuth client, requesting the `identity` scope with a random state.
+    pub fn new(client_id: &str, client_secret: &str) -> OAuthFlow {
+        OAuthFlow {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            scopes: vec![String::from("identity")],
+            state: random_state(),
+            identity_url: Url::parse("https://id.heroku.com/").unwrap(),
+            api_environment: ApiEnvironment::Production,
+            timeout: Some(Duration::from_secs(5 * 60)),
+        }
+    }
+
+    /// Create a flow for an OAuth client returned by `OAuthClientCreate` or `OAuthClientDetails`.
+    pub fn for_client(client: &OAuthClient) -> OAuthFlow {
+        OAuthFlow::new(&client.id, &client.secret)
+    }
+
+    /// # scopes: the scopes to request
+    pub fn scopes(&mut self, scopes: &[&str]) -> &mut Self {
+        self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        self
+    }
+
+    /// # state: the value expected back with the redirect, random by default
+    pub fn state(&mut self, state: &str) -> &mut Self {
+        self.state = state.to_string();
+        self
+    }
+
+    /// # identity_url: the base url of the identity server
+    pub fn identity_url(&mut self, identity_url: Url) -> &mut Self {
+        self.identity_url = identity_url;
+        self
+    }
+
+    /// # api_environment: the API the code is exchanged with
+    pub fn api_environment(&mut self, api_environment: ApiEnvironment) -> &mut Self {
+        self.api_environment = api_environment;
+        self
+    }
+
+    /// # timeout: how long to wait for the redirect
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(&self) -> OAuthFlow {
+        self.clone()
+    }
+
+    /// The url the user opens to grant access to the OAuth client.
+    pub fn authorize_url(&self) -> Url {
+        let mut url = self
+            .identity_url
+            .join("oauth/authorize")
+            .unwrap_or_else(|_| self.identity_url.clone());
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("response_type", "code")
+            .append_pair("scope", &self.scopes.join(" "))
+            .append_pair("state", &self.state);
+        url
+    }
+
+    /// Wait for the browser to be redirected to `listener`, and return the authorization code.
+    ///
+    /// `listener` must listen on the host and port of the OAuth client's redirect uri.
+    /// Requests without a code, an error or a state, e.g. for `/favicon.ico`, are answered with `404 Not Found` and ignored,
+    /// and so are connections which send no request, e.g. browser preconnects.
+    /// Redirects without the state of the flow may be forged, they are answered with `400 Bad Request` and ignored too.
+    /// Fails with `OAuthFlowError::Timeout` if there is no redirect within the `timeout` of the flow.
+    pub fn receive_code(&self, listener: &TcpListener) -> Result<String, OAuthFlowError> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        listener.set_nonblocking(true)?;
+        let result = self.accept_redirect(listener, deadline);
+        listener.set_nonblocking(false)?;
+        result
+    }
+
+    fn accept_redirect(
+        &self,
+        listener: &TcpListener,
+        deadline: Option<Instant>,
+    ) -> Result<String, OAuthFlowError> {
+        loop {
+            let mut stream = match listener.accept() {
+                Ok((stream, _)) => stream,
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => {
+                    if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                        return Err(OAuthFlowError::Timeout);
+                    }
+                    thread::sleep(ACCEPT_INTERVAL);
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            let read_timeout = match deadline {
+                Some(deadline) => CONNECTION_TIMEOUT
+                    .min(deadline.saturating_duration_since(Instant::now()))
+                    .max(ACCEPT_INTERVAL),
+                None => CONNECTION_TIMEOUT,
+            };
+            stream.set_nonblocking(false)?;
+            stream.set_read_timeout(Some(read_timeout))?;
+            let query = match read_redirect(&mut stream) {
+                Ok(Some(query)) => query,
+                Ok(None) => {
+                    // The browser may have closed the connection already, it is ignored anyway.
+                    let _ = respond(&mut stream, "404 Not Found", "Not found.");
+                    continue;
+                }
+                // The connection sent no request before the timeout, or was closed.
+                Err(_) => continue,
+            };
+
+            let result = match self.check_redirect(query) {
+                Some(result) => result,
+                None => {
+                    let _ = respond(
+                        &mut stream,
+                        "400 Bad Request",
+                        "The authorization redirect has an unexpected state.",
+                    );
+                    continue;
+                }
+            };
+            let _ = match &result {
+                Ok(_) => respond(
+                    &mut stream,
+                    "200 OK",
+                    "Heroku authorization complete, you can close this window.",
+                ),
+                Err(error) => respond(&mut stream, "400 Bad Request", &error.to_string()),
+            };
+            return result;
+        }
+    }
+
+    /// Exchange the authorization code for a token, through `OAuthTokenCreate` sent with `transport`.
+    ///
+    /// The code and the client secret are the credentials, the request has no `Authorization` header.
+    /// Use [`Credentials::oauth`](../auth/enum.Credentials.html#method.oauth) to make requests with the token.
+    pub fn exchange(&self, transport: &dyn Transport, code: &str) -> ApiResponse<OAuthToken> {
+        let endpoint = OAuthTokenCreate {
+            params: OAuthTokenCreateParams {
+                client: Client {
+                    secret: &self.client_secret,
+                },
+                grant: Grant {
+                    code,
+                    type_field: "authorization_code",
+                },
+                refresh_token: RefreshToken { token: "" },
+            },
+        };
+        let request = build_unauthenticated_request(&endpoint, &self.api_environment)?;
+        parse_response(transport.send(request)?)
+    }
+
+    /// The code or the error of the redirect, `None` if it does not have the state of the flow.
+    fn check_redirect(
+        &self,
+        query: Vec<(String, String)>,
+    ) -> Option<Result<String, OAuthFlowError>> {
+        let param = |name: &str| {
+            query
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        // Anything can connect to the listener, only a redirect with the state is from Heroku.
+        if param("state").as_deref() != Some(self.state.as_str()) {
+            return None;
+        }
+        if let Some(error) = param("error") {
+            return Some(Err(OAuthFlowError::Denied {
+                error,
+                description: param("error_description"),
+            }));
+        }
+        Some(param("code").ok_or(OAuthFlowError::MissingCode))
+    }
+}
+
+// The client secret is left out, so that logging a flow does not leak it.
+impl fmt::Debug for OAuthFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OAuthFlow")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[redacted]")
+            .field("scopes", &self.scopes)
+            .field("state", &self.state)
+            .field("identity_url", &self.identity_url)
+            .field("api_environment", &self.api_environment)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// The query of the redirect, `None` if the request has no `code`, `error` or `state`.
+fn read_redirect(stream: &mut TcpStream) -> io::Result<Option<Vec<(String, String)>>> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    // Drain the headers, so the browser gets the response.
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+    }
+
+    let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let query: Vec<(String, String)> =
+        match Url::parse("http://localhost/").and_then(|base| base.join(target)) {
+            Ok(url) => url.query_pairs().into_owned().collect(),
+            Err(_) => Vec::new(),
+        };
+
+    if query
+        .iter()
+        .any(|(key, _)| key == "code" || key == "error" || key == "state")
+    {
+        Ok(Some(query))
+    } else {
+        Ok(None)
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, message: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        message.len(),
+        message
+    )?;
+    stream.flush()
+}
+
+/// A hard to guess value, from the random keys of the standard library hasher.
+fn random_state() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    (0..2)
+        .map(|_| {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u128(nanos);
+            format!("{:016x}", hasher.finish())
+        })
+        .collect()
+}
+
+/// The error returned when the OAuth web flow fails.
+#[derive(Debug)]
+pub enum OAuthFlowError {
+    /// The user denied access, or Heroku refused the authorization
+    Denied {
+        /// the OAuth error code, e.g. `access_denied`
+        error: String,
+        /// the human readable explanation, if any
+        description: Option<String>,
+    },
+    /// The redirect has the expected state but no code
+    MissingCode,
+    /// There was no redirect within the timeout of the flow
+    Timeout,
+    /// The loopback listener failed
+    Io(io::Error),
+}
+
+impl From<io::Error> for OAuthFlowError {
+    fn from(error: io::Error) -> Self {
+        OAuthFlowError::Io(error)
+    }
+}
+
+impl Error for OAuthFlowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OAuthFlowError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OAuthFlowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OAuthFlowError::Denied { error, description } => match description {
+                Some(description) => write!(f, "Authorization denied: {} ({})", error, description),
+                None => write!(f, "Authorization denied: {}", error),
+            },
+            OAuthFlowError::MissingCode => {
+                write!(f, "The authorization redirect has no code")
+            }
+            OAuthFlowError::Timeout => {
+                write!(f, "The authorization redirect did not arrive in time")
+            }
+            OAuthFlowError::Io(error) => {
+                write!(f, "Could not receive the authorization redirect: {}", error)
+            }
+        }
+    }
+}
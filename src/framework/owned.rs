@@ -0,0 +1,177 @@
+//! Module for owned endpoints, to build a request on one thread and send it on another, or to persist it in a job queue.
+use crate::framework::{
+    dyn_client::DynRequest,
+    endpoint::{HerokuEndpoint, Method},
+    range::Range,
+    response::{ApiResult, HerokuApiFailure},
+};
+use http::HeaderMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// OwnedEndpoint
+///
+/// Any endpoint, with nothing borrowed: it is `'static`, `Send` and `Sync`, and it can be serialized and deserialized.
+///
+/// It is sent like the endpoint it was built from, and its response is parsed into the same result type.
+///
+/// It is serialized as its [`DynRequest`](../dyn_client/struct.DynRequest.html),
+/// with the [`NAME`](../response/trait.ApiResult.html#associatedconstant.NAME) of its result type in `result_type`:
+/// deserializing it as an `OwnedEndpoint` of another result type fails.
+///
+/// # Example:
+///
+/// Queuing a dyno creation as JSON, and sending it later from another thread.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::owned::OwnedEndpoint;
+/// use std::collections::HashMap;
+/// use std::thread;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let command = String::from("bash");
+///    let mut env = HashMap::new();
+///    env.insert("COLUMNS", "80");
+///
+///    let endpoint = OwnedEndpoint::new(&DynoCreate::new("APP_ID", &command).env(env).build())?;
+///    let job = serde_json::to_string(&endpoint)?;
+///    drop(command);
+///
+///    let worker = thread::spawn(move || {
+///        let api_client = HttpApiClient::create("API_KEY").unwrap();
+///        let endpoint: OwnedEndpoint<Dyno> = serde_json::from_str(&job).unwrap();
+///        // let dyno = api_client.request(&endpoint);
+///        endpoint.request().path.clone()
+///    });
+///
+///    assert_eq!(worker.join().unwrap(), "apps/APP_ID/dynos");
+///    Ok(())
+/// }
+/// ```
+pub struct OwnedEndpoint<ResultType> {
+    request: DynRequest,
+    result: PhantomData<fn() -> ResultType>,
+}
+
+/// The serialized form of an `OwnedEndpoint`.
+#[derive(Serialize, Deserialize)]
+struct Tagged<Request> {
+    result_type: String,
+    #[serde(flatten)]
+    request: Request,
+}
+
+impl<ResultType: ApiResult> OwnedEndpoint<ResultType> {
+    /// Copy everything the endpoint sends.
+    ///
+    /// Fails with `HerokuApiFailure::Request` if its query or body cannot be serialized.
+    pub fn new<QueryType, BodyType>(
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> Result<OwnedEndpoint<ResultType>, HerokuApiFailure>
+    where
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        Ok(OwnedEndpoint::from_request(DynRequest::new(endpoint)?))
+    }
+
+    /// An endpoint sending this request, whose response is parsed into `ResultType`.
+    pub fn from_request(request: DynRequest) -> OwnedEndpoint<ResultType> {
+        OwnedEndpoint {
+            request,
+            result: PhantomData,
+        }
+    }
+}
+
+impl<ResultType> OwnedEndpoint<ResultType> {
+    /// The request this endpoint sends.
+    pub fn request(&self) -> &DynRequest {
+        &self.request
+    }
+
+    /// The request this endpoint sends, without its result type.
+    pub fn into_request(self) -> DynRequest {
+        self.request
+    }
+}
+
+impl<ResultType: ApiResult> Serialize for OwnedEndpoint<ResultType> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tagged {
+            result_type: ResultType::NAME.to_string(),
+            request: &self.request,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, ResultType: ApiResult> Deserialize<'de> for OwnedEndpoint<ResultType> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tagged = Tagged::<DynRequest>::deserialize(deserializer)?;
+        if tagged.result_type != ResultType::NAME {
+            return Err(de::Error::custom(format!(
+                "the endpoint returns `{}`, not `{}`",
+                tagged.result_type,
+                ResultType::NAME
+            )));
+        }
+        Ok(OwnedEndpoint {
+            request: tagged.request,
+            result: PhantomData,
+        })
+    }
+}
+
+impl<ResultType> Clone for OwnedEndpoint<ResultType> {
+    fn clone(&self) -> Self {
+        OwnedEndpoint {
+            request: self.request.clone(),
+            result: PhantomData,
+        }
+    }
+}
+
+impl<ResultType> fmt::Debug for OwnedEndpoint<ResultType> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OwnedEndpoint").field(&self.request).finish()
+    }
+}
+
+impl<ResultType> PartialEq for OwnedEndpoint<ResultType> {
+    fn eq(&self, other: &Self) -> bool {
+        self.request == other.request
+    }
+}
+
+impl<ResultType: ApiResult> HerokuEndpoint<ResultType, Value, Value> for OwnedEndpoint<ResultType> {
+    fn method(&self) -> Method {
+        self.request.method
+    }
+    fn path(&self) -> String {
+        self.request.path.clone()
+    }
+    fn query(&self) -> Option<Value> {
+        self.request.query.clone()
+    }
+    fn body(&self) -> Option<Value> {
+        self.request.body.clone()
+    }
+    fn content_type(&self) -> &str {
+        &self.request.content_type
+    }
+    fn version(&self) -> &str {
+        &self.request.version
+    }
+    fn agent(&self) -> &str {
+        &self.request.agent
+    }
+    fn range(&self) -> Option<Range> {
+        self.request.range.clone()
+    }
+    fn headers(&self) -> HeaderMap {
+        self.request.headers.clone()
+    }
+}
@@ -0,0 +1,329 @@
+//! Module for what the blocking and the async clients do with a request, but sending it and waiting.
+#[cfg(feature = "oauth")]
+use crate::framework::auth::OAuthRefresh;
+#[cfg(feature = "tracing")]
+use crate::framework::trace;
+use crate::framework::{
+    auth::{CredentialStore, Credentials},
+    cache::{CacheLookup, ResponseCache},
+    endpoint::HerokuEndpoint,
+    middleware::Middleware,
+    range,
+    ratelimit::RateLimiter,
+    request::{self, clone_request},
+    response::{
+        decode, parse_response, set_url, ApiResponse, ApiResult, HerokuApiFailure, ResponseMeta,
+        WithMeta,
+    },
+    retry::RetryPolicy,
+    ApiEnvironment, HttpApiClientConfig,
+};
+use http::{HeaderMap, HeaderValue, Request, Response, StatusCode};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Pipeline
+///
+/// Builds the requests of a client, and runs them through the response cache, the middlewares,
+/// the rate limiter, the retries and the OAuth refreshes.
+/// The clients only send the requests and wait, blocking the thread or not.
+#[derive(Clone)]
+pub(crate) struct Pipeline {
+    /// The base endpoint to target. By default will be heroku
+    pub(crate) environment: ApiEnvironment,
+    /// The authentication credential, refreshed when it is an expiring OAuth token
+    pub(crate) credentials: Arc<CredentialStore>,
+    /// Which failed requests to send again
+    retry_policy: RetryPolicy,
+    /// Throttles requests before the Heroku quota runs out
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Stores responses with their ETag, to send conditional requests
+    pub(crate) cache: Option<Arc<dyn ResponseCache>>,
+    /// Hooks called around every request
+    middlewares: Arc<[Arc<dyn Middleware>]>,
+    /// Sent with every request which has no header of the same name
+    pub(crate) headers: HeaderMap,
+    /// Appended to the `User-Agent` of every request
+    user_agent: Option<String>,
+}
+
+impl Pipeline {
+    pub(crate) fn new(
+        credentials: Credentials,
+        config: HttpApiClientConfig,
+        environment: ApiEnvironment,
+    ) -> Pipeline {
+        Pipeline {
+            environment,
+            credentials: Arc::new(CredentialStore::new(credentials, config.on_token_refresh)),
+            retry_policy: config.retry_policy,
+            rate_limiter: config.rate_limiter,
+            cache: config.cache,
+            middlewares: config.middlewares.into(),
+            headers: HeaderMap::new(),
+            user_agent: config.user_agent,
+        }
+    }
+
+    /// Build the request for the endpoint, with the body, the Heroku headers and the credentials.
+    pub(crate) fn build_request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> Result<Request<Vec<u8>>, HerokuApiFailure>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut request =
+            request::build_request(endpoint, &self.environment, &self.credentials.current())?;
+        request::apply_client_headers(&mut request, &self.headers, self.user_agent.as_deref())?;
+        Ok(request)
+    }
+
+    /// Build the request for the endpoint, with the body and the Heroku headers.
+    #[cfg(feature = "oauth")]
+    fn unauthenticated_request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> Result<Request<Vec<u8>>, HerokuApiFailure>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        let mut request = request::build_unauthenticated_request(endpoint, &self.environment)?;
+        request::apply_client_headers(&mut request, &self.headers, self.user_agent.as_deref())?;
+        Ok(request)
+    }
+
+    /// Look the request up in the response cache, making it conditional when a response is cached.
+    pub(crate) fn cache_lookup(&self, request: &mut Request<Vec<u8>>) -> Option<CacheLookup<'_>> {
+        let cache = self.cache.as_ref()?;
+        let method = request.method().clone();
+        let url = request.uri().to_string();
+        CacheLookup::start(cache.as_ref(), &method, &url, request.headers_mut())
+    }
+
+    /// Parse the response, from the cache when Heroku answered it was not modified, and store it with its ETag.
+    pub(crate) fn parse<ResultType: ApiResult>(
+        &self,
+        lookup: Option<CacheLookup<'_>>,
+        response: Response<Vec<u8>>,
+    ) -> ApiResponse<WithMeta<ResultType>> {
+        let meta = ResponseMeta::new(response.status(), response.headers());
+
+        if let Some(mut lookup) = lookup {
+            if let Some(body) = lookup.not_modified(meta.status) {
+                let result = decode(meta.status, &body)?;
+                return Ok(WithMeta { result, meta });
+            }
+            if let (true, Some(etag)) = (meta.status.is_success(), &meta.etag) {
+                lookup.store(etag, response.body());
+                let result = decode(meta.status, response.body())?;
+                return Ok(WithMeta { result, meta });
+            }
+        }
+
+        let result = parse_response(response)?;
+        Ok(WithMeta { result, meta })
+    }
+
+    /// Whether there are middlewares, or the requests go straight to the retries.
+    pub(crate) fn has_middlewares(&self) -> bool {
+        !self.middlewares.is_empty()
+    }
+
+    /// Call the `before_send` hooks until one answers the request.
+    ///
+    /// Returns how many hooks were called without answering, and the answer if there is one.
+    pub(crate) fn before_send(
+        &self,
+        request: &mut Request<Vec<u8>>,
+    ) -> (usize, Option<Response<Vec<u8>>>) {
+        for (called, middleware) in self.middlewares.iter().enumerate() {
+            if let Some(mut response) = middleware.before_send(request) {
+                set_url(&mut response, request.uri());
+                return (called, Some(response));
+            }
+        }
+        (self.middlewares.len(), None)
+    }
+
+    /// Call the `after_receive` hooks of the `called` middlewares, in reverse order.
+    pub(crate) fn after_receive(
+        &self,
+        called: usize,
+        sent: &Request<Vec<u8>>,
+        response: &mut Response<Vec<u8>>,
+    ) {
+        for middleware in self.middlewares[..called].iter().rev() {
+            middleware.after_receive(sent, response);
+        }
+    }
+
+    /// The attempts at sending the request, as many as the retry policy allows.
+    pub(crate) fn attempts(&self, request: &Request<Vec<u8>>) -> Attempts<'_> {
+        Attempts {
+            pipeline: self,
+            retries_method: self.retry_policy.retries_method(request.method()),
+            attempt: 1,
+            reauthenticate: self.credentials.refreshable(),
+        }
+    }
+
+    /// The request exchanging the refresh token for a new access token.
+    #[cfg(feature = "oauth")]
+    pub(crate) fn refresh_request(
+        &self,
+        refresh: &OAuthRefresh,
+    ) -> Result<Request<Vec<u8>>, HerokuApiFailure> {
+        // The refresh token is the credential, the expired access token is not sent.
+        self.unauthenticated_request(&refresh.endpoint())
+    }
+
+    /// Replace the credentials with the token Heroku answered the refresh with.
+    #[cfg(feature = "oauth")]
+    pub(crate) fn refreshed(
+        &self,
+        refresh: &OAuthRefresh,
+        response: Response<Vec<u8>>,
+    ) -> Result<(), HerokuApiFailure> {
+        let token = parse_response(response)?;
+        self.credentials.refreshed(refresh, &token);
+        Ok(())
+    }
+}
+
+/// What to do once a request was answered.
+pub(crate) enum Next {
+    /// Return the response
+    Done(Response<Vec<u8>>),
+    /// Refresh the credentials rejected in the headers of the request, and send it again with the new ones
+    Reauthenticate(Request<Vec<u8>>),
+    /// Wait, and send the request again
+    Retry(Request<Vec<u8>>, Duration),
+}
+
+/// The attempts at sending a request.
+///
+/// OAuth credentials are refreshed once if Heroku rejects them.
+pub(crate) struct Attempts<'a> {
+    pipeline: &'a Pipeline,
+    retries_method: bool,
+    attempt: u32,
+    reauthenticate: bool,
+}
+
+impl<'a> Attempts<'a> {
+    fn retries(&self) -> bool {
+        self.retries_method && self.attempt < self.pipeline.retry_policy.max_attempts
+    }
+
+    /// Start an attempt, taking a token from the rate limiter.
+    ///
+    /// Returns a copy of the request if it may be sent again, and how long to wait before sending it.
+    pub(crate) fn start(&self, request: &Request<Vec<u8>>) -> (Option<Request<Vec<u8>>>, Duration) {
+        #[cfg(feature = "tracing")]
+        trace::record_attempt(self.attempt);
+
+        let retry_request = if self.retries() || self.reauthenticate {
+            Some(clone_request(request))
+        } else {
+            None
+        };
+        let wait = match &self.pipeline.rate_limiter {
+            Some(rate_limiter) => rate_limiter.reserve(),
+            None => Duration::from_secs(0),
+        };
+        (retry_request, wait)
+    }
+
+    /// Decide what to do with the response to the attempt, given the copy of the request `start` returned.
+    pub(crate) fn next(
+        &mut self,
+        response: Response<Vec<u8>>,
+        retry_request: Option<Request<Vec<u8>>>,
+    ) -> Next {
+        if let Some(rate_limiter) = &self.pipeline.rate_limiter {
+            rate_limiter.update(response.headers());
+        }
+
+        let retry_request = match retry_request {
+            Some(retry_request) => retry_request,
+            None => return Next::Done(response),
+        };
+        let retry_policy = &self.pipeline.retry_policy;
+        if self.reauthenticate && response.status() == StatusCode::UNAUTHORIZED {
+            self.reauthenticate = false;
+            Next::Reauthenticate(retry_request)
+        } else if self.retries() && retry_policy.retries_status(response.status()) {
+            let backoff = match retry_policy.backoff(self.attempt, response.headers()) {
+                Some(backoff) => backoff,
+                None => return Next::Done(response),
+            };
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                status = response.status().as_u16(),
+                backoff_ms = backoff.as_millis() as u64,
+                "retrying the request"
+            );
+            self.attempt += 1;
+            Next::Retry(retry_request, backoff)
+        } else {
+            Next::Done(response)
+        }
+    }
+}
+
+/// The pages of a list endpoint, following the `Next-Range` header.
+pub(crate) struct Pages {
+    request: Request<Vec<u8>>,
+    range: Option<HeaderValue>,
+    done: bool,
+}
+
+impl Pages {
+    pub(crate) fn new(request: Request<Vec<u8>>) -> Pages {
+        Pages {
+            request,
+            range: None,
+            done: false,
+        }
+    }
+
+    /// The request for the next page, none once the last one was received.
+    pub(crate) fn next_request(&self) -> Option<Request<Vec<u8>>> {
+        if self.done {
+            return None;
+        }
+        let mut request = clone_request(&self.request);
+        if let Some(range) = &self.range {
+            request
+                .headers_mut()
+                .insert(http::header::RANGE, range.clone());
+        }
+        Some(request)
+    }
+
+    /// Add the items of the page to `items`, and move to the next page if there is one.
+    pub(crate) fn receive<ItemType>(
+        &mut self,
+        response: Response<Vec<u8>>,
+        items: &mut Vec<ItemType>,
+    ) -> Result<(), HerokuApiFailure>
+    where
+        Vec<ItemType>: ApiResult,
+    {
+        let next = range::next_range(response.status(), response.headers());
+        let page: Vec<ItemType> = parse_response(response)?;
+        items.extend(page);
+
+        match next {
+            Some(next) if self.range.as_ref() != Some(&next) => self.range = Some(next),
+            _ => self.done = true,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! Module for sending the requests through an HTTP proxy, e.g. behind a corporate egress.
+use url::Url;
+
+/// ProxyConfig
+///
+/// A proxy the default transport sends every request through, except the requests to the hosts of `no_proxy`.
+///
+/// Without one, the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables are used.
+/// Setting one ignores those variables.
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let proxy = ProxyConfig {
+///        credentials: Some((String::from("user"), String::from("password"))),
+///        no_proxy: vec![String::from("localhost"), String::from(".internal.example.com")],
+///        ..ProxyConfig::new(url::Url::parse("http://proxy.example.com:3128")?)
+///    };
+///
+///    assert!(proxy.bypasses("api.internal.example.com"));
+///    assert!(!proxy.bypasses("api.heroku.com"));
+///
+///    let api_client = HttpApiClient::new(
+///        Credentials::UserAuthToken { token: String::from("API_KEY") },
+///        HttpApiClientConfig {
+///            proxy: Some(proxy),
+///            ..HttpApiClientConfig::default()
+///        },
+///        ApiEnvironment::Production,
+///    )?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProxyConfig {
+    /// The url of the proxy, e.g. `http://proxy.example.com:3128`.
+    pub url: Url,
+    /// The username and password sent to the proxy with basic authentication.
+    pub credentials: Option<(String, String)>,
+    /// The hosts reached without the proxy, with the syntax of `NO_PROXY`:
+    /// `example.com` or `.example.com` match the domain and its subdomains, an ip matches itself and `*` matches every host.
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// A proxy for every host, without credentials.
+    pub fn new(url: Url) -> ProxyConfig {
+        ProxyConfig {
+            url,
+            credentials: None,
+            no_proxy: Vec::new(),
+        }
+    }
+
+    /// Whether the requests to `host` are sent without the proxy.
+    pub fn bypasses(&self, host: &str) -> bool {
+        let host = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+        self.no_proxy.iter().any(|pattern| {
+            let pattern = pattern.trim().trim_start_matches('.').to_ascii_lowercase();
+            pattern == "*"
+                || (!pattern.is_empty()
+                    && (host == pattern || host.ends_with(&format!(".{}", pattern))))
+        })
+    }
+
+    /// The reqwest proxy, deciding per request url whether to go through the proxy.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn to_reqwest(&self) -> reqwest::Proxy {
+        let proxy = self.clone();
+        let reqwest_proxy = reqwest::Proxy::custom(move |url| match url.host_str() {
+            Some(host) if proxy.bypasses(host) => None,
+            _ => Some(proxy.url.clone()),
+        });
+        match &self.credentials {
+            Some((username, password)) => reqwest_proxy.basic_auth(username, password),
+            None => reqwest_proxy,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Module for Heroku's `Range` header, used to sort, limit and paginate list endpoints.
+//!
+//! List endpoints return at most 200 items per request (1000 max). When there is more to fetch,
+//! Heroku answers with `206 Partial Content` and a `Next-Range` header, which is sent back as the `Range` header of the next request.
+//!
+//! [See the Heroku docs for more information](https://devcenter.heroku.com/articles/platform-api-reference#ranges)
+use crate::framework::endpoint::{HerokuEndpoint, Method};
+use crate::framework::response::{ApiResult, HerokuApiFailure};
+use crate::framework::ApiEnvironment;
+use http::{HeaderMap, HeaderValue, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use url::Url;
+
+/// The response header Heroku uses to point to the next page.
+pub(crate) const NEXT_RANGE: &str = "Next-Range";
+
+/// Returns the `Next-Range` value if the response is a partial one.
+pub(crate) fn next_range(status: StatusCode, headers: &HeaderMap) -> Option<HeaderValue> {
+    if status != StatusCode::PARTIAL_CONTENT {
+        return None;
+    }
+    headers.get(NEXT_RANGE).cloned()
+}
+
+/// Sort order of a [`Range`](struct.Range.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RangeOrder {
+    Asc,
+    Desc,
+}
+
+/// Range
+///
+/// The value of the `Range` header sent to a list endpoint, e.g. `version ..; max=5, order=desc`.
+///
+/// [See Heroku documentation for more information about ranges](https://devcenter.heroku.com/articles/platform-api-reference#ranges)
+///
+/// # Example:
+///
+/// Requesting the last 5 releases of an app.
+/// ```rust
+/// use heroku_rs::prelude::*;
+///#    let api_client = HttpApiClient::create("API_KEY").unwrap();
+///
+/// let range = Range::new("version").order(RangeOrder::Desc).max(5).build();
+/// let response = api_client.request(&ReleaseList::new("APP_ID").with_range(range));
+///
+///match response {
+///     Ok(success) => println!("Success: {:#?}", success),
+///     Err(e) => println!("Error: {}", e),
+///}
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    /// the field to sort by, usually one of `id`, `name`, `version` or `created_at`, depending on the endpoint
+    pub field: String,
+    /// value of the field to start from, inclusive
+    pub start: Option<String>,
+    /// value of the field to end at, inclusive
+    pub end: Option<String>,
+    /// sort order, ascending by default
+    pub order: Option<RangeOrder>,
+    /// maximum number of items per page, 200 by default and at most 1000
+    pub max: Option<u32>,
+}
+
+impl Range {
+    /// Create a range sorted by `field`, without any other options.
+    pub fn new(field: &str) -> Range {
+        Range {
+            field: field.to_string(),
+            start: None,
+            end: None,
+            order: None,
+            max: None,
+        }
+    }
+
+    /// # start: value of the field to start from
+    pub fn start(&mut self, start: &str) -> &mut Self {
+        self.start = Some(start.to_string());
+        self
+    }
+
+    /// # end: value of the field to end at
+    pub fn end(&mut self, end: &str) -> &mut Self {
+        self.end = Some(end.to_string());
+        self
+    }
+
+    /// # order: sort order, one of `RangeOrder::Asc` or `RangeOrder::Desc`
+    pub fn order(&mut self, order: RangeOrder) -> &mut Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// # max: maximum number of items per page
+    pub fn max(&mut self, max: u32) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn build(&self) -> Range {
+        self.clone()
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}..{}",
+            self.field,
+            self.start.as_deref().unwrap_or_default(),
+            self.end.as_deref().unwrap_or_default()
+        )?;
+
+        let mut options = Vec::new();
+        if let Some(max) = self.max {
+            options.push(format!("max={}", max));
+        }
+        if let Some(order) = self.order {
+            options.push(match order {
+                RangeOrder::Asc => String::from("order=asc"),
+                RangeOrder::Desc => String::from("order=desc"),
+            });
+        }
+        if !options.is_empty() {
+            write!(f, "; {}", options.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// An endpoint sent with a `Range` header.
+///
+/// This is created through [`ListEndpoint::with_range`](trait.ListEndpoint.html#method.with_range).
+pub struct RangedEndpoint<E> {
+    /// the wrapped list endpoint
+    pub endpoint: E,
+    /// the range to send with it
+    pub range: Range,
+}
+
+impl<E, ItemType, QueryType, BodyType> HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>
+    for RangedEndpoint<E>
+where
+    E: HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    Vec<ItemType>: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    fn query(&self) -> Option<QueryType> {
+        self.endpoint.query()
+    }
+    fn body(&self) -> Option<BodyType> {
+        self.endpoint.body()
+    }
+    fn url(&self, environment: &ApiEnvironment) -> Result<Url, HerokuApiFailure> {
+        self.endpoint.url(environment)
+    }
+    fn content_type(&self) -> &str {
+        self.endpoint.content_type()
+    }
+    fn version(&self) -> &str {
+        self.endpoint.version()
+    }
+    fn agent(&self) -> &str {
+        self.endpoint.agent()
+    }
+    fn range(&self) -> Option<Range> {
+        Some(self.range.clone())
+    }
+    fn headers(&self) -> HeaderMap {
+        self.endpoint.headers()
+    }
+}
+
+/// Implemented for every endpoint returning a list, e.g. `AppList`, `ReleaseList` or `BuildList`.
+pub trait ListEndpoint<ItemType, QueryType, BodyType>:
+    HerokuEndpoint<Vec<ItemType>, QueryType, BodyType> + Sized
+where
+    Vec<ItemType>: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    /// Attach a [`Range`](struct.Range.html) to the endpoint, to choose the sort field, order and page size.
+    fn with_range(self, range: Range) -> RangedEndpoint<Self> {
+        RangedEndpoint {
+            endpoint: self,
+            range,
+        }
+    }
+}
+
+impl<E, ItemType, QueryType, BodyType> ListEndpoint<ItemType, QueryType, BodyType> for E
+where
+    E: HerokuEndpoint<Vec<ItemType>, QueryType, BodyType>,
+    Vec<ItemType>: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+}
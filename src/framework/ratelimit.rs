@@ -0,0 +1,145 @@
+//! Module for throttling requests on the client side, before Heroku starts rejecting them.
+use http::HeaderMap;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The response header Heroku uses to report the remaining request budget.
+pub(crate) const RATELIMIT_REMAINING: &str = "RateLimit-Remaining";
+
+/// RateLimiter
+///
+/// A token bucket shared by every request of a client, and across threads.
+///
+/// Each request takes one token, and the bucket refills evenly over time. When the bucket is empty, requests wait
+/// until a token is available instead of being rejected by Heroku with `429 Too Many Requests`.
+///
+/// Heroku reports the actual remaining budget in the `RateLimit-Remaining` header of every response,
+/// the bucket is synced with it after each request so that other clients using the same account are accounted for.
+///
+/// [See Heroku documentation for more information about rate limits](https://devcenter.heroku.com/articles/platform-api-reference#rate-limits)
+///
+/// # Example:
+///
+/// Using the Heroku quota of 4500 requests per hour, shared by two clients.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use std::sync::Arc;
+///
+/// let rate_limiter = Arc::new(RateLimiter::default());
+///
+/// let config = HttpApiClientConfig {
+///     rate_limiter: Some(rate_limiter.clone()),
+///     ..HttpApiClientConfig::default()
+/// };
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The maximum number of tokens in the bucket
+    capacity: f64,
+    /// Tokens added to the bucket per second
+    refill_rate: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens, negative when requests are waiting for tokens
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Default for RateLimiter {
+    /// The Heroku quota, 4500 requests per hour.
+    fn default() -> Self {
+        RateLimiter::new(4500, Duration::from_secs(60 * 60))
+    }
+}
+
+impl RateLimiter {
+    /// Create a full bucket of `capacity` tokens, refilled completely over `period`.
+    pub fn new(capacity: u32, period: Duration) -> RateLimiter {
+        let capacity = f64::from(capacity.max(1));
+        RateLimiter {
+            capacity,
+            refill_rate: capacity / period.as_secs_f64().max(f64::EPSILON),
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// The number of requests which can be sent right now without waiting.
+    pub fn remaining(&self) -> u32 {
+        let mut bucket = self.bucket();
+        self.refill(&mut bucket);
+        bucket.tokens.max(0.0) as u32
+    }
+
+    /// Sync the bucket with the budget reported by Heroku, e.g. from [`RatelimitDetails`](../../endpoints/misc/get/struct.RatelimitDetails.html).
+    pub fn set_remaining(&self, remaining: u32) {
+        let mut bucket = self.bucket();
+        self.refill(&mut bucket);
+        // Keep the debt of requests already waiting for a token.
+        let waiting = bucket.tokens.min(0.0);
+        bucket.tokens = f64::from(remaining).min(self.capacity) + waiting;
+    }
+
+    /// Take a token if one is available, without waiting.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket();
+        self.refill(&mut bucket);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Take a token, blocking the current thread until one is available.
+    pub fn acquire(&self) {
+        let wait = self.reserve();
+        if wait > Duration::from_secs(0) {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Take a token, and return how long to wait before it can be used.
+    pub(crate) fn reserve(&self) -> Duration {
+        let mut bucket = self.bucket();
+        self.refill(&mut bucket);
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.refill_rate)
+        }
+    }
+
+    /// Sync the bucket from the `RateLimit-Remaining` response header, if there is one.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let remaining = headers
+            .get(RATELIMIT_REMAINING)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u32>().ok());
+        if let Some(remaining) = remaining {
+            self.set_remaining(remaining);
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_rate).min(self.capacity);
+        bucket.refilled_at = now;
+    }
+
+    fn bucket(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        // The bucket is always left consistent, so a poisoned lock is still usable.
+        self.bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
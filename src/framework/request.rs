@@ -0,0 +1,146 @@
+//! Module for building the HTTP request of an endpoint, without sending it.
+//!
+//! [`build_request`](fn.build_request.html) and [`parse_response`](../response/fn.parse_response.html)
+//! are the two halves of a Heroku call, independent of any HTTP client:
+//! send the `http::Request` with hyper, ureq or a test harness, and parse the `http::Response` it got.
+//! `HttpApiClient` and `AsyncHttpApiClient` build their requests the same way.
+use crate::framework::{
+    auth::Credentials,
+    endpoint::HerokuEndpoint,
+    response::{ApiResult, HerokuApiFailure},
+    ApiEnvironment,
+};
+use http::header::{self, HeaderMap, HeaderValue};
+use serde::Serialize;
+
+/// Build the request of an endpoint
+///
+/// The request has the url of the endpoint in the environment with its query, its JSON body,
+/// the Heroku `Accept`, `User-Agent` and `Range` headers, the headers of the endpoint and the `Authorization` of the credentials.
+///
+/// # Example:
+///
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::{request::build_request, response::parse_response};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let credentials = Credentials::UserAuthToken { token: String::from("API_KEY") };
+///    let request = build_request(&AppDetails { app_id: "my-app" }, &ApiEnvironment::Production, &credentials)?;
+///
+///    assert_eq!(request.uri(), "https://api.heroku.com/apps/my-app");
+///    assert_eq!(request.headers()["authorization"], "Bearer API_KEY");
+///
+///    // send it with any HTTP client, and parse what it answered
+///    let response = http::Response::builder()
+///        .status(404)
+///        .body(br#"{"id": "not_found", "message": "Couldn't find that app."}"#.to_vec())?;
+///    let failure = parse_response::<App>(response).unwrap_err();
+///
+///    assert!(failure.is_not_found());
+///    Ok(())
+/// }
+/// ```
+pub fn build_request<ResultType, QueryType, BodyType>(
+    endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    environment: &ApiEnvironment,
+    credentials: &Credentials,
+) -> Result<http::Request<Vec<u8>>, HerokuApiFailure>
+where
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    let mut request = build_unauthenticated_request(endpoint, environment)?;
+    for (name, value) in credentials.headers() {
+        let value = HeaderValue::from_str(&value).map_err(invalid)?;
+        request.headers_mut().insert(name, value);
+    }
+    Ok(request)
+}
+
+/// Build the request of an endpoint without credentials, e.g. to refresh an OAuth token.
+pub(crate) fn build_unauthenticated_request<ResultType, QueryType, BodyType>(
+    endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    environment: &ApiEnvironment,
+) -> Result<http::Request<Vec<u8>>, HerokuApiFailure>
+where
+    ResultType: ApiResult,
+    QueryType: Serialize,
+    BodyType: Serialize,
+{
+    let mut url = endpoint.url(environment)?;
+    if let Some(query) = endpoint.query() {
+        query
+            .serialize(serde_urlencoded::Serializer::new(
+                &mut url.query_pairs_mut(),
+            ))
+            .map_err(invalid)?;
+        if url.query() == Some("") {
+            url.set_query(None);
+        }
+    }
+
+    let mut request = http::Request::builder()
+        .method(http::Method::from(endpoint.method()))
+        .uri(url.as_str());
+
+    // Add body if one was passed
+    let body = match endpoint.body() {
+        Some(body) => {
+            request = request.header(header::CONTENT_TYPE, endpoint.content_type());
+            serde_json::to_vec(&body).map_err(invalid)?
+        }
+        None => Vec::new(),
+    };
+
+    request = request.header(header::ACCEPT, endpoint.version());
+    request = request.header(header::USER_AGENT, endpoint.agent());
+    if let Some(range) = endpoint.range() {
+        request = request.header(header::RANGE, range.to_string());
+    }
+
+    let mut request = request.body(body).map_err(invalid)?;
+    request.headers_mut().extend(endpoint.headers());
+    Ok(request)
+}
+
+/// Add the headers of a client to the request, where it has none of the same name,
+/// and the application suffix to its `User-Agent`, e.g. `heroku_rs my-deploy-tool/1.2.0`.
+pub(crate) fn apply_client_headers(
+    request: &mut http::Request<Vec<u8>>,
+    headers: &HeaderMap,
+    user_agent: Option<&str>,
+) -> Result<(), HerokuApiFailure> {
+    for name in headers.keys() {
+        if !request.headers().contains_key(name) {
+            for value in headers.get_all(name) {
+                request.headers_mut().append(name, value.clone());
+            }
+        }
+    }
+
+    if let Some(suffix) = user_agent {
+        let user_agent = match request.headers().get(header::USER_AGENT) {
+            Some(agent) => format!("{} {}", agent.to_str().map_err(invalid)?, suffix),
+            None => String::from(suffix),
+        };
+        let user_agent = HeaderValue::from_str(&user_agent).map_err(invalid)?;
+        request.headers_mut().insert(header::USER_AGENT, user_agent);
+    }
+    Ok(())
+}
+
+/// A copy of a request to send it again, e.g. to retry it; its extensions are not copied.
+pub(crate) fn clone_request(request: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+fn invalid<E: std::error::Error + Send + Sync + 'static>(error: E) -> HerokuApiFailure {
+    HerokuApiFailure::Request(Box::new(error))
+}
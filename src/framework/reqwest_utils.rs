@@ -1,13 +1,120 @@
-use crate::framework::endpoint::Method;
+use crate::framework::response::HerokuApiFailure;
+use crate::framework::HttpApiClientConfig;
 use reqwest;
 
-/// Match the reqwest methods to our enum Method.
-pub fn match_reqwest_method(method: Method) -> reqwest::Method {
-    match method {
-        Method::Get => reqwest::Method::GET,
-        Method::Post => reqwest::Method::POST,
-        Method::Delete => reqwest::Method::DELETE,
-        Method::Put => reqwest::Method::PUT,
-        Method::Patch => reqwest::Method::PATCH,
+/// Apply the timeouts, headers and connection settings of the configuration to a blocking or async reqwest client builder,
+/// which have the same methods but no common trait.
+macro_rules! configure_builder {
+    ($builder:expr, $config:expr) => {{
+        let config: &HttpApiClientConfig = $config;
+        let mut builder = $builder
+            .timeout(config.http_timeout)
+            .default_headers(config.default_headers.clone())
+            .pool_idle_timeout(config.pool_idle_timeout)
+            .pool_max_idle_per_host(config.pool_max_idle_per_host);
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(proxy.to_reqwest());
+        }
+        for certificate in &config.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        builder
+    }};
+}
+
+/// Build the blocking reqwest client of the default transport, with the timeouts, headers and connection settings of the configuration.
+pub fn blocking_client(
+    config: &HttpApiClientConfig,
+) -> Result<reqwest::blocking::Client, HerokuApiFailure> {
+    configure_builder!(reqwest::blocking::Client::builder(), config)
+        .build()
+        .map_err(|error| HerokuApiFailure::Client(Box::new(error)))
+}
+
+/// Build the async reqwest client, with the timeouts, headers and connection settings of the configuration.
+#[cfg(feature = "async")]
+pub fn async_client(config: &HttpApiClientConfig) -> Result<reqwest::Client, HerokuApiFailure> {
+    configure_builder!(reqwest::Client::builder(), config)
+        .build()
+        .map_err(|error| HerokuApiFailure::Client(Box::new(error)))
+}
+
+/// Turn a request built by `request::build_request` into a reqwest blocking request.
+pub fn into_reqwest_request(
+    request: http::Request<Vec<u8>>,
+) -> Result<reqwest::blocking::Request, HerokuApiFailure> {
+    let (parts, body) = request.into_parts();
+    let mut request = reqwest::blocking::Request::new(parts.method, parse_url(&parts.uri)?);
+    *request.headers_mut() = parts.headers;
+    if !body.is_empty() {
+        *request.body_mut() = Some(body.into());
     }
+    Ok(request)
+}
+
+/// Read a reqwest blocking response into a plain response, with its whole body and its `url::Url` in the extensions.
+pub fn into_http_response(
+    response: reqwest::blocking::Response,
+) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+    let mut received = http::Response::builder()
+        .status(response.status())
+        .version(response.version());
+    if let Some(headers) = received.headers_mut() {
+        *headers = response.headers().clone();
+    }
+    // The url after the redirects, for `request_raw` callers.
+    let url = response.url().clone();
+    let body = response.bytes().map_err(transport_failure)?.to_vec();
+    let mut received = received
+        .body(body)
+        .map_err(|error| HerokuApiFailure::Transport(Box::new(error)))?;
+    received.extensions_mut().insert(url);
+    Ok(received)
+}
+
+/// Turn a request built by `request::build_request` into a reqwest async request.
+#[cfg(feature = "async")]
+pub fn into_async_reqwest_request(
+    request: http::Request<Vec<u8>>,
+) -> Result<reqwest::Request, HerokuApiFailure> {
+    let (parts, body) = request.into_parts();
+    let mut request = reqwest::Request::new(parts.method, parse_url(&parts.uri)?);
+    *request.headers_mut() = parts.headers;
+    if !body.is_empty() {
+        *request.body_mut() = Some(body.into());
+    }
+    Ok(request)
+}
+
+/// Read a reqwest async response into a plain response, with its whole body and its `url::Url` in the extensions.
+#[cfg(feature = "async")]
+pub async fn into_async_http_response(
+    response: reqwest::Response,
+) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+    let mut received = http::Response::builder()
+        .status(response.status())
+        .version(response.version());
+    if let Some(headers) = received.headers_mut() {
+        *headers = response.headers().clone();
+    }
+    // The url after the redirects, for `request_raw` callers.
+    let url = response.url().clone();
+    let body = response.bytes().await.map_err(transport_failure)?.to_vec();
+    let mut received = received
+        .body(body)
+        .map_err(|error| HerokuApiFailure::Transport(Box::new(error)))?;
+    received.extensions_mut().insert(url);
+    Ok(received)
+}
+
+/// A reqwest error sending the request or reading its response, e.g. a connection refused or a timeout.
+pub fn transport_failure(error: reqwest::Error) -> HerokuApiFailure {
+    HerokuApiFailure::Transport(Box::new(error))
+}
+
+fn parse_url(uri: &http::Uri) -> Result<url::Url, HerokuApiFailure> {
+    url::Url::parse(&uri.to_string()).map_err(|error| HerokuApiFailure::Request(Box::new(error)))
 }
@@ -1,3 +1,4 @@
+use http::StatusCode;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Debug;
@@ -13,15 +14,195 @@ pub struct HerokuApiError {
     pub message: String,
     /// id of error raised
     pub id: String,
+    /// reference url with more information about the error
+    #[serde(default)]
+    pub url: Option<String>,
+    /// the raw response body, kept when it could not be parsed as a Heroku error, e.g. an HTML page from a proxy
+    #[serde(skip)]
+    pub raw_body: Option<String>,
+    /// the `Request-Id` header of the response, to quote when contacting Heroku support
+    #[serde(skip)]
+    pub request_id: Option<String>,
 }
 
-/// An enum to classify which errors are what.
+impl HerokuApiError {
+    /// The kind of error, derived from the error id
+    pub fn kind(&self) -> HerokuApiErrorKind {
+        HerokuApiErrorKind::from(self.id.as_str())
+    }
+}
+
+/// The kind of a [`HerokuApiError`](struct.HerokuApiError.html), derived from its id.
+///
+/// [See the Heroku docs for the error ids](https://devcenter.heroku.com/articles/platform-api-reference#error-responses)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HerokuApiErrorKind {
+    /// `bad_request`: request invalid, validate usage and try again
+    BadRequest,
+    /// `unauthorized`: request not authenticated, API token is missing, invalid or expired
+    Unauthorized,
+    /// `delinquent`: either the account has become delinquent as a result of non-payment, or the account’s payment method must be confirmed to continue
+    Delinquent,
+    /// `forbidden`: request not authorized, provided credentials do not provide access to specified resource
+    Forbidden,
+    /// `suspended`: request not authorized, account or application was suspended
+    Suspended,
+    /// `two_factor`: request requires a second factor, sent through the `Heroku-Two-Factor-Code` header
+    TwoFactor,
+    /// `not_found`: request failed, the specified resource does not exist
+    NotFound,
+    /// `conflict`: request failed, see response body for suggested resolution
+    Conflict,
+    /// `requested_range_not_satisfiable`: the `Range` header of the request is invalid
+    RequestedRangeNotSatisfiable,
+    /// `invalid_params`: request failed, invalid parameters
+    InvalidParams,
+    /// `verification_required`: request failed, enter billing information in the Heroku dashboard before utilizing resources
+    VerificationRequired,
+    /// `rate_limit`: request failed, wait for rate limits to reset and try again
+    RateLimit,
+    /// `internal_server_error`: error occurred, we are notified, but contact support if the issue persists
+    InternalServerError,
+    /// `service_unavailable`: API is unavailable, check response body or Heroku status for details
+    ServiceUnavailable,
+    /// Any other error id, including an empty one when the body could not be parsed
+    Other(String),
+}
+
+impl From<&str> for HerokuApiErrorKind {
+    fn from(id: &str) -> Self {
+        match id {
+            "bad_request" => HerokuApiErrorKind::BadRequest,
+            "unauthorized" => HerokuApiErrorKind::Unauthorized,
+            "delinquent" => HerokuApiErrorKind::Delinquent,
+            "forbidden" => HerokuApiErrorKind::Forbidden,
+            "suspended" => HerokuApiErrorKind::Suspended,
+            "two_factor" => HerokuApiErrorKind::TwoFactor,
+            "not_found" => HerokuApiErrorKind::NotFound,
+            "conflict" => HerokuApiErrorKind::Conflict,
+            "requested_range_not_satisfiable" => HerokuApiErrorKind::RequestedRangeNotSatisfiable,
+            "invalid_params" => HerokuApiErrorKind::InvalidParams,
+            "verification_required" => HerokuApiErrorKind::VerificationRequired,
+            "rate_limit" => HerokuApiErrorKind::RateLimit,
+            "internal_server_error" => HerokuApiErrorKind::InternalServerError,
+            "service_unavailable" => HerokuApiErrorKind::ServiceUnavailable,
+            other => HerokuApiErrorKind::Other(other.to_string()),
+        }
+    }
+}
+
+/// The error type of this crate, an enum to classify which errors are what.
+///
+/// It implements `std::error::Error` and is `Send + Sync + 'static`, so it composes with `Box<dyn Error>` and other error handling crates.
+///
+/// `Invalid` only exists with the `reqwest` feature, so matches need a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HerokuApiFailure {
     /// If Heroku API returned a Error code, this enum is used to handle the error
-    Error(reqwest::StatusCode, HerokuApiError),
+    Error(StatusCode, HerokuApiError),
     /// If there was a invalid response, or the response failed, this enum is used to handle the error
+    #[cfg(feature = "reqwest")]
     Invalid(reqwest::Error),
+    /// If the response was successful, but its body could not be decoded into the expected struct
+    Decode {
+        /// status of the response
+        status: StatusCode,
+        /// path to the field which failed to decode, e.g. `[0].owner.email`
+        path: String,
+        /// the part of the body around the error
+        snippet: String,
+        /// the underlying JSON error
+        source: serde_json::Error,
+    },
+    /// If the HTTP client could not be built from the configuration, or there is no transport to send the requests
+    Client(Box<dyn Error + Send + Sync>),
+    /// If the request could not be built from the endpoint, e.g. a body which does not serialize or an invalid header value
+    Request(Box<dyn Error + Send + Sync>),
+    /// If the transport could not send the request or read its response, e.g. a connection error
+    Transport(Box<dyn Error + Send + Sync>),
+}
+
+impl HerokuApiFailure {
+    /// The HTTP status of the response, if there was one
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            HerokuApiFailure::Error(status, _) => Some(*status),
+            #[cfg(feature = "reqwest")]
+            HerokuApiFailure::Invalid(error) => error.status(),
+            HerokuApiFailure::Decode { status, .. } => Some(*status),
+            HerokuApiFailure::Client(_)
+            | HerokuApiFailure::Request(_)
+            | HerokuApiFailure::Transport(_) => None,
+        }
+    }
+
+    /// The kind of error Heroku returned, `None` if the request failed before Heroku answered
+    pub fn kind(&self) -> Option<HerokuApiErrorKind> {
+        match self {
+            HerokuApiFailure::Error(_, error) => Some(error.kind()),
+            _ => None,
+        }
+    }
+
+    /// The resource does not exist, `404 Not Found`
+    pub fn is_not_found(&self) -> bool {
+        self.has_status(404) || self.kind() == Some(HerokuApiErrorKind::NotFound)
+    }
+
+    /// The credentials are missing, invalid or expired, `401 Unauthorized`
+    pub fn is_unauthorized(&self) -> bool {
+        self.has_status(401) || self.kind() == Some(HerokuApiErrorKind::Unauthorized)
+    }
+
+    /// The credentials do not give access to the resource, `403 Forbidden`
+    pub fn is_forbidden(&self) -> bool {
+        self.has_status(403) || self.kind() == Some(HerokuApiErrorKind::Forbidden)
+    }
+
+    /// The request needs a second factor, sent through the `Heroku-Two-Factor-Code` header
+    pub fn is_two_factor_required(&self) -> bool {
+        self.kind() == Some(HerokuApiErrorKind::TwoFactor)
+    }
+
+    /// The account ran out of requests, `429 Too Many Requests`
+    pub fn is_rate_limited(&self) -> bool {
+        self.has_status(429) || self.kind() == Some(HerokuApiErrorKind::RateLimit)
+    }
+
+    /// The parameters of the request were rejected, `422 Unprocessable Entity`
+    pub fn is_invalid_params(&self) -> bool {
+        self.kind() == Some(HerokuApiErrorKind::InvalidParams)
+    }
+
+    /// The failure is likely temporary and the same request may succeed later:
+    /// rate limits, `502`, `503` and `504` responses, timeouts and connection errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HerokuApiFailure::Error(status, _) => {
+                matches!(status.as_u16(), 429 | 502 | 503 | 504)
+            }
+            #[cfg(feature = "reqwest")]
+            HerokuApiFailure::Invalid(error) => error.is_timeout() || error.is_connect(),
+            #[cfg(feature = "reqwest")]
+            HerokuApiFailure::Transport(error) => error
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|error| error.is_timeout() || error.is_connect()),
+            _ => false,
+        }
+    }
+
+    /// The `Request-Id` Heroku gave to the failed request, to quote when contacting Heroku support
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            HerokuApiFailure::Error(_, error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn has_status(&self, status: u16) -> bool {
+        self.status().map(|code| code.as_u16()) == Some(status)
+    }
 }
 
 impl PartialEq for HerokuApiError {
@@ -30,7 +211,19 @@ impl PartialEq for HerokuApiError {
     }
 }
 
-impl Error for HerokuApiFailure {}
+impl Error for HerokuApiFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HerokuApiFailure::Error(_, error) => Some(error),
+            #[cfg(feature = "reqwest")]
+            HerokuApiFailure::Invalid(error) => Some(error),
+            HerokuApiFailure::Decode { source, .. } => Some(source),
+            HerokuApiFailure::Client(error) => Some(error.as_ref()),
+            HerokuApiFailure::Request(error) => Some(error.as_ref()),
+            HerokuApiFailure::Transport(error) => Some(error.as_ref()),
+        }
+    }
+}
 impl Error for HerokuApiError {}
 
 impl Eq for HerokuApiFailure {}
@@ -39,12 +232,19 @@ impl Eq for HerokuApiError {}
 impl PartialEq for HerokuApiFailure {
     fn eq(&self, other: &HerokuApiFailure) -> bool {
         match (self, other) {
+            #[cfg(feature = "reqwest")]
             (HerokuApiFailure::Invalid(e1), HerokuApiFailure::Invalid(e2)) => {
                 e1.to_string() == e2.to_string()
             }
             (HerokuApiFailure::Error(status1, e1), HerokuApiFailure::Error(status2, e2)) => {
                 status1 == status2 && e1 == e2
             }
+            (HerokuApiFailure::Decode { .. }, HerokuApiFailure::Decode { .. })
+            | (HerokuApiFailure::Client(_), HerokuApiFailure::Client(_))
+            | (HerokuApiFailure::Request(_), HerokuApiFailure::Request(_))
+            | (HerokuApiFailure::Transport(_), HerokuApiFailure::Transport(_)) => {
+                self.to_string() == other.to_string()
+            }
             _ => false,
         }
     }
@@ -56,6 +256,7 @@ impl fmt::Display for HerokuApiError {
     }
 }
 
+#[cfg(feature = "reqwest")]
 impl From<reqwest::Error> for HerokuApiFailure {
     fn from(error: reqwest::Error) -> Self {
         HerokuApiFailure::Invalid(error)
@@ -69,10 +270,27 @@ impl fmt::Display for HerokuApiFailure {
                 let mut output = "".to_owned();
                 output.push_str(&format!("HTTP: {}", status));
 
-                output.push_str(&format!("\n{} {} ", error.id, error.message));
+                match &error.raw_body {
+                    Some(body) if error.id.is_empty() => output.push_str(&format!("\n{}", body)),
+                    _ => output.push_str(&format!("\n{} {} ", error.id, error.message)),
+                }
                 write!(f, "{}", output)
             }
+            #[cfg(feature = "reqwest")]
             HerokuApiFailure::Invalid(err) => write!(f, "{}", err),
+            HerokuApiFailure::Decode {
+                status,
+                path,
+                snippet,
+                source,
+            } => write!(
+                f,
+                "HTTP: {}\nInvalid response body at `{}`: {}\n{}",
+                status, path, source, snippet
+            ),
+            HerokuApiFailure::Client(err) => write!(f, "Could not build the HTTP client: {}", err),
+            HerokuApiFailure::Request(err) => write!(f, "Could not build the request: {}", err),
+            HerokuApiFailure::Transport(err) => write!(f, "Could not send the request: {}", err),
         }
     }
 }
@@ -0,0 +1,61 @@
+use http::{HeaderMap, StatusCode};
+
+/// The response header Heroku uses to identify a request, quote it when contacting Heroku support.
+pub(crate) const REQUEST_ID: &str = "Request-Id";
+
+/// ResponseMeta
+///
+/// What Heroku tells about a response besides its body: the status code and the Heroku specific headers.
+///
+/// [See the Heroku docs for more information about these headers](https://devcenter.heroku.com/articles/platform-api-reference#clients)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// status of the response
+    pub status: StatusCode,
+    /// unique identifier of the request, from the `Request-Id` header
+    pub request_id: Option<String>,
+    /// number of requests left in the account's budget, from the `RateLimit-Remaining` header
+    pub ratelimit_remaining: Option<u32>,
+    /// version of the returned resource, from the `ETag` header
+    pub etag: Option<String>,
+    /// deprecation or other warning about the request, from the `Warning` header
+    pub warning: Option<String>,
+    /// range of the next page of a list endpoint, from the `Next-Range` header of a `206 Partial Content` response
+    pub next_range: Option<String>,
+}
+
+impl ResponseMeta {
+    /// Read the metadata from the status and headers of a response.
+    pub(crate) fn new(status: StatusCode, headers: &HeaderMap) -> ResponseMeta {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+        };
+
+        ResponseMeta {
+            status,
+            request_id: header(REQUEST_ID),
+            ratelimit_remaining: header(crate::framework::ratelimit::RATELIMIT_REMAINING)
+                .and_then(|value| value.trim().parse().ok()),
+            etag: header(http::header::ETAG.as_str()),
+            warning: header(http::header::WARNING.as_str()),
+            next_range: crate::framework::range::next_range(status, headers)
+                .and_then(|value| value.to_str().ok().map(|value| value.to_string())),
+        }
+    }
+}
+
+/// WithMeta
+///
+/// A parsed response body, together with the [`ResponseMeta`](struct.ResponseMeta.html) of the response.
+///
+/// This is returned by [`request_with_meta`](../apiclient/trait.HerokuApiClient.html#tymethod.request_with_meta).
+#[derive(Debug)]
+pub struct WithMeta<T> {
+    /// the parsed body
+    pub result: T,
+    /// the status and headers of the response
+    pub meta: ResponseMeta,
+}
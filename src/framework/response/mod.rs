@@ -1,36 +1,130 @@
+#[cfg(feature = "reqwest")]
 extern crate reqwest;
 extern crate serde_json;
+use http::{HeaderMap, StatusCode};
 use serde::de::DeserializeOwned;
 use std::fmt::Debug;
 mod error;
+mod meta;
 
 pub use error::*;
+pub use meta::*;
 /// A type to return parsed Result<T, heroku_rs::framework::response::error::HerokuApiFailure>
 pub type ApiResponse<T> = Result<T, HerokuApiFailure>;
 
 /// Match the response we just got from the API and return a parsed struct
+#[cfg(feature = "reqwest")]
 pub fn match_response<T: ApiResult>(api_response: reqwest::blocking::Response) -> ApiResponse<T> {
     let api_status = api_response.status();
 
     if api_status.is_success() {
-        let parsed_response: Result<T, reqwest::Error> = api_response.json();
-        match parsed_response {
-            Ok(response) => Ok(response),
-            Err(e) => Err(HerokuApiFailure::Invalid(e)),
-        }
+        let body = api_response.bytes()?;
+        decode(api_status, &body)
     } else {
-        let parsed: Result<HerokuApiError, reqwest::Error> = api_response.json();
-        let errors = parsed.unwrap_or_default();
-        Err(HerokuApiFailure::Error(api_status, errors))
+        let request_id = request_id(api_response.headers());
+        let body = api_response.text().unwrap_or_default();
+        Err(HerokuApiFailure::Error(api_status, parse_error(body, request_id)))
+    }
+}
+
+/// Parse a response received with any HTTP client, like `match_response` does, see `request::build_request`
+pub fn parse_response<T: ApiResult>(response: http::Response<Vec<u8>>) -> ApiResponse<T> {
+    let status = response.status();
+
+    if status.is_success() {
+        decode(status, response.body())
+    } else {
+        let request_id = request_id(response.headers());
+        let body = String::from_utf8_lossy(response.body()).into_owned();
+        Err(HerokuApiFailure::Error(status, parse_error(body, request_id)))
+    }
+}
+
+/// Parse a response received with any HTTP client, together with the response metadata
+pub fn parse_response_with_meta<T: ApiResult>(
+    response: http::Response<Vec<u8>>,
+) -> ApiResponse<WithMeta<T>> {
+    let meta = ResponseMeta::new(response.status(), response.headers());
+    let result = parse_response(response)?;
+    Ok(WithMeta { result, meta })
+}
+
+/// Keep the url of the request in the extensions of its response, unless the transport put the one it was received from.
+pub(crate) fn set_url(response: &mut http::Response<Vec<u8>>, uri: &http::Uri) {
+    if response.extensions().get::<url::Url>().is_none() {
+        if let Ok(url) = url::Url::parse(&uri.to_string()) {
+            response.extensions_mut().insert(url);
+        }
     }
 }
 
+/// Decode the body of a successful response, pointing at the field that failed if it does not match `T`.
+pub(crate) fn decode<T: ApiResult>(status: StatusCode, body: &[u8]) -> ApiResponse<T> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let path = error.path().to_string();
+        let source = error.into_inner();
+        HerokuApiFailure::Decode {
+            status,
+            path,
+            snippet: snippet(body, &source),
+            source,
+        }
+    })
+}
+
+/// The part of the body around the position of a JSON error.
+fn snippet(body: &[u8], error: &serde_json::Error) -> String {
+    const AROUND: usize = 80;
+
+    let line_start: usize = body
+        .split(|byte| *byte == b'\n')
+        .take(error.line().saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum();
+    let position = (line_start + error.column().saturating_sub(1)).min(body.len());
+    let start = position.saturating_sub(AROUND);
+    let end = (position + AROUND).min(body.len());
+
+    String::from_utf8_lossy(&body[start..end]).into_owned()
+}
+
+/// Parse the body of a failed response, keeping it whole if it is not a Heroku error.
+fn parse_error(body: String, request_id: Option<String>) -> HerokuApiError {
+    let error = match serde_json::from_str::<HerokuApiError>(&body) {
+        Ok(error) => error,
+        Err(_) => HerokuApiError {
+            raw_body: Some(body).filter(|body| !body.is_empty()),
+            ..HerokuApiError::default()
+        },
+    };
+    HerokuApiError { request_id, ..error }
+}
+
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
 // Some endpoints return empty objects, empty vectors or just ().
-impl ApiResult for Empty {}
-impl ApiResult for () {}
-impl ApiResult for Vec<Empty> {}
+impl ApiResult for Empty {
+    const NAME: &'static str = "Empty";
+}
+impl ApiResult for () {
+    const NAME: &'static str = "()";
+}
+impl ApiResult for Vec<Empty> {
+    const NAME: &'static str = "Vec<Empty>";
+}
 
-pub trait ApiResult: DeserializeOwned + Debug {}
+pub trait ApiResult: DeserializeOwned + Debug {
+    /// The name of the type, e.g. `Vec<Release>`, which tags the serialized [`OwnedEndpoint`](../owned/struct.OwnedEndpoint.html)s returning it.
+    ///
+    /// Unlike `std::any::type_name`, it stays the same whatever the compiler.
+    const NAME: &'static str;
+}
 
 // This because Heroku returns a empty object in some responses.
 #[derive(Deserialize, Serialize, Debug)]
@@ -0,0 +1,133 @@
+//! Module for retrying requests which failed because of rate limiting or a transient server error.
+use crate::framework::endpoint::Method;
+use http::{HeaderMap, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// RetryPolicy
+///
+/// Decides which failed requests the client sends again, and how long it waits in between.
+///
+/// The wait doubles after each attempt, starting at `initial_backoff` and capped at `max_backoff`.
+/// If the response has a `Retry-After` header, the client waits that long instead,
+/// or returns the response without retrying if it asks to wait longer than `max_backoff`.
+///
+/// By default, idempotent requests (`GET`, `PUT`, `DELETE`) are attempted up to 3 times when Heroku answers with
+/// `429 Too Many Requests`, `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`.
+///
+/// # Example:
+///
+/// Retrying every request up to 5 times on rate limits only.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use std::time::Duration;
+///
+/// let config = HttpApiClientConfig {
+///     retry_policy: RetryPolicy {
+///         max_attempts: 5,
+///         initial_backoff: Duration::from_secs(1),
+///         retry_statuses: vec![429],
+///         retry_methods: vec![Method::Get, Method::Post, Method::Put, Method::Delete, Method::Patch],
+///         ..RetryPolicy::default()
+///     },
+///     ..HttpApiClientConfig::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The longest the client will wait between two attempts. A longer `Retry-After` is not waited for, the request fails instead.
+    pub max_backoff: Duration,
+    /// Randomize each wait between half and all of the computed backoff, so that clients don't retry in lockstep.
+    pub jitter: bool,
+    /// The response status codes that are retried.
+    pub retry_statuses: Vec<u16>,
+    /// The request methods that are retried. Only idempotent methods are retried by default.
+    pub retry_methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_statuses: vec![429, 502, 503, 504],
+            retry_methods: vec![Method::Get, Method::Put, Method::Delete],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether a request with this method may be sent again.
+    pub(crate) fn retries_method(&self, method: &http::Method) -> bool {
+        self.max_attempts > 1
+            && self
+                .retry_methods
+                .iter()
+                .any(|retry_method| http::Method::from(*retry_method) == *method)
+    }
+
+    /// Whether a response with this status should be retried.
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status.as_u16())
+    }
+
+    /// How long to wait before sending the request again, `retry` being 1 for the first retry.
+    ///
+    /// `None` if the `Retry-After` of the response is longer than `max_backoff`, the request is not retried then.
+    pub(crate) fn backoff(&self, retry: u32, headers: &HeaderMap) -> Option<Duration> {
+        if let Some(retry_after) = retry_after(headers) {
+            return Some(retry_after).filter(|retry_after| *retry_after <= self.max_backoff);
+        }
+
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            Some(backoff / 2 + backoff.mul_f64(random_fraction() / 2.0))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// A number in `[0, 1)`, good enough for jitter without pulling in a random number generator.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or_default();
+    hasher.write_u32(nanos);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,164 @@
+//! Tracing spans around the requests of `HttpApiClient` and `AsyncHttpApiClient`, behind the `tracing` feature.
+//!
+//! Only the method, the path and the response headers are recorded, never the query, the body or the request headers,
+//! so credentials and config var values stay out of the traces.
+use crate::framework::response::{request_id, HerokuApiFailure};
+use std::time::Duration;
+use tracing::{field, Span};
+
+/// The literal segments of the paths of `endpoints`, the other segments are identifiers.
+const ROUTE_SEGMENTS: &[&str] = &[
+    "accept",
+    "account",
+    "acm",
+    "actions",
+    "addon-attachments",
+    "addon-region-capabilities",
+    "addon-services",
+    "addons",
+    "app-setups",
+    "app-transfers",
+    "apps",
+    "authorizations",
+    "build-cache",
+    "buildpack-installations",
+    "builds",
+    "clients",
+    "collaborators",
+    "config",
+    "config-vars",
+    "confirm",
+    "credits",
+    "deprovision",
+    "domains",
+    "dyno-sizes",
+    "dynos",
+    "enterprise-accounts",
+    "features",
+    "finalize",
+    "formation",
+    "inbound-ruleset",
+    "inbound-rulesets",
+    "invitations",
+    "invoice-address",
+    "invoices",
+    "keys",
+    "latest-builds",
+    "latest-deployments",
+    "latest-releases",
+    "log-drains",
+    "log-sessions",
+    "members",
+    "nat",
+    "oauth",
+    "outbound-ruleset",
+    "outbound-rulesets",
+    "password-resets",
+    "permissions",
+    "pipeline-couplings",
+    "pipeline-promotions",
+    "pipeline-stack",
+    "pipeline-transfers",
+    "pipelines",
+    "preferences",
+    "promotion-targets",
+    "provision",
+    "rate-limits",
+    "recover",
+    "regenerate-tokens",
+    "region-capabilities",
+    "regions",
+    "releases",
+    "resolve",
+    "review-app",
+    "review-app-config",
+    "review-apps",
+    "rotate-credentials",
+    "slugs",
+    "sms-number",
+    "sni-endpoints",
+    "sources",
+    "spaces",
+    "ssl-endpoints",
+    "stacks",
+    "stage",
+    "stop",
+    "teams",
+    "test-cases",
+    "test-nodes",
+    "test-runs",
+    "tokens",
+    "transfer",
+    "users",
+    "vpn-connections",
+    "webhook-deliveries",
+    "webhook-events",
+    "webhooks",
+    "~",
+];
+
+/// The span of a request, with the fields known once it is answered left empty.
+pub(crate) fn request_span(request: &http::Request<Vec<u8>>) -> Span {
+    let path = request.uri().path();
+    let span = tracing::info_span!(
+        "heroku_request",
+        method = %request.method(),
+        path = %path_template(path),
+        app_id = field::Empty,
+        status = field::Empty,
+        request_id = field::Empty,
+        attempts = field::Empty,
+        duration_ms = field::Empty,
+        error = field::Empty,
+    );
+    if let Some(app_id) = app_id(path) {
+        span.record("app_id", &app_id);
+    }
+    span
+}
+
+/// Record the outcome of the request on its span.
+pub(crate) fn record_result(
+    span: &Span,
+    result: &Result<http::Response<Vec<u8>>, HerokuApiFailure>,
+    duration: Duration,
+) {
+    span.record("duration_ms", &(duration.as_millis() as u64));
+    match result {
+        Ok(response) => {
+            span.record("status", &response.status().as_u16());
+            if let Some(request_id) = request_id(response.headers()) {
+                span.record("request_id", &request_id.as_str());
+            }
+        }
+        Err(failure) => {
+            span.record("error", &field::display(failure));
+        }
+    }
+}
+
+/// Record the attempt about to be sent on the current request span.
+pub(crate) fn record_attempt(attempt: u32) {
+    Span::current().record("attempts", &attempt);
+}
+
+/// The path with its identifiers replaced by `{}`, e.g. `/apps/{}/config-vars`.
+fn path_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.is_empty() || ROUTE_SEGMENTS.binary_search(&segment).is_ok() {
+                segment
+            } else {
+                "{}"
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+/// The app a path is about, e.g. `example` for `/apps/example/releases`.
+fn app_id(path: &str) -> Option<&str> {
+    let mut segments = path.split('/').skip_while(|segment| *segment != "apps");
+    segments.next();
+    segments.next().filter(|segment| !segment.is_empty())
+}
@@ -0,0 +1,90 @@
+//! Module for transports, the HTTP clients `HttpApiClient` sends its requests with.
+//!
+//! The requests and responses are plain `http` types, see [`request::build_request`](../request/fn.build_request.html),
+//! so any HTTP client fits: ureq for small CLI tools, hyper for services, or an in-memory function for tests.
+#[cfg(feature = "reqwest")]
+use crate::framework::reqwest_utils::{
+    into_http_response, into_reqwest_request, transport_failure,
+};
+use crate::framework::response::HerokuApiFailure;
+
+/// Transport
+///
+/// Sends a request built by [`HttpApiClient`](../struct.HttpApiClient.html) and returns the whole response.
+///
+/// The client keeps doing everything else: credentials, retries, rate limiting, caching, middlewares and parsing.
+/// Set one with the `transport` of [`HttpApiClientConfig`](../struct.HttpApiClientConfig.html),
+/// by default the client uses a [`ReqwestTransport`](struct.ReqwestTransport.html), which needs the `reqwest` feature.
+///
+/// A failure to send the request, e.g. a connection error, is returned as `HerokuApiFailure::Transport`.
+///
+/// # Example:
+///
+/// Answering every request in memory.
+/// ```rust
+/// use heroku_rs::prelude::*;
+/// use heroku_rs::framework::response::HerokuApiFailure;
+/// use std::sync::Arc;
+///
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn send(&self, request: http::Request<Vec<u8>>) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+///         let (status, body) = match request.uri().path() {
+///             "/account/rate-limits" => (200, r#"{"remaining": 4500}"#),
+///             _ => (404, r#"{"id": "not_found", "message": "Not found."}"#),
+///         };
+///         Ok(http::Response::builder().status(status).body(body.as_bytes().to_vec()).unwrap())
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///    let api_client = HttpApiClient::new(
+///        Credentials::UserAuthToken { token: String::from("API_KEY") },
+///        HttpApiClientConfig {
+///            transport: Some(Arc::new(Offline)),
+///            ..HttpApiClientConfig::default()
+///        },
+///        ApiEnvironment::Production,
+///    )?;
+///
+///    assert_eq!(api_client.request(&RatelimitDetails::new())?.remaining, 4500);
+///    Ok(())
+/// }
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send the request and read the response, whatever its status.
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure>;
+}
+
+/// The default transport, a `reqwest::blocking::Client`, with the `reqwest` feature.
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    /// A transport sending the requests with `client`, e.g. one configured with a proxy.
+    pub fn new(client: reqwest::blocking::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        let response = self
+            .client
+            .execute(into_reqwest_request(request)?)
+            .map_err(transport_failure)?;
+        into_http_response(response)
+    }
+}
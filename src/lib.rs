@@ -194,6 +194,7 @@
 //! [tomlfeature]: https://github.com/bensadiku/heroku_rs/blob/master/docs/FEATURES.md
 
 extern crate chrono;
+#[cfg(feature = "reqwest")]
 extern crate reqwest;
 #[macro_use]
 extern crate serde;
@@ -258,10 +259,31 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use crate::endpoints::*;
     #[doc(no_inline)]
-    pub use crate::framework::endpoint::Method;
+    pub use crate::framework::endpoint::{ExtraHeaders, Method};
+    #[doc(no_inline)]
+    pub use crate::framework::middleware::Middleware;
+    #[doc(no_inline)]
+    pub use crate::framework::range::{ListEndpoint, Range, RangeOrder};
+    #[doc(no_inline)]
+    pub use crate::framework::retry::RetryPolicy;
+    #[doc(no_inline)]
+    pub use crate::framework::proxy::ProxyConfig;
+    #[doc(no_inline)]
+    pub use crate::framework::owned::OwnedEndpoint;
+    #[doc(no_inline)]
+    pub use crate::framework::transport::Transport;
+    #[doc(no_inline)]
+    pub use crate::framework::ratelimit::RateLimiter;
+    #[doc(no_inline)]
+    pub use crate::framework::cache::{MemoryCache, ResponseCache};
+    #[doc(no_inline)]
+    pub use crate::framework::response::{ResponseMeta, WithMeta};
     #[doc(no_inline)]
     pub use crate::framework::{
-        apiclient::HerokuApiClient, auth::Credentials, ApiEnvironment, HttpApiClient,
-        HttpApiClientConfig,
+        apiclient::HerokuApiClient, auth::Credentials, dyn_client::DynHerokuApiClient,
+        ApiEnvironment, HttpApiClient, HttpApiClientConfig,
     };
+    #[cfg(feature = "async")]
+    #[doc(no_inline)]
+    pub use crate::framework::{apiclient::AsyncHerokuApiClient, AsyncHttpApiClient};
 }
@@ -0,0 +1,428 @@
+#![cfg(feature = "async")]
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    apiclient::AsyncHerokuApiClient, auth::Credentials, cache::MemoryCache, endpoint::ExtraHeaders,
+    middleware::Middleware, response::HerokuApiFailure, retry::RetryPolicy, transport::Transport,
+    ApiEnvironment, AsyncHttpApiClient, HttpApiClientConfig,
+};
+use http::header::{HeaderName, HeaderValue};
+use std::sync::{Arc, Mutex};
+use stub::{async_stub_client, async_stub_client_with, stub_config, StubResponse, StubServer};
+mod stub;
+
+/// Tags the requests, and records the status of their responses.
+#[derive(Default)]
+struct Tagger {
+    statuses: Mutex<Vec<u16>>,
+}
+
+impl Middleware for Tagger {
+    fn before_send(&self, request: &mut http::Request<Vec<u8>>) -> Option<http::Response<Vec<u8>>> {
+        request
+            .headers_mut()
+            .insert("x-tagged", HeaderValue::from_static("async"));
+        None
+    }
+
+    fn after_receive(
+        &self,
+        _request: &http::Request<Vec<u8>>,
+        response: &mut http::Response<Vec<u8>>,
+    ) {
+        self.statuses
+            .lock()
+            .unwrap()
+            .push(response.status().as_u16());
+    }
+}
+
+/// Answers every request with the same body, keeping the paths it was sent.
+#[derive(Debug, Default)]
+struct EchoTransport {
+    paths: Mutex<Vec<String>>,
+}
+
+impl Transport for EchoTransport {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.paths
+            .lock()
+            .unwrap()
+            .push(request.uri().path().to_string());
+        Ok(http::Response::builder()
+            .status(200)
+            .body(br#"{"remaining": 1200}"#.to_vec())
+            .unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn async_request_parses_response() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 4321}"#)]);
+        let api_client = async_stub_client(&server);
+
+        let ratelimit = api_client
+            .request(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+        assert_eq!(ratelimit.remaining, 4321);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/account/rate-limits");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer TOKEN_HERE")
+        );
+        assert_eq!(
+            requests[0].header("accept"),
+            Some("application/vnd.heroku+json; version=3")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_request_returns_heroku_error() {
+        let server = StubServer::start(vec![StubResponse::new(
+            404,
+            r#"{"id": "not_found", "message": "Couldn't find that app."}"#,
+        )]);
+        let api_client = async_stub_client(&server);
+
+        let response = api_client.request(&misc::RatelimitDetails::new()).await;
+        match response {
+            Err(HerokuApiFailure::Error(status, error)) => {
+                assert_eq!(status, 404);
+                assert_eq!(error.id, "not_found");
+            }
+            other => panic!("Expected a not_found error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn async_request_future_is_send() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 10}"#)]);
+        let api_client = std::sync::Arc::new(async_stub_client(&server));
+
+        let handle = {
+            let api_client = api_client.clone();
+            tokio::spawn(async move { api_client.request(&misc::RatelimitDetails::new()).await })
+        };
+        assert_eq!(handle.await.unwrap().unwrap().remaining, 10);
+    }
+
+    #[tokio::test]
+    async fn async_request_raw_returns_response() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 1}"#)]);
+        let api_client = async_stub_client(&server);
+
+        let response = api_client
+            .request_raw(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.extensions().get::<url::Url>(),
+            Some(&server.url().join("account/rate-limits").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn async_request_sends_extra_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 1}"#)]);
+        let api_client = async_stub_client(&server);
+
+        let endpoint = misc::RatelimitDetails::new().with_header(
+            HeaderName::from_static("heroku-two-factor-code"),
+            HeaderValue::from_static("123456"),
+        );
+        api_client.request(&endpoint).await.unwrap();
+
+        assert_eq!(
+            server.requests()[0].header("heroku-two-factor-code"),
+            Some("123456")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_request_with_meta_keeps_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 1}"#)
+            .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")
+            .header("RateLimit-Remaining", "1")]);
+        let api_client = async_stub_client(&server);
+
+        let response = api_client
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+        assert_eq!(response.result.remaining, 1);
+        assert_eq!(
+            response.meta.request_id.as_deref(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+        assert_eq!(response.meta.ratelimit_remaining, Some(1));
+    }
+
+    #[tokio::test]
+    async fn async_request_uses_response_cache() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 7}"#).header("ETag", "\"v1\""),
+            StubResponse::new(304, ""),
+        ]);
+        let api_client = async_stub_client_with(
+            &server,
+            HttpApiClientConfig {
+                cache: Some(std::sync::Arc::new(MemoryCache::new())),
+                ..stub_config()
+            },
+        );
+
+        for _ in 0..2 {
+            let ratelimit = api_client
+                .request(&misc::RatelimitDetails::new())
+                .await
+                .unwrap();
+            assert_eq!(ratelimit.remaining, 7);
+        }
+        assert_eq!(server.requests()[1].header("if-none-match"), Some("\"v1\""));
+    }
+
+    #[tokio::test]
+    async fn async_request_refreshes_rejected_oauth_token() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                401,
+                r#"{"id": "unauthorized", "message": "Invalid credentials provided."}"#,
+            ),
+            StubResponse::new(
+                201,
+                r#"{
+                    "access_token": {"expires_in": 28800, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "NEW_TOKEN"},
+                    "authorization": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                    "client": null,
+                    "created_at": "2012-01-01T12:00:00Z",
+                    "grant": {"code": "", "type": "refresh_token"},
+                    "id": "01234567-89ab-cdef-0123-456789abcdef",
+                    "refresh_token": {"expires_in": null, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "REFRESH_TOKEN"},
+                    "session": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                    "updated_at": "2012-01-01T12:00:00Z",
+                    "user": {"id": "01234567-89ab-cdef-0123-456789abcdef"}
+                }"#,
+            ),
+            StubResponse::new(200, r#"{"remaining": 3}"#),
+        ]);
+        let api_client = AsyncHttpApiClient::new(
+            Credentials::OAuth {
+                access_token: String::from("OLD_TOKEN"),
+                refresh_token: String::from("REFRESH_TOKEN"),
+                client_secret: String::from("CLIENT_SECRET"),
+                expires_at: None,
+            },
+            HttpApiClientConfig::default(),
+            ApiEnvironment::Custom(server.url()),
+        )
+        .unwrap();
+
+        let ratelimit = api_client
+            .request(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+        assert_eq!(ratelimit.remaining, 3);
+        assert_eq!(
+            server.requests()[2].header("authorization"),
+            Some("Bearer NEW_TOKEN")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_concurrent_requests_refresh_an_expiring_token_once() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                201,
+                r#"{
+                    "access_token": {"expires_in": 28800, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "NEW_TOKEN"},
+                    "authorization": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                    "client": null,
+                    "created_at": "2012-01-01T12:00:00Z",
+                    "grant": {"code": "", "type": "refresh_token"},
+                    "id": "01234567-89ab-cdef-0123-456789abcdef",
+                    "refresh_token": {"expires_in": null, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "REFRESH_TOKEN"},
+                    "session": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                    "updated_at": "2012-01-01T12:00:00Z",
+                    "user": {"id": "01234567-89ab-cdef-0123-456789abcdef"}
+                }"#,
+            ),
+            StubResponse::new(200, r#"{"remaining": 3}"#),
+            StubResponse::new(200, r#"{"remaining": 3}"#),
+            StubResponse::new(200, r#"{"remaining": 3}"#),
+        ]);
+        let api_client = AsyncHttpApiClient::new(
+            Credentials::OAuth {
+                access_token: String::from("OLD_TOKEN"),
+                refresh_token: String::from("REFRESH_TOKEN"),
+                client_secret: String::from("CLIENT_SECRET"),
+                expires_at: Some(std::time::SystemTime::now()),
+            },
+            stub_config(),
+            ApiEnvironment::Custom(server.url()),
+        )
+        .unwrap();
+
+        let endpoint = misc::RatelimitDetails::new();
+        let (first, second, third) = tokio::join!(
+            api_client.request(&endpoint),
+            api_client.request(&endpoint),
+            api_client.request(&endpoint),
+        );
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].path, "/oauth/tokens");
+        assert!(requests[1..]
+            .iter()
+            .all(|request| request.header("authorization") == Some("Bearer NEW_TOKEN")));
+    }
+
+    #[tokio::test]
+    async fn async_request_all_follows_next_range() {
+        let stack = |name: &str| {
+            format!(
+                r#"[{{"default": false, "created_at": "2012-01-01T12:00:00Z", "id": "{0}", "name": "{0}", "state": "public", "updated_at": "2012-01-01T12:00:00Z"}}]"#,
+                name
+            )
+        };
+        let server = StubServer::start(vec![
+            StubResponse::new(206, &stack("heroku-18")).header("Next-Range", "]heroku-18..; max=1"),
+            StubResponse::new(200, &stack("heroku-20")),
+        ]);
+        let api_client = async_stub_client(&server);
+
+        let stacks = api_client
+            .request_all(&misc::StackList::new())
+            .await
+            .unwrap();
+        assert_eq!(stacks.len(), 2);
+        assert_eq!(stacks[1].name, "heroku-20");
+        assert_eq!(
+            server.requests()[1].header("range"),
+            Some("]heroku-18..; max=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_request_retries_rate_limits() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                429,
+                r#"{"id": "rate_limit", "message": "Your account reached the API rate limit"}"#,
+            ),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+        let api_client = async_stub_client_with(
+            &server,
+            HttpApiClientConfig {
+                retry_policy: RetryPolicy {
+                    initial_backoff: std::time::Duration::from_millis(1),
+                    ..RetryPolicy::default()
+                },
+                ..stub_config()
+            },
+        );
+
+        let ratelimit = api_client
+            .request(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+        assert_eq!(ratelimit.remaining, 4500);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn async_clones_share_rotated_credentials() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 4500}"#)]);
+        let api_client = async_stub_client(&server);
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-request-origin", HeaderValue::from_static("deployer"));
+        let sub_client = api_client.clone().with_headers(headers);
+
+        api_client.set_credentials(Credentials::UserAuthToken {
+            token: String::from("ROTATED_TOKEN"),
+        });
+        let task = tokio::spawn(async move {
+            sub_client
+                .request(&misc::RatelimitDetails::new())
+                .await
+                .unwrap()
+        });
+        assert_eq!(task.await.unwrap().remaining, 4500);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer ROTATED_TOKEN")
+        );
+        assert_eq!(requests[0].header("x-request-origin"), Some("deployer"));
+    }
+
+    #[tokio::test]
+    async fn async_request_calls_middlewares() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 4500}"#)]);
+        let tagger = Arc::new(Tagger::default());
+        let api_client = async_stub_client_with(
+            &server,
+            HttpApiClientConfig {
+                middlewares: vec![tagger.clone()],
+                ..stub_config()
+            },
+        );
+
+        api_client
+            .request(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+
+        assert_eq!(server.requests()[0].header("x-tagged"), Some("async"));
+        assert_eq!(*tagger.statuses.lock().unwrap(), vec![200]);
+    }
+
+    #[tokio::test]
+    async fn async_request_uses_configured_transport() {
+        let transport = Arc::new(EchoTransport::default());
+        let api_client = AsyncHttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: String::from("TOKEN_HERE"),
+            },
+            HttpApiClientConfig {
+                transport: Some(transport.clone()),
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Production,
+        )
+        .unwrap();
+
+        let response = api_client
+            .request_raw(&misc::RatelimitDetails::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.body().as_slice(), br#"{"remaining": 1200}"#);
+        assert_eq!(
+            response
+                .extensions()
+                .get::<url::Url>()
+                .map(url::Url::as_str),
+            Some("https://api.heroku.com/account/rate-limits")
+        );
+        assert_eq!(
+            *transport.paths.lock().unwrap(),
+            vec!["/account/rate-limits"]
+        );
+    }
+}
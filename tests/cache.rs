@@ -0,0 +1,90 @@
+use heroku_rs::endpoints::{apps, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, cache::MemoryCache, HttpApiClient, HttpApiClientConfig,
+};
+use std::sync::Arc;
+use stub::{stub_client_with, stub_config, StubResponse, StubServer};
+mod stub;
+
+fn get_cached_client(server: &StubServer, cache: Arc<MemoryCache>) -> HttpApiClient {
+    stub_client_with(
+        server,
+        HttpApiClientConfig {
+            cache: Some(cache),
+            ..stub_config()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_modified_is_served_from_cache() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 2400}"#).header("ETag", "\"v1\""),
+            StubResponse::new(304, "").header("ETag", "\"v1\""),
+        ]);
+        let cache = Arc::new(MemoryCache::new());
+        let api_client = get_cached_client(&server, cache.clone());
+
+        let first = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(first.remaining, 2400);
+        assert_eq!(cache.len(), 1);
+
+        let second = api_client
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .unwrap();
+        assert_eq!(second.result.remaining, 2400);
+        assert_eq!(second.meta.status.as_u16(), 304);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match"), Some("\"v1\""));
+    }
+
+    #[test]
+    fn changed_response_replaces_cache() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 2400}"#).header("ETag", "\"v1\""),
+            StubResponse::new(200, r#"{"remaining": 2399}"#).header("ETag", "\"v2\""),
+            StubResponse::new(304, ""),
+        ]);
+        let api_client = get_cached_client(&server, Arc::new(MemoryCache::new()));
+
+        for _ in 0..2 {
+            api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        }
+        let third = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(third.remaining, 2399);
+        assert_eq!(server.requests()[2].header("if-none-match"), Some("\"v2\""));
+    }
+
+    #[test]
+    fn response_without_etag_is_not_cached() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 2400}"#),
+            StubResponse::new(200, r#"{"remaining": 2399}"#),
+        ]);
+        let cache = Arc::new(MemoryCache::new());
+        let api_client = get_cached_client(&server, cache.clone());
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        assert!(cache.is_empty());
+        assert_eq!(server.requests()[1].header("if-none-match"), None);
+    }
+
+    #[test]
+    fn only_get_requests_are_cached() {
+        let server = StubServer::start(vec![StubResponse::new(200, "{}").header("ETag", "\"v1\"")]);
+        let cache = Arc::new(MemoryCache::new());
+        let api_client = get_cached_client(&server, cache.clone());
+
+        let _ = api_client.request(&apps::AppDelete::new("example"));
+
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, auth::Credentials, proxy::ProxyConfig, retry::RetryPolicy,
+    ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use std::time::Duration;
+use stub::{StubResponse, StubServer};
+mod stub;
+
+fn get_client(config: HttpApiClientConfig, environment: ApiEnvironment) -> HttpApiClient {
+    let credentials = Credentials::UserAuthToken {
+        token: String::from("TOKEN_HERE"),
+    };
+    HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig {
+            retry_policy: RetryPolicy::none(),
+            ..config
+        },
+        environment,
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_agent_suffix_is_appended() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let api_client = get_client(
+            HttpApiClientConfig {
+                user_agent: Some(String::from("my-deploy-tool/1.2.0")),
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Custom(server.url()),
+        );
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(
+            server.requests()[0].header("user-agent"),
+            Some("heroku_rs my-deploy-tool/1.2.0")
+        );
+    }
+
+    #[test]
+    fn invalid_user_agent_is_a_request_failure() {
+        let api_client = get_client(
+            HttpApiClientConfig {
+                user_agent: Some(String::from("my-deploy-tool\n")),
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Production,
+        );
+
+        let failure = api_client
+            .request(&misc::RatelimitDetails::new())
+            .unwrap_err();
+        assert_eq!(failure.status(), None);
+        assert!(failure
+            .to_string()
+            .starts_with("Could not build the request"));
+    }
+
+    #[test]
+    fn requests_go_through_the_proxy() {
+        let proxy = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let api_client = get_client(
+            HttpApiClientConfig {
+                proxy: Some(ProxyConfig {
+                    credentials: Some((String::from("user"), String::from("password"))),
+                    ..ProxyConfig::new(proxy.url())
+                }),
+                connect_timeout: Some(Duration::from_secs(5)),
+                pool_idle_timeout: None,
+                pool_max_idle_per_host: 1,
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Custom(url::Url::parse("http://api.heroku.test/").unwrap()),
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+
+        let requests = proxy.requests();
+        assert_eq!(
+            requests[0].path,
+            "http://api.heroku.test/account/rate-limits"
+        );
+        assert_eq!(
+            requests[0].header("proxy-authorization"),
+            Some("Basic dXNlcjpwYXNzd29yZA==")
+        );
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer TOKEN_HERE")
+        );
+    }
+
+    #[test]
+    fn no_proxy_hosts_are_reached_directly() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let api_client = get_client(
+            HttpApiClientConfig {
+                proxy: Some(ProxyConfig {
+                    no_proxy: vec![String::from("127.0.0.1")],
+                    ..ProxyConfig::new(url::Url::parse("http://proxy.invalid:3128").unwrap())
+                }),
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Custom(server.url()),
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+        assert_eq!(server.requests()[0].path, "/account/rate-limits");
+    }
+
+    #[test]
+    fn no_proxy_matches_domains_subdomains_and_ips() {
+        let proxy = ProxyConfig {
+            no_proxy: vec![
+                String::from("example.com"),
+                String::from(" .Internal.Test"),
+                String::from("10.0.0.1"),
+                String::from("::1"),
+            ],
+            ..ProxyConfig::new(url::Url::parse("http://proxy.example.org:3128").unwrap())
+        };
+
+        assert!(proxy.bypasses("example.com"));
+        assert!(proxy.bypasses("api.example.com"));
+        assert!(proxy.bypasses("API.INTERNAL.TEST."));
+        assert!(proxy.bypasses("internal.test"));
+        assert!(proxy.bypasses("10.0.0.1"));
+        assert!(proxy.bypasses("[::1]"));
+        assert!(!proxy.bypasses("notexample.com"));
+        assert!(!proxy.bypasses("example.com.evil.test"));
+        assert!(!proxy.bypasses("api.heroku.com"));
+        assert!(!proxy.bypasses("10.0.0.10"));
+
+        let everything = ProxyConfig {
+            no_proxy: vec![String::from("*")],
+            ..proxy
+        };
+        assert!(everything.bypasses("api.heroku.com"));
+    }
+}
@@ -0,0 +1,155 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, auth::Credentials, endpoint::ExtraHeaders, ApiEnvironment,
+    HttpApiClient,
+};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use std::thread;
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+fn token(token: &str) -> Credentials {
+    Credentials::UserAuthToken {
+        token: String::from(token),
+    }
+}
+
+fn ratelimits(count: usize) -> Vec<StubResponse> {
+    (0..count)
+        .map(|_| StubResponse::new(200, r#"{"remaining": 2400}"#))
+        .collect()
+}
+
+fn is_shareable<T: Clone + Send + Sync + 'static>() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_is_a_shareable_handle() {
+        is_shareable::<HttpApiClient>();
+
+        let server = StubServer::start(ratelimits(4));
+        let api_client = stub_client(&server);
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let api_client = api_client.clone();
+                thread::spawn(move || {
+                    api_client
+                        .request(&misc::RatelimitDetails::new())
+                        .unwrap()
+                        .remaining
+                })
+            })
+            .collect();
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), 2400);
+        }
+        assert_eq!(server.requests().len(), 4);
+    }
+
+    #[test]
+    fn set_credentials_rotates_the_token_of_every_clone() {
+        let server = StubServer::start(ratelimits(2));
+        let api_client = stub_client(&server);
+        let clone = api_client.clone();
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        clone.set_credentials(token("ROTATED_TOKEN"));
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer TOKEN_HERE")
+        );
+        assert_eq!(
+            requests[1].header("authorization"),
+            Some("Bearer ROTATED_TOKEN")
+        );
+        assert!(matches!(
+            api_client.credentials(),
+            Credentials::UserAuthToken { token } if token == "ROTATED_TOKEN"
+        ));
+    }
+
+    #[test]
+    fn with_environment_targets_another_server() {
+        let production = StubServer::start(ratelimits(1));
+        let staging = StubServer::start(ratelimits(1));
+        let api_client = stub_client(&production);
+        let staging_client = api_client.with_environment(ApiEnvironment::Custom(staging.url()));
+
+        staging_client
+            .request(&misc::RatelimitDetails::new())
+            .unwrap();
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        assert_eq!(production.requests().len(), 1);
+        assert_eq!(staging.requests().len(), 1);
+
+        // the sub-client shares the credentials
+        api_client.set_credentials(token("ROTATED_TOKEN"));
+        assert!(matches!(
+            staging_client.credentials(),
+            Credentials::UserAuthToken { token } if token == "ROTATED_TOKEN"
+        ));
+    }
+
+    #[test]
+    fn with_headers_are_sent_unless_the_endpoint_sets_them() {
+        let server = StubServer::start(ratelimits(3));
+        let api_client = stub_client(&server);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-origin", HeaderValue::from_static("deployer"));
+        headers.insert("variant", HeaderValue::from_static("client"));
+        let sub_client = api_client.with_headers(headers);
+
+        sub_client.request(&misc::RatelimitDetails::new()).unwrap();
+        sub_client
+            .request(&misc::RatelimitDetails::new().with_header(
+                HeaderName::from_static("variant"),
+                HeaderValue::from_static("endpoint"),
+            ))
+            .unwrap();
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("x-request-origin"), Some("deployer"));
+        assert_eq!(requests[0].header("variant"), Some("client"));
+        assert_eq!(requests[1].header("variant"), Some("endpoint"));
+        assert_eq!(requests[2].header("x-request-origin"), None);
+    }
+
+    #[test]
+    fn with_credentials_keeps_the_credentials_apart() {
+        let server = StubServer::start(ratelimits(3));
+        let api_client = stub_client(&server);
+        let other_account = api_client.with_credentials(token("OTHER_TOKEN"));
+
+        other_account
+            .request(&misc::RatelimitDetails::new())
+            .unwrap();
+        other_account.set_credentials(token("OTHER_ROTATED_TOKEN"));
+        other_account
+            .request(&misc::RatelimitDetails::new())
+            .unwrap();
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer OTHER_TOKEN")
+        );
+        assert_eq!(
+            requests[1].header("authorization"),
+            Some("Bearer OTHER_ROTATED_TOKEN")
+        );
+        assert_eq!(
+            requests[2].header("authorization"),
+            Some("Bearer TOKEN_HERE")
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    auth::{Credentials, CredentialsError},
+    netrc::Netrc,
+    ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use std::path::PathBuf;
+use stub::{StubResponse, StubServer};
+mod stub;
+
+/// Write `content` to a file of the temp directory, unique to the test.
+fn netrc_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("heroku_rs_{}_{}", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn token(credentials: Credentials) -> String {
+    match credentials {
+        Credentials::UserAuthToken { token } => token,
+        other => panic!("Expected a token, got {:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn netrc_parses_machines_and_default() {
+        let netrc = Netrc::parse(
+            "# written by the Heroku CLI\n\
+             machine api.heroku.com\n  login me@example.com\n  password API_KEY\n\
+             machine git.heroku.com login me@example.com password GIT_KEY\n\
+             default login anonymous password \"with space\"\n",
+        )
+        .unwrap();
+
+        let api = netrc.machine("api.heroku.com").unwrap();
+        assert_eq!(api.login.as_deref(), Some("me@example.com"));
+        assert_eq!(api.password.as_deref(), Some("API_KEY"));
+        assert_eq!(
+            netrc.machine("git.heroku.com").unwrap().password.as_deref(),
+            Some("GIT_KEY")
+        );
+        assert_eq!(
+            netrc.machine("example.com").unwrap().password.as_deref(),
+            Some("with space")
+        );
+    }
+
+    #[test]
+    fn netrc_skips_macros() {
+        let netrc = Netrc::parse(
+            "macdef init\ncd /pub\nmachine not.a.machine\n\nmachine api.heroku.com password API_KEY\n",
+        )
+        .unwrap();
+
+        assert_eq!(netrc.machines.len(), 1);
+        assert!(netrc.machine("not.a.machine").is_none());
+    }
+
+    #[test]
+    fn netrc_reports_malformed_line() {
+        let error =
+            Netrc::parse("machine api.heroku.com\n  login me@example.com\n  password").unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = Netrc::parse("machine api.heroku.com\nsecret API_KEY").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.to_string().contains("secret"));
+    }
+
+    #[test]
+    fn credentials_from_netrc_file() {
+        let path = netrc_file(
+            "_netrc",
+            "machine api.heroku.com\n  login me@example.com\n  password API_KEY\n",
+        );
+
+        assert_eq!(
+            token(Credentials::from_netrc_file(&path).unwrap()),
+            "API_KEY"
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn credentials_from_netrc_file_without_heroku() {
+        let path = netrc_file("other_netrc", "machine example.com password SECRET\n");
+
+        match Credentials::from_netrc_file(&path) {
+            Err(CredentialsError::NotFound) => {}
+            other => panic!("Expected NotFound, got {:?}", other),
+        }
+        std::fs::remove_file(path).unwrap();
+
+        match Credentials::from_netrc_file("/does/not/exist/.netrc") {
+            Err(CredentialsError::Io(path, _)) => {
+                assert_eq!(path, PathBuf::from("/does/not/exist/.netrc"))
+            }
+            other => panic!("Expected an io error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn credentials_from_env() {
+        let lookup = |value: &'static str| {
+            move |name: &str| {
+                assert_eq!(name, "HEROKU_API_KEY");
+                Some(String::from(value))
+            }
+        };
+        assert_eq!(
+            token(Credentials::from_env_with(lookup(" ENV_KEY\n")).unwrap()),
+            "ENV_KEY"
+        );
+        assert!(Credentials::from_env_with(lookup("")).is_none());
+        assert!(Credentials::from_env_with(|_| None).is_none());
+    }
+
+    #[test]
+    fn basic_credentials_header() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 1}"#)]);
+        let api_client = HttpApiClient::new(
+            Credentials::Basic {
+                email: String::from("me@example.com"),
+                api_key: String::from("API_KEY"),
+            },
+            HttpApiClientConfig::default(),
+            ApiEnvironment::Custom(server.url()),
+        )
+        .unwrap();
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        assert_eq!(
+            server.requests()[0].header("authorization"),
+            Some("Basic bWVAZXhhbXBsZS5jb206QVBJX0tFWQ==")
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use heroku_rs::endpoints::{apps, config_vars, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    dyn_client::{DynHerokuApiClient, DynRequest},
+    endpoint::{ExtraHeaders, HerokuEndpoint, Method},
+    range::{ListEndpoint, Range},
+    response::HerokuApiFailure,
+};
+use http::header::{HeaderName, HeaderValue};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+fn get_dyn_client(server: &StubServer) -> Box<dyn DynHerokuApiClient> {
+    Box::new(stub_client(server))
+}
+
+fn stacks(names: &[&str]) -> String {
+    let stacks: Vec<String> = names
+        .iter()
+        .map(|name| {
+            format!(
+                r#"{{"default": false, "created_at": "2012-01-01T12:00:00Z", "id": "{0}", "name": "{0}", "state": "public", "updated_at": "2012-01-01T12:00:00Z"}}"#,
+                name
+            )
+        })
+        .collect();
+    format!("[{}]", stacks.join(","))
+}
+
+/// An endpoint with a query, which no endpoint of the crate has.
+struct SearchApps;
+
+#[derive(Serialize)]
+struct SearchQuery {
+    name: &'static str,
+    limit: Option<u32>,
+}
+
+impl HerokuEndpoint<Vec<apps::App>, SearchQuery> for SearchApps {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        String::from("apps")
+    }
+    fn query(&self) -> Option<SearchQuery> {
+        Some(SearchQuery {
+            name: "my app",
+            limit: None,
+        })
+    }
+}
+
+/// Answers every request with the same body, and keeps the requests.
+#[derive(Default)]
+struct Recorder {
+    requests: Mutex<Vec<DynRequest>>,
+}
+
+impl DynHerokuApiClient for Recorder {
+    fn send_request(
+        &self,
+        request: &DynRequest,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.requests.lock().unwrap().push(request.clone());
+        Ok(http::Response::builder()
+            .status(200)
+            .body(br#"{"PORT": "5000"}"#.to_vec())
+            .unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_client_sends_and_parses_typed_endpoints() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 2400}"#).header("Request-Id", "request-1"),
+            StubResponse::new(
+                404,
+                r#"{"id": "not_found", "message": "Couldn't find that app."}"#,
+            ),
+        ]);
+        let api_client = get_dyn_client(&server);
+
+        let ratelimit = api_client
+            .request_with_meta(&misc::RatelimitDetails::new().with_header(
+                HeaderName::from_static("heroku-two-factor-code"),
+                HeaderValue::from_static("123456"),
+            ))
+            .unwrap();
+        assert_eq!(ratelimit.result.remaining, 2400);
+        assert_eq!(ratelimit.meta.request_id.as_deref(), Some("request-1"));
+
+        let failure = api_client
+            .request(&apps::AppDetails { app_id: "missing" })
+            .unwrap_err();
+        assert!(failure.is_not_found());
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/account/rate-limits");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer TOKEN_HERE")
+        );
+        assert_eq!(requests[0].header("heroku-two-factor-code"), Some("123456"));
+        assert_eq!(requests[1].path, "/apps/missing");
+    }
+
+    #[test]
+    fn dyn_client_keeps_the_query_body_and_range() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, "[]"),
+            StubResponse::new(200, r#"{"PORT": "5000"}"#),
+        ]);
+        let api_client = get_dyn_client(&server);
+
+        api_client
+            .request(&SearchApps.with_range(Range::new("name").max(10).build()))
+            .unwrap();
+        let mut params = HashMap::new();
+        params.insert(String::from("PORT"), String::from("5000"));
+        api_client
+            .request(&config_vars::AppConfigVarUpdate {
+                app_id: "my-app",
+                params,
+            })
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/apps?name=my+app");
+        assert_eq!(requests[0].header("range"), Some("name ..; max=10"));
+        assert_eq!(requests[1].method, "PATCH");
+        assert_eq!(requests[1].body, r#"{"PORT":"5000"}"#);
+    }
+
+    #[test]
+    fn dyn_client_request_all_follows_next_range() {
+        let server = StubServer::start(vec![
+            StubResponse::new(206, &stacks(&["heroku-18", "heroku-20"]))
+                .header("Next-Range", "]heroku-20..; max=2"),
+            StubResponse::new(200, &stacks(&["container"])),
+        ]);
+        let api_client = get_dyn_client(&server);
+
+        let stacks = api_client.request_all(&misc::StackList {}).unwrap();
+        let names: Vec<&str> = stacks.iter().map(|stack| stack.name.as_str()).collect();
+        assert_eq!(names, vec!["heroku-18", "heroku-20", "container"]);
+        assert_eq!(
+            server.requests()[1].header("range"),
+            Some("]heroku-20..; max=2")
+        );
+    }
+
+    #[test]
+    fn dyn_client_raw_responses_keep_their_url() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let api_client = get_dyn_client(&server);
+
+        let response = api_client
+            .request_raw(&misc::RatelimitDetails::new())
+            .unwrap();
+        assert_eq!(
+            response.extensions().get::<url::Url>(),
+            Some(&server.url().join("account/rate-limits").unwrap())
+        );
+    }
+
+    #[test]
+    fn clients_are_swapped_at_runtime() {
+        let recorder = Arc::new(Recorder::default());
+        let mut clients: Vec<Arc<dyn DynHerokuApiClient>> = vec![recorder.clone()];
+
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"PORT": "8080"}"#)]);
+        clients.push(Arc::from(get_dyn_client(&server)));
+
+        let ports: Vec<String> = clients
+            .iter()
+            .map(|api_client| {
+                let config_vars = api_client
+                    .request(&config_vars::AppConfigVarDetails { app_id: "my-app" })
+                    .unwrap();
+                config_vars["PORT"].clone().unwrap()
+            })
+            .collect();
+        assert_eq!(ports, vec!["5000", "8080"]);
+
+        let requests = recorder.requests.lock().unwrap();
+        assert_eq!(requests[0].method, Method::Get);
+        assert_eq!(requests[0].path, "apps/my-app/config-vars");
+        assert_eq!(requests[0].query, None);
+        assert_eq!(requests[0].body, None);
+    }
+
+    #[test]
+    fn dyn_request_erases_the_query_and_body() {
+        let request = DynRequest::new(&SearchApps).unwrap();
+        assert_eq!(request.query, Some(serde_json::json!({ "name": "my app" })));
+        assert_eq!(request.version, "application/vnd.heroku+json; version=3");
+
+        let mut params = HashMap::new();
+        params.insert(String::from("PORT"), String::from("5000"));
+        let request = DynRequest::new(&config_vars::AppConfigVarUpdate {
+            app_id: "my-app",
+            params,
+        })
+        .unwrap();
+        assert_eq!(request.method, Method::Patch);
+        assert_eq!(request.body, Some(serde_json::json!({ "PORT": "5000" })));
+    }
+}
@@ -0,0 +1,164 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    auth::Credentials,
+    response::{HerokuApiErrorKind, HerokuApiFailure},
+    retry::RetryPolicy,
+    ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use stub::{StubResponse, StubServer};
+mod stub;
+
+fn failing_request(response: StubResponse) -> HerokuApiFailure {
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+
+    let server = StubServer::start(vec![response]);
+    let api_client = HttpApiClient::new(
+        Credentials::UserAuthToken {
+            token: String::from("TOKEN_HERE"),
+        },
+        HttpApiClientConfig {
+            retry_policy: RetryPolicy::none(),
+            ..HttpApiClientConfig::default()
+        },
+        ApiEnvironment::Custom(server.url()),
+    )
+    .unwrap();
+
+    api_client
+        .request(&misc::RatelimitDetails::new())
+        .expect_err("the stub server only returns failing responses")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_kind_from_id() {
+        assert_eq!(
+            HerokuApiErrorKind::from("not_found"),
+            HerokuApiErrorKind::NotFound
+        );
+        assert_eq!(
+            HerokuApiErrorKind::from("two_factor"),
+            HerokuApiErrorKind::TwoFactor
+        );
+        assert_eq!(
+            HerokuApiErrorKind::from("verification_required"),
+            HerokuApiErrorKind::VerificationRequired
+        );
+        assert_eq!(
+            HerokuApiErrorKind::from("something_new"),
+            HerokuApiErrorKind::Other(String::from("something_new"))
+        );
+    }
+
+    #[test]
+    fn not_found_error() {
+        let failure = failing_request(StubResponse::new(
+            404,
+            r#"{"id": "not_found", "message": "Couldn't find that app.", "url": "https://devcenter.heroku.com/articles/platform-api-reference"}"#,
+        ));
+
+        assert!(failure.is_not_found());
+        assert!(!failure.is_retryable());
+        assert_eq!(failure.status().unwrap(), 404);
+        assert_eq!(failure.kind(), Some(HerokuApiErrorKind::NotFound));
+        match failure {
+            HerokuApiFailure::Error(_, error) => {
+                assert_eq!(
+                    error.url.as_deref(),
+                    Some("https://devcenter.heroku.com/articles/platform-api-reference")
+                );
+                assert_eq!(error.raw_body, None);
+            }
+            other => panic!("Expected a Heroku error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn two_factor_error() {
+        let failure = failing_request(StubResponse::new(
+            403,
+            r#"{"id": "two_factor", "message": "A second authentication factor is required."}"#,
+        ));
+
+        assert!(failure.is_forbidden());
+        assert!(failure.is_two_factor_required());
+        assert!(!failure.is_not_found());
+    }
+
+    #[test]
+    fn forbidden_error_by_id() {
+        let failure = failing_request(StubResponse::new(
+            400,
+            r#"{"id": "forbidden", "message": "You do not have access to the app example."}"#,
+        ));
+
+        assert!(failure.is_forbidden());
+        assert!(!failure.is_unauthorized());
+    }
+
+    #[test]
+    fn rate_limit_error_is_retryable() {
+        let failure = failing_request(StubResponse::new(
+            429,
+            r#"{"id": "rate_limit", "message": "Your account reached the API rate limit"}"#,
+        ));
+
+        assert!(failure.is_rate_limited());
+        assert!(failure.is_retryable());
+    }
+
+    #[test]
+    fn non_json_error_keeps_body() {
+        let body = "<html><body>502 Bad Gateway</body></html>";
+        let failure =
+            failing_request(StubResponse::new(502, body).header("Content-Type", "text/html"));
+
+        assert!(failure.is_retryable());
+        assert_eq!(
+            failure.kind(),
+            Some(HerokuApiErrorKind::Other(String::new()))
+        );
+        assert!(failure.to_string().contains(body));
+        match failure {
+            HerokuApiFailure::Error(_, error) => assert_eq!(error.raw_body.as_deref(), Some(body)),
+            other => panic!("Expected a Heroku error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn undecodable_body_points_at_the_field() {
+        let failure = failing_request(StubResponse::new(200, r#"{"remaining": "plenty"}"#));
+
+        match &failure {
+            HerokuApiFailure::Decode {
+                status,
+                path,
+                snippet,
+                ..
+            } => {
+                assert_eq!(*status, 200);
+                assert_eq!(path, "remaining");
+                assert!(snippet.contains("plenty"));
+            }
+            other => panic!("Expected a decode error, got {:?}", other),
+        }
+        assert!(std::error::Error::source(&failure).is_some());
+        assert!(failure.to_string().contains("remaining"));
+    }
+
+    #[test]
+    fn failure_is_a_std_error() {
+        fn assert_error<E: std::error::Error + Send + Sync + 'static>() {}
+        assert_error::<HerokuApiFailure>();
+
+        let failure = failing_request(StubResponse::new(
+            404,
+            r#"{"id": "not_found", "message": "Couldn't find that app."}"#,
+        ));
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(failure);
+        assert!(boxed.to_string().contains("not_found"));
+    }
+}
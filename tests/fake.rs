@@ -0,0 +1,249 @@
+#![cfg(feature = "fake")]
+use heroku_rs::endpoints::{apps, config_vars, formations, releases};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, auth::Credentials, fake::FakeHeroku, response::HerokuApiFailure,
+    HttpApiClient, HttpApiClientConfig,
+};
+use std::collections::HashMap;
+
+fn get_fake_client(heroku: &FakeHeroku) -> HttpApiClient {
+    let credentials = Credentials::UserAuthToken {
+        token: String::from("FAKE_API_KEY"),
+    };
+    HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig::default(),
+        heroku.environment(),
+    )
+    .unwrap()
+}
+
+fn create_app(api_client: &HttpApiClient, name: &str) -> apps::App {
+    api_client
+        .request(&apps::AppCreate {
+            params: apps::AppCreateParams {
+                name: Some(name),
+                region: None,
+                stack: None,
+            },
+        })
+        .unwrap()
+}
+
+/// The id of the Heroku error of the failure, e.g. `not_found`.
+fn error_id(failure: HerokuApiFailure) -> String {
+    match failure {
+        HerokuApiFailure::Error(_, error) => error.id,
+        other => panic!("Expected a Heroku error, got {:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_lists_updates_and_deletes_created_apps() {
+        let heroku = FakeHeroku::start().unwrap();
+        let api_client = get_fake_client(&heroku);
+
+        let app = create_app(&api_client, "my-app");
+        assert_eq!(app.name, "my-app");
+        let apps = api_client.request(&apps::AppList {}).unwrap();
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].id, app.id);
+
+        let updated = api_client
+            .request(&apps::AppUpdate {
+                app_id: &app.id,
+                params: apps::AppUpdateParams {
+                    build_stack: None,
+                    maintenance: Some(true),
+                    name: Some("my-renamed-app"),
+                },
+            })
+            .unwrap();
+        assert!(updated.maintenance);
+        let details = api_client
+            .request(&apps::AppDetails {
+                app_id: "my-renamed-app",
+            })
+            .unwrap();
+        assert_eq!(details.id, app.id);
+
+        api_client
+            .request(&apps::AppDelete {
+                app_id: "my-renamed-app",
+            })
+            .unwrap();
+        assert!(api_client.request(&apps::AppList {}).unwrap().is_empty());
+    }
+
+    #[test]
+    fn fake_answers_heroku_errors() {
+        let heroku = FakeHeroku::start().unwrap();
+        let api_client = get_fake_client(&heroku);
+        create_app(&api_client, "my-app");
+
+        let failure = api_client
+            .request(&apps::AppDetails { app_id: "missing" })
+            .unwrap_err();
+        assert!(failure.is_not_found());
+        assert_eq!(error_id(failure), "not_found");
+
+        let taken = api_client
+            .request(&apps::AppCreate {
+                params: apps::AppCreateParams {
+                    name: Some("my-app"),
+                    region: None,
+                    stack: None,
+                },
+            })
+            .unwrap_err();
+        assert_eq!(error_id(taken), "invalid_params");
+
+        let invalid = api_client
+            .request(&apps::AppCreate {
+                params: apps::AppCreateParams {
+                    name: Some("Not A Name"),
+                    region: None,
+                    stack: None,
+                },
+            })
+            .unwrap_err();
+        assert_eq!(error_id(invalid), "invalid_params");
+
+        let process_type = api_client
+            .request(&formations::FormationDetails {
+                app_id: "my-app",
+                formation_id: "worker",
+            })
+            .unwrap_err();
+        assert_eq!(error_id(process_type), "not_found");
+    }
+
+    #[test]
+    fn fake_config_vars_are_kept_and_released() {
+        let heroku = FakeHeroku::start().unwrap();
+        let api_client = get_fake_client(&heroku);
+        create_app(&api_client, "my-app");
+
+        let mut params = HashMap::new();
+        params.insert(String::from("LOG_LEVEL"), String::from("debug"));
+        api_client
+            .request(&config_vars::AppConfigVarUpdate {
+                app_id: "my-app",
+                params,
+            })
+            .unwrap();
+        let config_vars = api_client
+            .request(&config_vars::AppConfigVarDetails { app_id: "my-app" })
+            .unwrap();
+        assert_eq!(config_vars["LOG_LEVEL"], Some(String::from("debug")));
+
+        let mut params = HashMap::new();
+        params.insert(String::from("LOG_LEVEL"), None);
+        let config_vars = api_client
+            .request(&config_vars::AppConfigVarDelete {
+                app_id: "my-app",
+                params,
+            })
+            .unwrap();
+        assert!(config_vars.is_empty());
+
+        let releases = api_client
+            .request(&releases::ReleaseList { app_id: "my-app" })
+            .unwrap();
+        let descriptions: Vec<&str> = releases
+            .iter()
+            .map(|release| release.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Initial release",
+                "Set LOG_LEVEL config var",
+                "Remove LOG_LEVEL config var"
+            ]
+        );
+    }
+
+    #[test]
+    fn fake_scales_the_formation() {
+        let heroku = FakeHeroku::start().unwrap();
+        let api_client = get_fake_client(&heroku);
+        create_app(&api_client, "my-app");
+
+        api_client
+            .request(&formations::FormationUpdate {
+                app_id: "my-app",
+                formation_id: "web",
+                params: formations::FormationUpdateParams {
+                    quantity: Some(2),
+                    size: Some("standard-2X"),
+                },
+            })
+            .unwrap();
+
+        let formation = api_client
+            .request(&formations::FormationList { app_id: "my-app" })
+            .unwrap();
+        assert_eq!(formation.len(), 1);
+        assert_eq!(formation[0].r#type, "web");
+        assert_eq!(formation[0].quantity, 2);
+        assert_eq!(formation[0].size, "standard-2X");
+    }
+
+    #[test]
+    fn fake_releases_bump_versions_and_roll_back() {
+        let heroku = FakeHeroku::start().unwrap();
+        let api_client = get_fake_client(&heroku);
+        create_app(&api_client, "my-app");
+
+        let deployed = api_client
+            .request(&releases::ReleaseCreate {
+                app_id: "my-app",
+                params: releases::ReleaseCreateParams {
+                    slug: "01234567-89ab-cdef-0123-456789abcdef",
+                    description: Some("Deploy 0123456"),
+                },
+            })
+            .unwrap();
+        assert_eq!(deployed.version, 2);
+        assert!(deployed.current);
+
+        let mut params = HashMap::new();
+        params.insert(String::from("PORT"), String::from("5000"));
+        api_client
+            .request(&config_vars::AppConfigVarUpdate {
+                app_id: "my-app",
+                params,
+            })
+            .unwrap();
+
+        let rollback = api_client
+            .request(&releases::ReleaseRollback {
+                app_id: "my-app",
+                params: releases::ReleaseRollbackParams { release: "2" },
+            })
+            .unwrap();
+        assert_eq!(rollback.version, 4);
+        assert_eq!(rollback.description, "Rollback to v2");
+        assert_eq!(
+            rollback.slug.map(|slug| slug.id),
+            Some(String::from("01234567-89ab-cdef-0123-456789abcdef"))
+        );
+
+        let previous = api_client
+            .request(&releases::ReleaseInfo {
+                app_id: "my-app",
+                release_id: "2",
+            })
+            .unwrap();
+        assert!(!previous.current);
+        let config_vars = api_client
+            .request(&config_vars::AppConfigVarDetails { app_id: "my-app" })
+            .unwrap();
+        assert!(config_vars.is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+use heroku_rs::endpoints::{apps, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    endpoint::ExtraHeaders,
+    range::{ListEndpoint, Range},
+};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_sends_extra_header() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+
+        let endpoint = misc::RatelimitDetails::new().with_header(
+            HeaderName::from_static("heroku-two-factor-code"),
+            HeaderValue::from_static("123456"),
+        );
+        let ratelimit = stub_client(&server).request(&endpoint).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.header("heroku-two-factor-code"), Some("123456"));
+        assert_eq!(request.header("authorization"), Some("Bearer TOKEN_HERE"));
+    }
+
+    #[test]
+    fn request_raw_sends_extra_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-heroku-sudo-user"),
+            HeaderValue::from_static("user@example.com"),
+        );
+        headers.insert(
+            HeaderName::from_static("idempotency-key"),
+            HeaderValue::from_static("KEY"),
+        );
+
+        let endpoint = misc::RatelimitDetails::new().with_headers(headers);
+        let response = stub_client(&server).request_raw(&endpoint).unwrap();
+        assert_eq!(response.status(), 200);
+
+        let request = &server.requests()[0];
+        assert_eq!(
+            request.header("x-heroku-sudo-user"),
+            Some("user@example.com")
+        );
+        assert_eq!(request.header("idempotency-key"), Some("KEY"));
+    }
+
+    #[test]
+    fn extra_headers_replace_endpoint_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, "[]")]);
+
+        let endpoint = apps::AppList::new()
+            .with_range(Range::new("name").max(10).build())
+            .with_header(
+                http::header::RANGE,
+                HeaderValue::from_static("id ..; max=5"),
+            )
+            .with_header(
+                http::header::ACCEPT,
+                HeaderValue::from_static("application/vnd.heroku+json; version=3.sdk"),
+            );
+        stub_client(&server).request(&endpoint).unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.header("range"), Some("id ..; max=5"));
+        assert_eq!(
+            request.header("accept"),
+            Some("application/vnd.heroku+json; version=3.sdk")
+        );
+    }
+
+    #[test]
+    fn wrapped_endpoint_keeps_its_range() {
+        let server = StubServer::start(vec![StubResponse::new(200, "[]")]);
+
+        let endpoint = apps::AppList::new()
+            .with_header(
+                HeaderName::from_static("x-request-id"),
+                HeaderValue::from_static("CORRELATION_ID"),
+            )
+            .with_range(Range::new("name").max(10).build());
+        stub_client(&server).request(&endpoint).unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.header("range"), Some("name ..; max=10"));
+        assert_eq!(request.header("x-request-id"), Some("CORRELATION_ID"));
+    }
+}
@@ -0,0 +1,155 @@
+use heroku_rs::endpoints::{misc, releases};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    endpoint::HerokuEndpoint,
+    response::{parse_response, ApiResponse, ApiResult},
+};
+use serde::Serialize;
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+const RELEASE: &str = r#"{
+    "addon_plan_names": [],
+    "app": {"name": "example", "id": "01234567-89ab-cdef-0123-456789abcdef"},
+    "created_at": "2012-01-01T12:00:00Z",
+    "description": "Added new feature",
+    "status": "succeeded",
+    "id": "01234567-89ab-cdef-0123-456789abcdef",
+    "slug": null,
+    "updated_at": "2012-01-01T12:00:00Z",
+    "user": {"id": "01234567-89ab-cdef-0123-456789abcdef", "email": "username@example.com"},
+    "version": 11,
+    "current": true,
+    "output_stream_url": null
+}"#;
+
+/// A client implementing only the required methods, answering every request with the same headers.
+struct CannedClient;
+
+impl HerokuApiClient for CannedClient {
+    fn request<ResultType, QueryType, BodyType>(
+        &self,
+        endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<ResultType>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        parse_response(self.request_raw(endpoint)?)
+    }
+
+    fn request_raw<ResultType, QueryType, BodyType>(
+        &self,
+        _endpoint: &dyn HerokuEndpoint<ResultType, QueryType, BodyType>,
+    ) -> ApiResponse<http::Response<Vec<u8>>>
+    where
+        ResultType: ApiResult,
+        QueryType: Serialize,
+        BodyType: Serialize,
+    {
+        Ok(http::Response::builder()
+            .status(200)
+            .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")
+            .header("RateLimit-Remaining", "2399")
+            .body(br#"{"remaining": 2400}"#.to_vec())
+            .unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_has_heroku_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)
+            .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")
+            .header("RateLimit-Remaining", "2399")
+            .header("ETag", "\"0123456789abcdef\"")
+            .header("Warning", "299 - \"Deprecated endpoint\"")]);
+
+        let response = stub_client(&server)
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .unwrap();
+
+        assert_eq!(response.result.remaining, 2400);
+        assert_eq!(response.meta.status.as_u16(), 200);
+        assert_eq!(
+            response.meta.request_id.as_deref(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+        assert_eq!(response.meta.ratelimit_remaining, Some(2399));
+        assert_eq!(response.meta.etag.as_deref(), Some("\"0123456789abcdef\""));
+        assert_eq!(
+            response.meta.warning.as_deref(),
+            Some("299 - \"Deprecated endpoint\"")
+        );
+        assert_eq!(response.meta.next_range, None);
+    }
+
+    #[test]
+    fn meta_without_headers() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+
+        let response = stub_client(&server)
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .unwrap();
+
+        assert_eq!(response.meta.request_id, None);
+        assert_eq!(response.meta.ratelimit_remaining, None);
+        assert_eq!(response.meta.etag, None);
+        assert_eq!(response.meta.warning, None);
+    }
+
+    #[test]
+    fn meta_has_next_range_of_partial_response() {
+        let server = StubServer::start(vec![StubResponse::new(206, &format!("[{}]", RELEASE))
+            .header("Next-Range", "version ]11..; max=1")]);
+
+        let response = stub_client(&server)
+            .request_with_meta(&releases::ReleaseList::new("example"))
+            .unwrap();
+
+        assert_eq!(response.result.len(), 1);
+        assert_eq!(response.meta.status.as_u16(), 206);
+        assert_eq!(
+            response.meta.next_range.as_deref(),
+            Some("version ]11..; max=1")
+        );
+    }
+
+    #[test]
+    fn failure_keeps_request_id() {
+        let server = StubServer::start(vec![StubResponse::new(
+            404,
+            r#"{"id": "not_found", "message": "Couldn't find that app."}"#,
+        )
+        .header("Request-Id", "fedcba98-7654-3210-fedc-ba9876543210")]);
+
+        let failure = stub_client(&server)
+            .request_with_meta(&releases::ReleaseList::new("example"))
+            .unwrap_err();
+
+        assert!(failure.is_not_found());
+        assert_eq!(
+            failure.request_id(),
+            Some("fedcba98-7654-3210-fedc-ba9876543210")
+        );
+    }
+
+    #[test]
+    fn meta_of_a_client_with_only_request_raw() {
+        let response = CannedClient
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .unwrap();
+
+        assert_eq!(response.result.remaining, 2400);
+        assert_eq!(response.meta.status.as_u16(), 200);
+        assert_eq!(
+            response.meta.request_id.as_deref(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+        assert_eq!(response.meta.ratelimit_remaining, Some(2399));
+    }
+}
@@ -0,0 +1,143 @@
+use heroku_rs::endpoints::{apps, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, middleware::Middleware, HttpApiClient, HttpApiClientConfig,
+};
+use http::{Request, Response};
+use std::sync::{Arc, Mutex};
+use stub::{stub_client_with, stub_config, StubResponse, StubServer};
+mod stub;
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+/// Records its hooks in a shared log, and optionally answers instead of Heroku.
+struct Recorder {
+    name: &'static str,
+    log: Log,
+    answer: Option<&'static str>,
+}
+
+impl Middleware for Recorder {
+    fn before_send(&self, request: &mut Request<Vec<u8>>) -> Option<Response<Vec<u8>>> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} before", self.name));
+        request
+            .headers_mut()
+            .append("x-middleware", self.name.parse().unwrap());
+        self.answer
+            .map(|body| Response::builder().status(200).body(body.into()).unwrap())
+    }
+
+    fn after_receive(&self, request: &Request<Vec<u8>>, response: &mut Response<Vec<u8>>) {
+        self.log.lock().unwrap().push(format!(
+            "{} after {} {} {}",
+            self.name,
+            request.method(),
+            request.uri().path(),
+            response.status().as_u16()
+        ));
+    }
+}
+
+fn recorder(name: &'static str, log: &Log) -> Arc<dyn Middleware> {
+    Arc::new(Recorder {
+        name,
+        log: log.clone(),
+        answer: None,
+    })
+}
+
+fn get_stub_client(server: &StubServer, middlewares: Vec<Arc<dyn Middleware>>) -> HttpApiClient {
+    stub_client_with(
+        server,
+        HttpApiClientConfig {
+            middlewares,
+            ..stub_config()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middlewares_run_in_order_around_the_request() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let log = Log::default();
+        let api_client = get_stub_client(
+            &server,
+            vec![recorder("first", &log), recorder("second", &log)],
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first before",
+                "second before",
+                "second after GET /account/rate-limits 200",
+                "first after GET /account/rate-limits 200",
+            ]
+        );
+        let request = &server.requests()[0];
+        assert_eq!(
+            request
+                .headers
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case("x-middleware"))
+                .map(|(_, value)| value.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["first", "second"]
+        );
+    }
+
+    #[test]
+    fn middleware_short_circuits_the_request() {
+        let server = StubServer::start(vec![]);
+        let log = Log::default();
+        let answering = Arc::new(Recorder {
+            name: "answering",
+            log: log.clone(),
+            answer: Some(r#"{"remaining": 42}"#),
+        });
+        let api_client = get_stub_client(
+            &server,
+            vec![recorder("first", &log), answering, recorder("last", &log)],
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 42);
+
+        assert!(server.requests().is_empty());
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first before",
+                "answering before",
+                "first after GET /account/rate-limits 200",
+            ]
+        );
+    }
+
+    #[test]
+    fn middlewares_see_raw_requests() {
+        let server = StubServer::start(vec![StubResponse::new(200, "{}")]);
+        let log = Log::default();
+        let api_client = get_stub_client(&server, vec![recorder("audit", &log)]);
+
+        let response = api_client
+            .request_raw(&apps::AppDelete { app_id: "example" })
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["audit before", "audit after DELETE /apps/example 200"]
+        );
+        assert_eq!(server.requests()[0].header("x-middleware"), Some("audit"));
+    }
+}
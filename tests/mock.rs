@@ -0,0 +1,191 @@
+#![cfg(feature = "mock")]
+use heroku_rs::endpoints::{config_vars, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    dyn_client::DynHerokuApiClient,
+    endpoint::Method,
+    mock::{MockApiClient, MockResponse},
+    response::{HerokuApiError, HerokuApiFailure},
+};
+use http::StatusCode;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Code under test, generic over the client: sets a config var and returns how many the app has.
+fn set_config_var<C: HerokuApiClient>(
+    api_client: &C,
+    app_id: &str,
+    key: &str,
+    value: &str,
+) -> Option<usize> {
+    let mut params = HashMap::new();
+    params.insert(key.to_string(), value.to_string());
+    api_client
+        .request(&config_vars::AppConfigVarUpdate { app_id, params })
+        .ok()
+        .map(|config_vars| config_vars.len())
+}
+
+/// The same code, taking any client at runtime.
+fn set_config_var_dyn(
+    api_client: &dyn DynHerokuApiClient,
+    app_id: &str,
+    key: &str,
+    value: &str,
+) -> Option<usize> {
+    let mut params = HashMap::new();
+    params.insert(key.to_string(), value.to_string());
+    api_client
+        .request(&config_vars::AppConfigVarUpdate { app_id, params })
+        .ok()
+        .map(|config_vars| config_vars.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_answers_expected_requests_and_records_them() {
+        let api_client = MockApiClient::new();
+        api_client.expect(
+            Method::Patch,
+            "apps/my-app/config-vars",
+            MockResponse::json(r#"{"LOG_LEVEL": "debug", "PORT": "5000"}"#),
+        );
+
+        assert_eq!(
+            set_config_var(&api_client, "my-app", "LOG_LEVEL", "debug"),
+            Some(2)
+        );
+
+        let calls = api_client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method, Method::Patch);
+        assert_eq!(calls[0].path, "apps/my-app/config-vars");
+        assert_eq!(calls[0].body, Some(json!({"LOG_LEVEL": "debug"})));
+        api_client.verify();
+    }
+
+    #[test]
+    fn mock_answers_in_registration_order() {
+        let api_client = MockApiClient::new();
+        api_client
+            .expect(
+                Method::Get,
+                "/account/rate-limits",
+                MockResponse::json(r#"{"remaining": 2}"#),
+            )
+            .expect(
+                Method::Get,
+                "account/rate-limits",
+                MockResponse::json(r#"{"remaining": 1}"#),
+            );
+
+        let first = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        let second = api_client
+            .request_with_meta(&misc::RatelimitDetails::new())
+            .unwrap();
+
+        assert_eq!(first.remaining, 2);
+        assert_eq!(second.result.remaining, 1);
+        assert_eq!(second.meta.status, StatusCode::OK);
+        assert_eq!(api_client.calls().len(), 2);
+    }
+
+    #[test]
+    fn mock_returns_heroku_errors() {
+        let api_client = MockApiClient::new();
+        api_client.expect(
+            Method::Get,
+            "apps/missing/config-vars",
+            MockResponse::error(
+                StatusCode::NOT_FOUND,
+                "not_found",
+                "Couldn't find that app.",
+            ),
+        );
+
+        let failure = api_client
+            .request(&config_vars::AppConfigVarDetails { app_id: "missing" })
+            .unwrap_err();
+
+        assert!(failure.is_not_found());
+        match failure {
+            HerokuApiFailure::Error(_, error) => {
+                assert_eq!(error.message, "Couldn't find that app.")
+            }
+            other => panic!("Expected a Heroku error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn mock_returns_canned_failures() {
+        let api_client = MockApiClient::new();
+        api_client.expect(
+            Method::Get,
+            "account/rate-limits",
+            MockResponse::from(HerokuApiFailure::Error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                HerokuApiError {
+                    id: String::from("unavailable"),
+                    message: String::from("Service temporarily unavailable"),
+                    ..HerokuApiError::default()
+                },
+            )),
+        );
+
+        match api_client.request(&misc::RatelimitDetails::new()) {
+            Err(HerokuApiFailure::Error(status, error)) => {
+                assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(error.id, "unavailable");
+            }
+            other => panic!("Expected a Heroku error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected request Get account/rate-limits")]
+    fn mock_panics_on_unexpected_requests() {
+        let api_client = MockApiClient::new();
+        api_client.expect(
+            Method::Patch,
+            "apps/my-app/config-vars",
+            MockResponse::json("{}"),
+        );
+
+        let _ = api_client.request(&misc::RatelimitDetails::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected requests were not made: Patch apps/my-app/config-vars")]
+    fn mock_verify_panics_on_missing_requests() {
+        let api_client = MockApiClient::new();
+        api_client.expect(
+            Method::Patch,
+            "apps/my-app/config-vars",
+            MockResponse::json("{}"),
+        );
+
+        api_client.verify();
+    }
+
+    #[test]
+    fn mock_is_a_dyn_client() {
+        let mock = Arc::new(MockApiClient::new());
+        mock.expect(
+            Method::Patch,
+            "apps/my-app/config-vars",
+            MockResponse::json(r#"{"PORT": "5000"}"#),
+        );
+        let api_client: Arc<dyn DynHerokuApiClient> = mock.clone();
+
+        assert_eq!(
+            set_config_var_dyn(api_client.as_ref(), "my-app", "PORT", "5000"),
+            Some(1)
+        );
+        assert_eq!(mock.calls()[0].body, Some(json!({ "PORT": "5000" })));
+        mock.verify();
+    }
+}
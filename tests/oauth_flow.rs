@@ -0,0 +1,256 @@
+use heroku_rs::framework::{
+    auth::Credentials,
+    oauth_flow::{OAuthFlow, OAuthFlowError},
+    transport::ReqwestTransport,
+    ApiEnvironment,
+};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+use stub::{StubResponse, StubServer};
+mod stub;
+
+/// Play the browser being redirected to `path` on the listener, returning the response it gets.
+fn redirect(listener: &TcpListener, path: &str) -> thread::JoinHandle<String> {
+    let address = listener.local_addr().unwrap();
+    let path = path.to_string();
+    thread::spawn(move || {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: {}\r\nAccept: text/html\r\n\r\n",
+            path, address
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_has_client_scopes_and_state() {
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .scopes(&["read", "write"])
+            .state("STATE")
+            .build();
+
+        assert_eq!(
+            flow.authorize_url().as_str(),
+            "https://id.heroku.com/oauth/authorize?client_id=CLIENT_ID&response_type=code&scope=read+write&state=STATE"
+        );
+    }
+
+    #[test]
+    fn debug_hides_the_client_secret() {
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET");
+
+        let debug = format!("{:?}", flow);
+        assert!(debug.contains("CLIENT_ID"));
+        assert!(!debug.contains("CLIENT_SECRET"));
+    }
+
+    #[test]
+    fn state_is_random() {
+        let first = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET");
+        let second = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET");
+
+        assert_eq!(first.state.len(), 32);
+        assert_ne!(first.state, second.state);
+    }
+
+    #[test]
+    fn receive_code_from_redirect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .build();
+
+        // The browser asks for a favicon before being redirected.
+        let favicon = redirect(&listener, "/favicon.ico");
+        let browser = {
+            let listener = listener.try_clone().unwrap();
+            thread::spawn(move || {
+                let favicon = favicon.join().unwrap();
+                (
+                    favicon,
+                    redirect(&listener, "/callback?code=CODE&state=STATE")
+                        .join()
+                        .unwrap(),
+                )
+            })
+        };
+
+        assert_eq!(flow.receive_code(&listener).unwrap(), "CODE");
+        let (favicon, callback) = browser.join().unwrap();
+        assert!(favicon.starts_with("HTTP/1.1 404"));
+        assert!(callback.starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn receive_code_skips_idle_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .build();
+
+        // A speculative connection which never sends a request, and one closed without a request.
+        let preconnect = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        drop(TcpStream::connect(listener.local_addr().unwrap()).unwrap());
+        let browser = redirect(&listener, "/callback?code=CODE&state=STATE");
+
+        assert_eq!(flow.receive_code(&listener).unwrap(), "CODE");
+        assert!(browser.join().unwrap().starts_with("HTTP/1.1 200"));
+        drop(preconnect);
+    }
+
+    #[test]
+    fn receive_code_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .timeout(Duration::from_millis(300))
+            .build();
+        let _preconnect = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        let start = Instant::now();
+        match flow.receive_code(&listener) {
+            Err(OAuthFlowError::Timeout) => {}
+            other => panic!("Expected a timeout, got {:?}", other),
+        }
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn receive_code_ignores_forged_redirects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .build();
+
+        // Redirects with a wrong or no state, before the one from Heroku.
+        let forged_code = redirect(&listener, "/callback?code=FORGED&state=FORGED");
+        let browser = {
+            let listener = listener.try_clone().unwrap();
+            thread::spawn(move || {
+                let forged_code = forged_code.join().unwrap();
+                let forged_error = redirect(&listener, "/callback?error=access_denied")
+                    .join()
+                    .unwrap();
+                let callback = redirect(&listener, "/callback?code=CODE&state=STATE")
+                    .join()
+                    .unwrap();
+                (forged_code, forged_error, callback)
+            })
+        };
+
+        assert_eq!(flow.receive_code(&listener).unwrap(), "CODE");
+        let (forged_code, forged_error, callback) = browser.join().unwrap();
+        assert!(forged_code.starts_with("HTTP/1.1 400"));
+        assert!(forged_error.starts_with("HTTP/1.1 400"));
+        assert!(callback.starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn receive_code_times_out_after_forged_redirects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .timeout(Duration::from_millis(300))
+            .build();
+
+        let forged = redirect(&listener, "/callback?error=access_denied&state=FORGED");
+
+        match flow.receive_code(&listener) {
+            Err(OAuthFlowError::Timeout) => {}
+            other => panic!("Expected a timeout, got {:?}", other),
+        }
+        assert!(forged.join().unwrap().starts_with("HTTP/1.1 400"));
+    }
+
+    #[test]
+    fn receive_code_reports_a_missing_code() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .build();
+
+        let browser = redirect(&listener, "/callback?state=STATE");
+
+        match flow.receive_code(&listener) {
+            Err(OAuthFlowError::MissingCode) => {}
+            other => panic!("Expected a missing code, got {:?}", other),
+        }
+        assert!(browser.join().unwrap().starts_with("HTTP/1.1 400"));
+    }
+
+    #[test]
+    fn receive_code_reports_denied_access() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .state("STATE")
+            .build();
+
+        let browser = redirect(
+            &listener,
+            "/callback?error=access_denied&error_description=User+denied+access&state=STATE",
+        );
+
+        match flow.receive_code(&listener) {
+            Err(OAuthFlowError::Denied { error, description }) => {
+                assert_eq!(error, "access_denied");
+                assert_eq!(description.as_deref(), Some("User denied access"));
+            }
+            other => panic!("Expected a denied access, got {:?}", other),
+        }
+        browser.join().unwrap();
+    }
+
+    #[test]
+    fn exchange_code_for_token() {
+        let server = StubServer::start(vec![StubResponse::new(
+            201,
+            r#"{
+                "access_token": {"expires_in": 28800, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "ACCESS_TOKEN"},
+                "authorization": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                "client": {"secret": "CLIENT_SECRET"},
+                "created_at": "2012-01-01T12:00:00Z",
+                "grant": {"code": "CODE", "type": "authorization_code"},
+                "id": "01234567-89ab-cdef-0123-456789abcdef",
+                "refresh_token": {"expires_in": null, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "REFRESH_TOKEN"},
+                "session": {"id": "01234567-89ab-cdef-0123-456789abcdef"},
+                "updated_at": "2012-01-01T12:00:00Z",
+                "user": {"id": "01234567-89ab-cdef-0123-456789abcdef"}
+            }"#,
+        )]);
+        let flow = OAuthFlow::new("CLIENT_ID", "CLIENT_SECRET")
+            .api_environment(ApiEnvironment::Custom(server.url()))
+            .build();
+
+        let token = flow.exchange(&ReqwestTransport::default(), "CODE").unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/oauth/tokens");
+        assert_eq!(request.header("authorization"), None);
+        assert!(request
+            .body
+            .contains(r#""grant":{"code":"CODE","type":"authorization_code"}"#));
+        assert!(request.body.contains(r#""secret":"CLIENT_SECRET""#));
+
+        match Credentials::oauth(&token, &flow.client_secret) {
+            Credentials::OAuth {
+                access_token,
+                refresh_token,
+                ..
+            } => {
+                assert_eq!(access_token, "ACCESS_TOKEN");
+                assert_eq!(refresh_token, "REFRESH_TOKEN");
+            }
+            other => panic!("Expected OAuth credentials, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, auth::Credentials, retry::RetryPolicy, ApiEnvironment,
+    HttpApiClient, HttpApiClientConfig,
+};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use stub::{StubResponse, StubServer};
+mod stub;
+
+/// The body of an `OAuthTokenCreate` response with a new access token.
+fn oauth_token(access_token: &str) -> String {
+    format!(
+        r#"{{
+            "access_token": {{"expires_in": 28800, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "{}"}},
+            "authorization": {{"id": "01234567-89ab-cdef-0123-456789abcdef"}},
+            "client": {{"secret": "CLIENT_SECRET"}},
+            "created_at": "2012-01-01T12:00:00Z",
+            "grant": {{"code": "", "type": "refresh_token"}},
+            "id": "01234567-89ab-cdef-0123-456789abcdef",
+            "refresh_token": {{"expires_in": null, "id": "01234567-89ab-cdef-0123-456789abcdef", "token": "REFRESH_TOKEN"}},
+            "session": {{"id": "01234567-89ab-cdef-0123-456789abcdef"}},
+            "updated_at": "2012-01-01T12:00:00Z",
+            "user": {{"id": "01234567-89ab-cdef-0123-456789abcdef"}}
+        }}"#,
+        access_token
+    )
+}
+
+fn oauth_credentials(expires_at: Option<SystemTime>) -> Credentials {
+    Credentials::OAuth {
+        access_token: String::from("OLD_TOKEN"),
+        refresh_token: String::from("REFRESH_TOKEN"),
+        client_secret: String::from("CLIENT_SECRET"),
+        expires_at,
+    }
+}
+
+/// A client which records the credentials it refreshes.
+fn get_oauth_client(
+    server: &StubServer,
+    credentials: Credentials,
+) -> (HttpApiClient, Arc<Mutex<Vec<Credentials>>>) {
+    let refreshed = Arc::new(Mutex::new(Vec::new()));
+    let on_refresh = refreshed.clone();
+    let api_client = HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig {
+            retry_policy: RetryPolicy::none(),
+            on_token_refresh: Some(Arc::new(move |credentials: &Credentials| {
+                on_refresh.lock().unwrap().push(credentials.clone())
+            })),
+            ..HttpApiClientConfig::default()
+        },
+        ApiEnvironment::Custom(server.url()),
+    )
+    .unwrap();
+    (api_client, refreshed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expiring_token_is_refreshed_first() {
+        let server = StubServer::start(vec![
+            StubResponse::new(201, &oauth_token("NEW_TOKEN")),
+            StubResponse::new(200, r#"{"remaining": 2400}"#),
+        ]);
+        let (api_client, refreshed) = get_oauth_client(
+            &server,
+            oauth_credentials(Some(SystemTime::now() + Duration::from_secs(30))),
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/oauth/tokens");
+        assert_eq!(requests[0].header("authorization"), None);
+        assert!(requests[0].body.contains(r#""type":"refresh_token""#));
+        assert!(requests[0].body.contains(r#""token":"REFRESH_TOKEN""#));
+        assert!(requests[0].body.contains(r#""secret":"CLIENT_SECRET""#));
+        assert_eq!(
+            requests[1].header("authorization"),
+            Some("Bearer NEW_TOKEN")
+        );
+
+        let refreshed = refreshed.lock().unwrap();
+        assert_eq!(refreshed.len(), 1);
+        match &refreshed[0] {
+            Credentials::OAuth {
+                access_token,
+                expires_at,
+                ..
+            } => {
+                assert_eq!(access_token, "NEW_TOKEN");
+                assert!(expires_at.unwrap() > SystemTime::now() + Duration::from_secs(60 * 60));
+            }
+            other => panic!("Expected OAuth credentials, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn concurrent_requests_refresh_an_expiring_token_once() {
+        let mut responses = vec![StubResponse::new(201, &oauth_token("NEW_TOKEN"))];
+        responses.extend((0..8).map(|_| StubResponse::new(200, r#"{"remaining": 2400}"#)));
+        let server = StubServer::start(responses);
+        let (api_client, refreshed) = get_oauth_client(
+            &server,
+            oauth_credentials(Some(SystemTime::now() + Duration::from_secs(30))),
+        );
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| api_client.request(&misc::RatelimitDetails::new()).unwrap());
+            }
+        });
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 9);
+        assert_eq!(requests[0].path, "/oauth/tokens");
+        assert!(requests[1..]
+            .iter()
+            .all(|request| request.header("authorization") == Some("Bearer NEW_TOKEN")));
+        assert_eq!(refreshed.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn valid_token_is_not_refreshed() {
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let (api_client, refreshed) = get_oauth_client(
+            &server,
+            oauth_credentials(Some(SystemTime::now() + Duration::from_secs(60 * 60))),
+        );
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            server.requests()[0].header("authorization"),
+            Some("Bearer OLD_TOKEN")
+        );
+        assert!(refreshed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejected_token_is_refreshed_and_sent_again() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                401,
+                r#"{"id": "unauthorized", "message": "Invalid credentials provided."}"#,
+            ),
+            StubResponse::new(201, &oauth_token("NEW_TOKEN")),
+            StubResponse::new(200, r#"{"remaining": 2400}"#),
+        ]);
+        let (api_client, refreshed) = get_oauth_client(&server, oauth_credentials(None));
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer OLD_TOKEN")
+        );
+        assert_eq!(requests[1].path, "/oauth/tokens");
+        assert_eq!(
+            requests[2].header("authorization"),
+            Some("Bearer NEW_TOKEN")
+        );
+        assert_eq!(refreshed.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn token_rejected_after_refresh_fails() {
+        let unauthorized = StubResponse::new(
+            401,
+            r#"{"id": "unauthorized", "message": "Invalid credentials provided."}"#,
+        );
+        let server = StubServer::start(vec![
+            unauthorized.clone(),
+            StubResponse::new(201, &oauth_token("NEW_TOKEN")),
+            unauthorized,
+        ]);
+        let (api_client, _) = get_oauth_client(&server, oauth_credentials(None));
+
+        let failure = api_client
+            .request(&misc::RatelimitDetails::new())
+            .unwrap_err();
+
+        assert!(failure.is_unauthorized());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn api_key_is_not_refreshed() {
+        let server = StubServer::start(vec![StubResponse::new(
+            401,
+            r#"{"id": "unauthorized", "message": "Invalid credentials provided."}"#,
+        )]);
+        let (api_client, _) = get_oauth_client(
+            &server,
+            Credentials::UserAuthToken {
+                token: String::from("API_KEY"),
+            },
+        );
+
+        assert!(api_client
+            .request(&misc::RatelimitDetails::new())
+            .unwrap_err()
+            .is_unauthorized());
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,149 @@
+use heroku_rs::endpoints::{dynos, releases};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    auth::Credentials,
+    endpoint::{ExtraHeaders, Method},
+    owned::OwnedEndpoint,
+    range::{ListEndpoint, Range, RangeOrder},
+    request::build_request,
+    ApiEnvironment,
+};
+use http::header::{HeaderName, HeaderValue};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+const DYNO: &str = r#"{
+    "app": {"id": "01234567-89ab-cdef-0123-456789abcdef", "name": "my-app"},
+    "attach_url": null,
+    "command": "rake db:migrate",
+    "created_at": "2012-01-01T12:00:00Z",
+    "id": "01234567-89ab-cdef-0123-456789abcdef",
+    "name": "run.1",
+    "release": {"id": "01234567-89ab-cdef-0123-456789abcdef", "version": 11},
+    "size": "standard-1X",
+    "state": "starting",
+    "type": "run",
+    "updated_at": "2012-01-01T12:00:00Z"
+}"#;
+
+/// Builds the endpoint from strings which are dropped before it is returned.
+fn dyno_creation(app_id: String, command: String) -> OwnedEndpoint<dynos::Dyno> {
+    let mut env = HashMap::new();
+    env.insert("RAILS_ENV", "production");
+    OwnedEndpoint::new(&dynos::DynoCreate {
+        app_id: &app_id,
+        params: dynos::DynoCreateParams {
+            command: &command,
+            attach: Some(false),
+            env: Some(env),
+            force_no_tty: None,
+            size: None,
+            time_to_live: Some(1800),
+            r#type: None,
+        },
+    })
+    .unwrap()
+}
+
+fn is_queueable<T: Serialize + DeserializeOwned + Send + Sync + 'static>() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_endpoints_are_sent_from_another_thread() {
+        is_queueable::<OwnedEndpoint<dynos::Dyno>>();
+
+        let server = StubServer::start(vec![StubResponse::new(201, DYNO)]);
+        let api_client = stub_client(&server);
+        let endpoint = dyno_creation(String::from("my-app"), String::from("rake db:migrate"));
+
+        let (queue, jobs) = mpsc::channel::<OwnedEndpoint<dynos::Dyno>>();
+        let worker = thread::spawn(move || {
+            let endpoint = jobs.recv().unwrap();
+            api_client.request(&endpoint).unwrap()
+        });
+        queue.send(endpoint).unwrap();
+
+        let dyno = worker.join().unwrap();
+        assert_eq!(dyno.name, "run.1");
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/apps/my-app/dynos");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            json!({
+                "command": "rake db:migrate",
+                "attach": false,
+                "env": {"RAILS_ENV": "production"},
+                "force_no_tty": null,
+                "time_to_live": 1800
+            })
+        );
+    }
+
+    #[test]
+    fn owned_endpoints_are_persisted_and_replayed() {
+        let endpoint = OwnedEndpoint::new(
+            &releases::ReleaseList { app_id: "my-app" }
+                .with_range(Range::new("version").order(RangeOrder::Desc).max(5).build())
+                .with_header(
+                    HeaderName::from_static("heroku-two-factor-code"),
+                    HeaderValue::from_static("123456"),
+                ),
+        )
+        .unwrap();
+
+        let job = serde_json::to_value(&endpoint).unwrap();
+        assert_eq!(job["result_type"], "Vec<Release>");
+        assert_eq!(job["method"], "Get");
+        assert_eq!(job["path"], "apps/my-app/releases");
+        assert_eq!(job["range"]["order"], "Desc");
+        assert_eq!(
+            job["headers"],
+            json!([["heroku-two-factor-code", "123456"]])
+        );
+
+        let replayed: OwnedEndpoint<Vec<releases::Release>> = serde_json::from_value(job).unwrap();
+        assert_eq!(replayed, endpoint);
+        assert_eq!(replayed.request().method, Method::Get);
+
+        let credentials = Credentials::UserAuthToken {
+            token: String::from("TOKEN_HERE"),
+        };
+        let request = build_request(&replayed, &ApiEnvironment::Production, &credentials).unwrap();
+        assert_eq!(request.uri(), "https://api.heroku.com/apps/my-app/releases");
+        assert_eq!(request.headers()["range"], "version ..; max=5, order=desc");
+        assert_eq!(request.headers()["heroku-two-factor-code"], "123456");
+        assert!(request.body().is_empty());
+    }
+
+    #[test]
+    fn persisted_endpoints_keep_their_result_type() {
+        let job =
+            serde_json::to_string(&dyno_creation(String::from("my-app"), String::from("bash")))
+                .unwrap();
+
+        let replayed = serde_json::from_str::<OwnedEndpoint<Vec<dynos::Dyno>>>(&job);
+        let error = replayed.unwrap_err().to_string();
+        assert!(error.contains("not `Vec<Dyno>`"));
+        assert!(serde_json::from_str::<OwnedEndpoint<dynos::Dyno>>(&job).is_ok());
+    }
+
+    #[test]
+    fn invalid_persisted_headers_are_rejected() {
+        let mut job =
+            serde_json::to_value(dyno_creation(String::from("my-app"), String::from("bash")))
+                .unwrap();
+        job["headers"] = json!([["not a header", "value"]]);
+
+        let replayed = serde_json::from_value::<OwnedEndpoint<dynos::Dyno>>(job);
+        assert!(replayed.is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use heroku_rs::endpoints::{misc, releases};
+use heroku_rs::framework::response::HerokuApiFailure;
+use stub::{stub_client, StubResponse, StubServer};
+mod stub;
+
+fn stacks(names: &[&str]) -> String {
+    let stacks: Vec<String> = names
+        .iter()
+        .map(|name| {
+            format!(
+                r#"{{"default": false, "created_at": "2012-01-01T12:00:00Z", "id": "{0}", "name": "{0}", "state": "public", "updated_at": "2012-01-01T12:00:00Z"}}"#,
+                name
+            )
+        })
+        .collect();
+    format!("[{}]", stacks.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+    use heroku_rs::framework::range::{ListEndpoint, Range, RangeOrder};
+
+    #[test]
+    fn request_all_follows_next_range() {
+        let server = StubServer::start(vec![
+            StubResponse::new(206, &stacks(&["heroku-16", "heroku-18"]))
+                .header("Next-Range", "]heroku-18..; max=2"),
+            StubResponse::new(206, &stacks(&["heroku-20", "cedar-14"]))
+                .header("Next-Range", "]cedar-14..; max=2"),
+            StubResponse::new(200, &stacks(&["container"])),
+        ]);
+
+        let stacks = stub_client(&server)
+            .request_all(&misc::StackList::new())
+            .unwrap();
+        let names: Vec<&str> = stacks.iter().map(|stack| stack.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "heroku-16",
+                "heroku-18",
+                "heroku-20",
+                "cedar-14",
+                "container"
+            ]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].header("range"), None);
+        assert_eq!(requests[1].header("range"), Some("]heroku-18..; max=2"));
+        assert_eq!(requests[2].header("range"), Some("]cedar-14..; max=2"));
+        assert!(requests.iter().all(|request| request.path == "/stacks"));
+    }
+
+    #[test]
+    fn request_all_single_page() {
+        let server = StubServer::start(vec![StubResponse::new(200, &stacks(&["heroku-20"]))]);
+
+        let stacks = stub_client(&server)
+            .request_all(&misc::StackList::new())
+            .unwrap();
+        assert_eq!(stacks.len(), 1);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn request_all_ignores_next_range_on_complete_response() {
+        let server = StubServer::start(vec![StubResponse::new(200, &stacks(&["heroku-20"]))
+            .header("Next-Range", "]heroku-20..; max=200")]);
+
+        let stacks = stub_client(&server)
+            .request_all(&misc::StackList::new())
+            .unwrap();
+        assert_eq!(stacks.len(), 1);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn request_all_stops_on_error_page() {
+        let server = StubServer::start(vec![
+            StubResponse::new(206, &stacks(&["heroku-18"]))
+                .header("Next-Range", "]heroku-18..; max=1"),
+            StubResponse::new(
+                429,
+                r#"{"id": "rate_limit", "message": "Your account reached the API rate limit"}"#,
+            ),
+        ]);
+
+        let response = stub_client(&server).request_all(&misc::StackList::new());
+        match response {
+            Err(HerokuApiFailure::Error(status, error)) => {
+                assert_eq!(status, 429);
+                assert_eq!(error.id, "rate_limit");
+            }
+            other => panic!("Expected a rate_limit error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn range_header_formatting() {
+        assert_eq!(Range::new("id").to_string(), "id ..");
+        assert_eq!(
+            Range::new("version")
+                .order(RangeOrder::Desc)
+                .max(5)
+                .build()
+                .to_string(),
+            "version ..; max=5, order=desc"
+        );
+        assert_eq!(
+            Range::new("name")
+                .start("a")
+                .end("m")
+                .order(RangeOrder::Asc)
+                .build()
+                .to_string(),
+            "name a..m; order=asc"
+        );
+    }
+
+    #[test]
+    fn request_sends_range_header() {
+        let server = StubServer::start(vec![StubResponse::new(200, "[]")]);
+        let range = Range::new("version").order(RangeOrder::Desc).max(5).build();
+
+        let releases = stub_client(&server)
+            .request(&releases::ReleaseList::new("123xyz").with_range(range))
+            .unwrap();
+        assert!(releases.is_empty());
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/apps/123xyz/releases");
+        assert_eq!(
+            requests[0].header("range"),
+            Some("version ..; max=5, order=desc")
+        );
+    }
+
+    #[test]
+    fn request_all_starts_from_range_and_follows_next_range() {
+        let server = StubServer::start(vec![
+            StubResponse::new(206, &stacks(&["heroku-20"]))
+                .header("Next-Range", "]heroku-20..; max=1, order=desc"),
+            StubResponse::new(200, &stacks(&["heroku-18"])),
+        ]);
+        let range = Range::new("name").order(RangeOrder::Desc).max(1).build();
+
+        let stacks = stub_client(&server)
+            .request_all(&misc::StackList::new().with_range(range))
+            .unwrap();
+        assert_eq!(stacks.len(), 2);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("range"),
+            Some("name ..; max=1, order=desc")
+        );
+        assert_eq!(
+            requests[1].header("range"),
+            Some("]heroku-20..; max=1, order=desc")
+        );
+        assert_eq!(
+            requests[1]
+                .headers
+                .iter()
+                .filter(|(k, _)| k.eq_ignore_ascii_case("range"))
+                .count(),
+            1
+        );
+    }
+}
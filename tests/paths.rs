@@ -0,0 +1,1164 @@
+use heroku_rs::endpoints::{
+    account, addons, apps, builds, collaborators, config_vars, domains, dynos, formations, logs,
+    misc, oauth, pipelines, releases, review, slugs, space, teams, testing,
+};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    auth::Credentials,
+    endpoint::{encode_segment, HerokuEndpoint},
+    request::build_request,
+    response::{ApiResult, HerokuApiFailure},
+    ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A seeded xorshift generator, so a failing id can be reproduced.
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// An arbitrary identifier, mostly made of the characters which have a meaning in a url.
+    fn id(&mut self) -> String {
+        const CHARACTERS: &[&str] = &[
+            "a", "Z", "0", "-", "_", ".", "~", "/", "?", "#", "%", "&", "=", "+", " ", "\\", "\"",
+            "<", ">", "`", "{", "}", "@", ":", ";", "[", "]", "%2F", "%2e", "\n", "\t", "é",
+            "日本", "🚀",
+        ];
+        let length = self.next() % 12;
+        (0..length)
+            .map(|_| CHARACTERS[(self.next() % CHARACTERS.len() as u64) as usize])
+            .collect()
+    }
+}
+
+/// The ids to feed every endpoint with, the generated ones and a few chosen ones.
+fn ids() -> Vec<String> {
+    let mut generator = Generator(0x5eed_01d5);
+    let mut ids: Vec<String> = vec![
+        "my-app",
+        "user@example.com",
+        "~",
+        ".",
+        "..",
+        "a/b",
+        "?x=1",
+        "#top",
+        "%2F",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+    ids.extend((0..300).map(|_| generator.id()));
+    ids
+}
+
+/// Whether the id is a `.` or `..` segment, which no url can hold.
+fn is_dot_segment(id: &str) -> bool {
+    id == "." || id == ".."
+}
+
+/// The url of the endpoint stays on the API, and decodes back to the template filled with the id.
+fn check<R, Q, B>(endpoint: &dyn HerokuEndpoint<R, Q, B>, template: &str, id: &str)
+where
+    R: ApiResult,
+    Q: Serialize,
+    B: Serialize,
+{
+    let url = match endpoint.url(&ApiEnvironment::Production) {
+        Ok(url) => url,
+        Err(failure) => {
+            assert!(
+                is_dot_segment(id),
+                "{}: {:?} failed: {}",
+                template,
+                id,
+                failure
+            );
+            assert!(matches!(failure, HerokuApiFailure::Request(_)));
+            return;
+        }
+    };
+    assert!(!is_dot_segment(id), "{}: {:?} gave {}", template, id, url);
+
+    assert_eq!(url.host_str(), Some("api.heroku.com"), "{:?}", id);
+    assert_eq!(url.query(), None, "{:?}", id);
+    assert_eq!(url.fragment(), None, "{:?}", id);
+    let segments: Vec<String> = url
+        .path_segments()
+        .unwrap()
+        .map(|segment| {
+            percent_decode_str(segment)
+                .decode_utf8()
+                .unwrap()
+                .into_owned()
+        })
+        .collect();
+    let expected: Vec<&str> = template
+        .split('/')
+        .map(|segment| if segment == "{}" { id } else { segment })
+        .collect();
+    assert_eq!(segments, expected, "{}: {:?}", template, id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_segment_keeps_safe_identifiers() {
+        assert_eq!(encode_segment("my-app"), "my-app");
+        assert_eq!(encode_segment("user@example.com"), "user@example.com");
+        assert_eq!(encode_segment("~"), "~");
+        assert_eq!(encode_segment("a/b?c#d%"), "a%2Fb%3Fc%23d%25");
+    }
+
+    #[test]
+    fn every_endpoint_path_holds_arbitrary_ids() {
+        for id in ids() {
+            let id = id.as_str();
+            check(
+                &account::UserAccountDelete { account_id: id },
+                "users/{}",
+                id,
+            );
+            check(
+                &account::AppTransferDelete { transfer_id: id },
+                "account/app-transfers/{}",
+                id,
+            );
+            check(
+                &account::UserAccountDetails { account_id: id },
+                "users/{}",
+                id,
+            );
+            check(
+                &account::AccountFeatureDetails { feature_id: id },
+                "account/features/{}",
+                id,
+            );
+            check(
+                &account::AppTransferDetails { transfer_id: id },
+                "account/app-transfers/{}",
+                id,
+            );
+            check(
+                &account::AccountCreditDetails { credit_id: id },
+                "account/credits/{}",
+                id,
+            );
+            check(
+                &account::SmsNumberDetails { account_id: id },
+                "users/{}/sms-number",
+                id,
+            );
+            check(
+                &account::InvoiceDetails { invoice_id: id },
+                "account/invoices/{}",
+                id,
+            );
+            check(&account::KeyDetails { key_id: id }, "account/keys/{}", id);
+            check(
+                &account::SmsNumberRecover { account_id: id },
+                "users/{}/sms-number/actions/recover",
+                id,
+            );
+            check(
+                &account::SmsNumberConfirm { account_id: id },
+                "users/{}/sms-number/actions/confirm",
+                id,
+            );
+            check(&account::UserAccountUpdate::new(id), "users/{}", id);
+            check(
+                &account::AccountFeatureUpdate::new(id, true),
+                "account/features/{}",
+                id,
+            );
+            check(
+                &account::AppTransferUpdate::new(id, "x"),
+                "account/app-transfers/{}",
+                id,
+            );
+            check(
+                &account::PasswordResetConfirm::new(id),
+                "password-resets/{}/actions/finalize",
+                id,
+            );
+            check(
+                &addons::AddonDelete {
+                    app_id: id,
+                    addon_id: id,
+                },
+                "apps/{}/addons/{}",
+                id,
+            );
+            check(
+                &addons::AttachmentDelete { attachment_id: id },
+                "addon-attachments/{}",
+                id,
+            );
+            check(
+                &addons::WebhookDelete {
+                    addon_id: id,
+                    webhook_id: id,
+                },
+                "addons/{}/webhooks/{}",
+                id,
+            );
+            check(&addons::AddonDetails { addon_id: id }, "addons/{}", id);
+            check(
+                &addons::AddonDetailsByApp {
+                    app_id: id,
+                    addon_id: id,
+                },
+                "apps/{}/addons/{}",
+                id,
+            );
+            check(&addons::AddonListByApp { app_id: id }, "apps/{}/addons", id);
+            check(
+                &addons::AddonListByAccount { account_id: id },
+                "users/{}/addons",
+                id,
+            );
+            check(
+                &addons::AddonListByTeam { team_id: id },
+                "teams/{}/addons",
+                id,
+            );
+            check(
+                &addons::AttachmentDetails { attachment_id: id },
+                "addon-attachments/{}",
+                id,
+            );
+            check(
+                &addons::AttachmentListByAddon { addon_id: id },
+                "addons/{}/addon-attachments",
+                id,
+            );
+            check(
+                &addons::AttachmentListByApp { app_id: id },
+                "apps/{}/addon-attachments",
+                id,
+            );
+            check(
+                &addons::AttachmentDetailsByApp {
+                    app_id: id,
+                    attachment_id: id,
+                },
+                "apps/{}/addon-attachments/{}",
+                id,
+            );
+            check(
+                &addons::AddonConfigList { addon_id: id },
+                "addons/{}/config",
+                id,
+            );
+            check(
+                &addons::RegionCapabilityListByService { service_id: id },
+                "addon-services/{}/region-capabilities",
+                id,
+            );
+            check(
+                &addons::RegionCapabilityListByRegion { region_id: id },
+                "regions/{}/addon-region-capabilities",
+                id,
+            );
+            check(
+                &addons::AddonServiceDetails { service_id: id },
+                "addon-services/{}",
+                id,
+            );
+            check(
+                &addons::WebhookList { addon_id: id },
+                "addons/{}/webhooks",
+                id,
+            );
+            check(
+                &addons::WebhookDetails {
+                    addon_id: id,
+                    webhook_id: id,
+                },
+                "addons/{}/webhooks/{}",
+                id,
+            );
+            check(
+                &addons::WebhookDeliveryDetails {
+                    addon_id: id,
+                    delivery_id: id,
+                },
+                "addons/{}/webhook-deliveries/{}",
+                id,
+            );
+            check(
+                &addons::WebhookDeliveryList { addon_id: id },
+                "addons/{}/webhook-deliveries",
+                id,
+            );
+            check(
+                &addons::WebhookEventList { addon_id: id },
+                "addons/{}/webhook-events",
+                id,
+            );
+            check(
+                &addons::WebhookEventDetails {
+                    addon_id: id,
+                    event_id: id,
+                },
+                "addons/{}/webhook-events/{}",
+                id,
+            );
+            check(
+                &addons::AddonActionProvision { addon_id: id },
+                "addons/{}/actions/provision",
+                id,
+            );
+            check(
+                &addons::AddonActionDeprovision { addon_id: id },
+                "addons/{}/actions/deprovision",
+                id,
+            );
+            check(
+                &addons::AddonUpdate::new(id, id, "x"),
+                "apps/{}/addons/{}",
+                id,
+            );
+            check(&addons::AddonConfigUpdate::new(id), "addons/{}/config", id);
+            check(
+                &addons::WebhookUpdate::new(id, id),
+                "addons/{}/webhooks/{}",
+                id,
+            );
+            check(&addons::AddonCreate::new(id, "x"), "apps/{}/addons", id);
+            check(
+                &addons::WebhookCreate::new(id, vec![], "x", "x"),
+                "addons/{}/webhooks",
+                id,
+            );
+            check(&apps::AppDelete { app_id: id }, "apps/{}", id);
+            check(&apps::AppDisableAcm { app_id: id }, "apps/{}/acm", id);
+            check(
+                &apps::AppWebhookDelete {
+                    app_id: id,
+                    webhook_id: id,
+                },
+                "apps/{}/webhooks/{}",
+                id,
+            );
+            check(
+                &apps::SNIDelete {
+                    app_id: id,
+                    sni_id: id,
+                },
+                "apps/{}/sni-endpoints/{}",
+                id,
+            );
+            check(
+                &apps::SSLDelete {
+                    app_id: id,
+                    ssl_id: id,
+                },
+                "apps/{}/ssl-endpoints/{}",
+                id,
+            );
+            check(&apps::AppDetails { app_id: id }, "apps/{}", id);
+            check(
+                &apps::AccountAppList { account_id: id },
+                "users/{}/apps",
+                id,
+            );
+            check(
+                &apps::AppFeatureDetails {
+                    app_id: id,
+                    feature_id: id,
+                },
+                "apps/{}/features/{}",
+                id,
+            );
+            check(&apps::AppFeatureList { app_id: id }, "apps/{}/features", id);
+            check(&apps::AppWebhookList { app_id: id }, "apps/{}/webhooks", id);
+            check(
+                &apps::AppWebhookDetails {
+                    app_id: id,
+                    webhook_id: id,
+                },
+                "apps/{}/webhooks/{}",
+                id,
+            );
+            check(
+                &apps::AppWebhookDeliveryDetails {
+                    app_id: id,
+                    webhook_delivery_id: id,
+                },
+                "apps/{}/webhook-deliveries/{}",
+                id,
+            );
+            check(
+                &apps::AppWebhookDeliveryList { app_id: id },
+                "apps/{}/webhook-deliveries",
+                id,
+            );
+            check(&apps::AppSetupDetails { setup_id: id }, "app-setups/{}", id);
+            check(
+                &apps::SNIDetails {
+                    app_id: id,
+                    sni_id: id,
+                },
+                "apps/{}/sni-endpoints/{}",
+                id,
+            );
+            check(&apps::SNIList { app_id: id }, "apps/{}/sni-endpoints", id);
+            check(&apps::SSLList { app_id: id }, "apps/{}/ssl-endpoints", id);
+            check(
+                &apps::SSLDetails {
+                    app_id: id,
+                    ssl_id: id,
+                },
+                "apps/{}/ssl-endpoints/{}",
+                id,
+            );
+            check(
+                &apps::WebhookEventDetails {
+                    app_id: id,
+                    event_id: id,
+                },
+                "apps/{}/webhook-events/{}",
+                id,
+            );
+            check(
+                &apps::WebhookEventList { app_id: id },
+                "apps/{}/webhook-events",
+                id,
+            );
+            check(&apps::AppRefreshAcm { app_id: id }, "apps/{}/acm", id);
+            check(&apps::AppEnableAcm { app_id: id }, "apps/{}/acm", id);
+            check(&apps::AppUpdate::new(id), "apps/{}", id);
+            check(
+                &apps::AppFeatureUpdate::new(id, id, true),
+                "apps/{}/features/{}",
+                id,
+            );
+            check(
+                &apps::AppWebhookUpdate::new(id, id),
+                "apps/{}/webhooks/{}",
+                id,
+            );
+            check(
+                &apps::SNIUpdate::new(id, id, "x", "x"),
+                "apps/{}/sni-endpoints/{}",
+                id,
+            );
+            check(
+                &apps::SSLUpdate::new(id, id),
+                "apps/{}/ssl-endpoints/{}",
+                id,
+            );
+            check(
+                &apps::AppWebhookCreate::new(id, vec![], "x", "x"),
+                "apps/{}/webhooks",
+                id,
+            );
+            check(
+                &apps::SNICreate::new(id, "x", "x"),
+                "apps/{}/sni-endpoints",
+                id,
+            );
+            check(
+                &apps::SSLCreate::new(id, "x", "x"),
+                "apps/{}/ssl-endpoints",
+                id,
+            );
+            check(&builds::BuildList { app_id: id }, "apps/{}/builds", id);
+            check(
+                &builds::BuildDetails {
+                    app_id: id,
+                    build_id: id,
+                },
+                "apps/{}/builds/{}",
+                id,
+            );
+            check(
+                &builds::BuildPackInstallationList { app_id: id },
+                "apps/{}/buildpack-installations",
+                id,
+            );
+            check(&builds::BuildCreate::new(id, "x"), "apps/{}/builds", id);
+            check(
+                &builds::BuildpackInstallationUpdate::new(id, vec![]),
+                "apps/{}/buildpack-installations",
+                id,
+            );
+            check(
+                &collaborators::CollaboratorDelete {
+                    app_id: id,
+                    collaborator_id: id,
+                },
+                "apps/{}/collaborators/{}",
+                id,
+            );
+            check(
+                &collaborators::TeamCollaboratorDelete {
+                    app_id: id,
+                    collaborator_id: id,
+                },
+                "teams/apps/{}/collaborators/{}",
+                id,
+            );
+            check(
+                &collaborators::CollaboratorList { app_id: id },
+                "apps/{}/collaborators",
+                id,
+            );
+            check(
+                &collaborators::CollaboratorDetails {
+                    app_id: id,
+                    collaborator_id: id,
+                },
+                "apps/{}/collaborators/{}",
+                id,
+            );
+            check(
+                &collaborators::TeamCollaboratorList { app_id: id },
+                "teams/apps/{}/collaborators",
+                id,
+            );
+            check(
+                &collaborators::TeamCollaboratorDetails {
+                    app_id: id,
+                    collaborator_id: id,
+                },
+                "teams/apps/{}/collaborators/{}",
+                id,
+            );
+            check(
+                &collaborators::TeamCollaboratorUpdate::new(id, "x", vec![]),
+                "teams/apps/{}/collaborators",
+                id,
+            );
+            check(
+                &collaborators::CollaboratorCreate::new(id, "x"),
+                "apps/{}/collaborators",
+                id,
+            );
+            check(
+                &collaborators::TeamCollaboratorCreate::new(id, "x"),
+                "teams/apps/{}/collaborators",
+                id,
+            );
+            check(
+                &config_vars::AppConfigVarDetails { app_id: id },
+                "apps/{}/config-vars",
+                id,
+            );
+            check(
+                &config_vars::ReleaseConfigVarDetails {
+                    app_id: id,
+                    release_id: id,
+                },
+                "apps/{}/releases/{}/config-vars",
+                id,
+            );
+            check(
+                &config_vars::PipelineConfigVarDetails {
+                    pipeline_id: id,
+                    stage_id: id,
+                },
+                "pipelines/{}/stage/{}/config-vars",
+                id,
+            );
+            check(
+                &config_vars::AppConfigVarDelete::new(id, HashMap::new()),
+                "apps/{}/config-vars",
+                id,
+            );
+            check(
+                &config_vars::PipelineConfigVarDelete::new(id, id, HashMap::new()),
+                "pipelines/{}/stage/{}/config-vars",
+                id,
+            );
+            check(
+                &config_vars::PipelineConfigVarUpdate::new(id, id, HashMap::new()),
+                "pipelines/{}/stage/{}/config-vars",
+                id,
+            );
+            check(
+                &domains::DomainDelete {
+                    app_id: id,
+                    domain_id: id,
+                },
+                "apps/{}/domains/{}",
+                id,
+            );
+            check(
+                &domains::DomainDetails {
+                    app_id: id,
+                    domain_id: id,
+                },
+                "apps/{}/domains/{}",
+                id,
+            );
+            check(&domains::DomainList { app_id: id }, "apps/{}/domains", id);
+            check(&domains::DomainCreate::new(id, "x"), "apps/{}/domains", id);
+            check(
+                &dynos::DynoDetails {
+                    app_id: id,
+                    dyno_id: id,
+                },
+                "apps/{}/dynos/{}",
+                id,
+            );
+            check(&dynos::DynoList { app_id: id }, "apps/{}/dynos", id);
+            check(&dynos::DynoSizeDetails { size_id: id }, "dyno-sizes/{}", id);
+            check(&dynos::DynoCreate::new(id, "x"), "apps/{}/dynos", id);
+            check(
+                &formations::FormationDetails {
+                    app_id: id,
+                    formation_id: id,
+                },
+                "apps/{}/formation/{}",
+                id,
+            );
+            check(
+                &formations::FormationList { app_id: id },
+                "apps/{}/formation",
+                id,
+            );
+            check(
+                &formations::FormationUpdate::new(id, id),
+                "apps/{}/formation/{}",
+                id,
+            );
+            check(
+                &logs::LogDrainDelete {
+                    app_id: id,
+                    drain_id: id,
+                },
+                "apps/{}/log-drains/{}",
+                id,
+            );
+            check(&logs::LogDrainList { app_id: id }, "apps/{}/log-drains", id);
+            check(
+                &logs::LogDrainDetails {
+                    app_id: id,
+                    drain_id: id,
+                },
+                "apps/{}/log-drains/{}",
+                id,
+            );
+            check(
+                &logs::LogDrainListByAddon { addon_id: id },
+                "addons/{}/log-drains",
+                id,
+            );
+            check(
+                &logs::LogDrainCreate::new(id, "x"),
+                "apps/{}/log-drains",
+                id,
+            );
+            check(&logs::LogSessionCreate::new(id), "apps/{}/log-sessions", id);
+            check(
+                &logs::LogDrainUpdate::new(id, id, "x"),
+                "addons/{}/log-drains/{}",
+                id,
+            );
+            check(&misc::RegionDetails { region_id: id }, "regions/{}", id);
+            check(&misc::StackDetails { stack_id: id }, "stacks/{}", id);
+            check(
+                &oauth::OAuthDelete { oauth_id: id },
+                "oauth/authorizations/{}",
+                id,
+            );
+            check(
+                &oauth::OAuthClientDelete { client_id: id },
+                "oauth/clients/{}",
+                id,
+            );
+            check(
+                &oauth::OAuthTokenDelete { token_id: id },
+                "oauth/tokens/{}",
+                id,
+            );
+            check(
+                &oauth::OAuthDetails { oauth_id: id },
+                "oauth/authorizations/{}",
+                id,
+            );
+            check(
+                &oauth::OAuthClientDetails { client_id: id },
+                "oauth/clients/{}",
+                id,
+            );
+            check(
+                &oauth::OAuthRegenerate { oauth_id: id },
+                "oauth/authorizations/{}/actions/regenerate-tokens",
+                id,
+            );
+            check(
+                &oauth::OAuthClientRotateCredentials { client_id: id },
+                "oauth/clients/{}/actions/rotate-credentials",
+                id,
+            );
+            check(&oauth::OAuthClientUpdate::new(id), "oauth/clients/{}", id);
+            check(
+                &pipelines::PipelineDelete { pipeline_id: id },
+                "pipelines/{}",
+                id,
+            );
+            check(
+                &pipelines::PipelineCouplingDelete { coupling_id: id },
+                "pipeline-couplings/{}",
+                id,
+            );
+            check(
+                &pipelines::PipelineDetails { pipeline_id: id },
+                "pipelines/{}",
+                id,
+            );
+            check(
+                &pipelines::PipelineLatestBuildsList { pipeline_id: id },
+                "pipelines/{}/latest-builds",
+                id,
+            );
+            check(
+                &pipelines::PipelineCouplingByPipelineList { pipeline_id: id },
+                "pipelines/{}/pipeline-couplings",
+                id,
+            );
+            check(
+                &pipelines::PipelineCouplingByTeamList { team_id: id },
+                "teams/{}/pipeline-couplings",
+                id,
+            );
+            check(
+                &pipelines::PipelineCouplingByAppDetails { app_id: id },
+                "apps/{}/pipeline-couplings",
+                id,
+            );
+            check(
+                &pipelines::PipelineCouplingDetails { coupling_id: id },
+                "pipeline-couplings/{}",
+                id,
+            );
+            check(
+                &pipelines::PipelineDeploymentList { pipeline_id: id },
+                "pipelines/{}/latest-deployments",
+                id,
+            );
+            check(
+                &pipelines::PipelinePromotionDetails { promotion_id: id },
+                "pipeline-promotions/{}",
+                id,
+            );
+            check(
+                &pipelines::PipelinePromotionTargetList { promotion_id: id },
+                "pipeline-promotions/{}/promotion-targets",
+                id,
+            );
+            check(
+                &pipelines::PipelineLatestReleaseList { pipeline_id: id },
+                "pipelines/{}/latest-releases",
+                id,
+            );
+            check(
+                &pipelines::PipelineStackDetails { pipeline_id: id },
+                "pipelines/{}/pipeline-stack",
+                id,
+            );
+            check(&pipelines::PipelineUpdate::new(id), "pipelines/{}", id);
+            check(
+                &pipelines::PipelineCouplingUpdate::new(id),
+                "pipeline-couplings/{}",
+                id,
+            );
+            check(
+                &releases::ReleaseList { app_id: id },
+                "apps/{}/releases",
+                id,
+            );
+            check(
+                &releases::ReleaseInfo {
+                    app_id: id,
+                    release_id: id,
+                },
+                "apps/{}/releases/{}",
+                id,
+            );
+            check(
+                &releases::ReleaseCreate::new(id, "x"),
+                "apps/{}/releases",
+                id,
+            );
+            check(
+                &releases::ReleaseRollback::new(id, "x"),
+                "apps/{}/releases",
+                id,
+            );
+            check(
+                &review::ReviewAppDelete { review_id: id },
+                "review-apps/{}",
+                id,
+            );
+            check(
+                &review::ReviewAppConfigDelete { pipeline_id: id },
+                "pipelines/{}/review-app-config",
+                id,
+            );
+            check(
+                &review::ReviewAppDetails { review_id: id },
+                "review-apps/{}",
+                id,
+            );
+            check(
+                &review::ReviewAppByAppDetails { app_id: id },
+                "apps/{}/review-app",
+                id,
+            );
+            check(
+                &review::ReviewAppByPipelineList { pipeline_id: id },
+                "pipelines/{}/review-apps",
+                id,
+            );
+            check(
+                &review::ReviewAppConfigDetails { pipeline_id: id },
+                "pipelines/{}/review-app-config",
+                id,
+            );
+            check(
+                &review::ReviewAppConfigUpdate::new(id),
+                "pipelines/{}/review-app-config",
+                id,
+            );
+            check(
+                &review::ReviewAppConfigEnable::new(id, "x"),
+                "pipelines/{}/review-app-config",
+                id,
+            );
+            check(
+                &slugs::SlugDetails {
+                    app_id: id,
+                    slug_id: id,
+                },
+                "apps/{}/slugs/{}",
+                id,
+            );
+            check(
+                &slugs::SlugCreate::new(id, HashMap::new()),
+                "apps/{}/slugs",
+                id,
+            );
+            check(&space::SpaceDelete { space_id: id }, "spaces/{}", id);
+            check(
+                &space::VPNDelete {
+                    space_id: id,
+                    vpn_id: id,
+                },
+                "spaces/{}/vpn-connections/{}",
+                id,
+            );
+            check(&space::SpaceDetails { space_id: id }, "spaces/{}", id);
+            check(
+                &space::SpaceAccessDetails {
+                    space_id: id,
+                    account_id: id,
+                },
+                "spaces/{}/members/{}",
+                id,
+            );
+            check(
+                &space::SpaceAccessList { space_id: id },
+                "spaces/{}/members",
+                id,
+            );
+            check(
+                &space::SpaceNATDetails { space_id: id },
+                "spaces/{}/nat",
+                id,
+            );
+            check(
+                &space::InboundRulesetCurrent { space_id: id },
+                "spaces/{}/inbound-ruleset",
+                id,
+            );
+            check(
+                &space::InboundRulesetDetails {
+                    space_id: id,
+                    ruleset_id: id,
+                },
+                "spaces/{}/inbound-rulesets/{}",
+                id,
+            );
+            check(
+                &space::InboundRulesetList { space_id: id },
+                "spaces/{}/inbound-rulesets",
+                id,
+            );
+            check(
+                &space::OutboundRulesetCurrent { space_id: id },
+                "spaces/{}/outbound-ruleset",
+                id,
+            );
+            check(
+                &space::OutboundRulesetDetails {
+                    space_id: id,
+                    ruleset_id: id,
+                },
+                "spaces/{}/outbound-rulesets/{}",
+                id,
+            );
+            check(
+                &space::OutboundRulesetList { space_id: id },
+                "spaces/{}/outbound-rulesets",
+                id,
+            );
+            check(
+                &space::VPNList { space_id: id },
+                "spaces/{}/vpn-connections",
+                id,
+            );
+            check(
+                &space::VPNDetails {
+                    space_id: id,
+                    vpn_id: id,
+                },
+                "spaces/{}/vpn-connections/{}",
+                id,
+            );
+            check(&space::SpaceUpdate::new(id), "spaces/{}", id);
+            check(
+                &space::SpaceAccessUpdate::new(id, id, "x"),
+                "spaces/{}/members/{}",
+                id,
+            );
+            check(
+                &space::SpaceTransferCreate::new(id, "x"),
+                "spaces/{}/transfer",
+                id,
+            );
+            check(
+                &space::VPNCreate::new(id, "x", "x", vec![]),
+                "spaces/{}/vpn-connections",
+                id,
+            );
+            check(
+                &space::InboundRulesetCreate::new(id),
+                "spaces/{}/inbound-ruleset",
+                id,
+            );
+            check(
+                &space::OutboundRulesetCreate::new(id),
+                "spaces/{}/outbound-ruleset",
+                id,
+            );
+            check(&teams::TeamDelete { team_id: id }, "teams/{}", id);
+            check(
+                &teams::TeamInvitationRevoke {
+                    team_id: id,
+                    invitation_id: id,
+                },
+                "teams/{}/invitations/{}",
+                id,
+            );
+            check(
+                &teams::TeamMemberDelete {
+                    team_id: id,
+                    member_id: id,
+                },
+                "teams/{}/members/{}",
+                id,
+            );
+            check(&teams::TeamDetails { team_id: id }, "teams/{}", id);
+            check(
+                &teams::TeamListByEA { account_id: id },
+                "enterprise-accounts/{}/teams",
+                id,
+            );
+            check(&teams::TeamAppDetails { app_id: id }, "teams/apps/{}", id);
+            check(&teams::TeamAppList { team_id: id }, "teams/apps/{}", id);
+            check(
+                &teams::TeamFeatureList { team_id: id },
+                "teams/{}/features",
+                id,
+            );
+            check(
+                &teams::TeamFeatureDetails {
+                    team_id: id,
+                    feature_id: id,
+                },
+                "teams/{}/features/{}",
+                id,
+            );
+            check(
+                &teams::TeamInvitationList { team_id: id },
+                "teams/{}/invitations",
+                id,
+            );
+            check(
+                &teams::TeamInvitationDetails { token_id: id },
+                "teams/invitations/{}",
+                id,
+            );
+            check(
+                &teams::TeamInvoiceList { team_id: id },
+                "teams/{}/invoices",
+                id,
+            );
+            check(
+                &teams::TeamInvoiceDetails {
+                    team_id: id,
+                    invoice_id: id,
+                },
+                "teams/{}/invoices/{}",
+                id,
+            );
+            check(
+                &teams::TeamMemberList { team_id: id },
+                "teams/{}/members",
+                id,
+            );
+            check(
+                &teams::TeamMemberAppsList {
+                    team_id: id,
+                    member_id: id,
+                },
+                "teams/{}/members/{}/apps",
+                id,
+            );
+            check(
+                &teams::TeamPreferenceList { id },
+                "teams/{}/preferences",
+                id,
+            );
+            check(
+                &teams::TeamInvitationAccept { token_id: id },
+                "teams/invitations/{}/accept",
+                id,
+            );
+            check(&teams::TeamUpdate::new(id), "teams/{}", id);
+            check(
+                &teams::TeamAppUpdateLocked::new(id, true),
+                "teams/apps/{}",
+                id,
+            );
+            check(&teams::TeamAppTransfer::new(id, "x"), "teams/apps/{}", id);
+            check(
+                &teams::TeamMemberUpdate::new(id, "x", "x"),
+                "teams/{}/members",
+                id,
+            );
+            check(
+                &teams::TeamPreferenceUpdate::new(id),
+                "teams/{}/preferences",
+                id,
+            );
+            check(
+                &teams::TeamCreateByEA::new(id, "x"),
+                "enterprise-accounts/{}/teams",
+                id,
+            );
+            check(
+                &teams::TeamMemberCreate::new(id, "x", "x"),
+                "teams/{}/members",
+                id,
+            );
+            check(
+                &teams::TeamInvitationCreate::new(id, "x"),
+                "teams/{}/invitations",
+                id,
+            );
+            check(
+                &teams::TeamMemberCreateorUpdate::new(id, "x", "x"),
+                "teams/{}/members",
+                id,
+            );
+            check(
+                &testing::TestCaseList { run_id: id },
+                "test-runs/{}/test-cases",
+                id,
+            );
+            check(
+                &testing::TestNodeList { run_id: id },
+                "test-runs/{}/test-nodes",
+                id,
+            );
+            check(&testing::TestRunDetails { run_id: id }, "test-runs/{}", id);
+            check(
+                &testing::TestRunList { pipeline_id: id },
+                "pipelines/{}/test-runs",
+                id,
+            );
+            check(
+                &testing::TestRunDetailsByPipeline {
+                    pipeline_id: id,
+                    run_id: id,
+                },
+                "pipelines/{}/test-runs/{}",
+                id,
+            );
+            check(
+                &testing::TestRunUpdate::new(id, None, "x"),
+                "test-runs/{}",
+                id,
+            );
+        }
+    }
+
+    #[test]
+    fn config_var_keys_and_values_round_trip() {
+        let mut generator = Generator(0x00c0_ffee);
+        for _ in 0..100 {
+            let mut params = HashMap::new();
+            for _ in 0..(generator.next() % 5) {
+                params.insert(generator.id(), generator.id());
+            }
+
+            let request = build_request(
+                &config_vars::AppConfigVarUpdate {
+                    app_id: "my-app",
+                    params: params.clone(),
+                },
+                &ApiEnvironment::Production,
+                &Credentials::UserAuthToken {
+                    token: String::from("TOKEN_HERE"),
+                },
+            )
+            .unwrap();
+
+            assert_eq!(
+                request.uri(),
+                "https://api.heroku.com/apps/my-app/config-vars"
+            );
+            let sent: HashMap<String, String> = serde_json::from_slice(request.body()).unwrap();
+            assert_eq!(sent, params);
+        }
+    }
+
+    #[test]
+    fn request_returns_invalid_urls_as_failures() {
+        let api_client = HttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: String::from("TOKEN_HERE"),
+            },
+            HttpApiClientConfig::default(),
+            ApiEnvironment::Production,
+        )
+        .unwrap();
+
+        for app_id in &[".", ".."] {
+            let failure = api_client
+                .request(&apps::AppDetails { app_id })
+                .unwrap_err();
+            assert!(
+                matches!(failure, HerokuApiFailure::Request(_)),
+                "{}",
+                app_id
+            );
+            assert_eq!(failure.status(), None);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use heroku_rs::endpoints::misc;
+use heroku_rs::framework::{ratelimit::RateLimiter, HttpApiClient, HttpApiClientConfig};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use stub::{stub_client_with, stub_config, StubResponse, StubServer};
+mod stub;
+
+fn get_limited_client(server: &StubServer, rate_limiter: Arc<RateLimiter>) -> HttpApiClient {
+    stub_client_with(
+        server,
+        HttpApiClientConfig {
+            rate_limiter: Some(rate_limiter),
+            ..stub_config()
+        },
+    )
+}
+
+fn ratelimit_response(remaining: u32) -> StubResponse {
+    StubResponse::new(200, &format!(r#"{{"remaining": {}}}"#, remaining))
+        .header("RateLimit-Remaining", &remaining.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+
+    #[test]
+    fn bucket_empties_and_refills() {
+        let rate_limiter = RateLimiter::new(2, Duration::from_millis(200));
+        assert!(rate_limiter.try_acquire());
+        assert!(rate_limiter.try_acquire());
+        assert!(!rate_limiter.try_acquire());
+
+        thread::sleep(Duration::from_millis(120));
+        assert!(rate_limiter.try_acquire());
+    }
+
+    #[test]
+    fn acquire_waits_for_a_token() {
+        let rate_limiter = RateLimiter::new(1, Duration::from_millis(200));
+        rate_limiter.acquire();
+
+        let started = Instant::now();
+        rate_limiter.acquire();
+        assert!(started.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn set_remaining_is_capped_by_capacity() {
+        let rate_limiter = RateLimiter::new(10, Duration::from_secs(3600));
+        rate_limiter.set_remaining(3);
+        assert_eq!(rate_limiter.remaining(), 3);
+        rate_limiter.set_remaining(100);
+        assert_eq!(rate_limiter.remaining(), 10);
+    }
+
+    #[test]
+    fn client_syncs_bucket_from_response_header() {
+        let server = StubServer::start(vec![ratelimit_response(1234)]);
+        let rate_limiter = Arc::new(RateLimiter::default());
+
+        get_limited_client(&server, rate_limiter.clone())
+            .request(&misc::RatelimitDetails::new())
+            .unwrap();
+        assert_eq!(rate_limiter.remaining(), 1234);
+    }
+
+    #[test]
+    fn client_waits_when_heroku_reports_no_budget() {
+        let server = StubServer::start(vec![ratelimit_response(0), ratelimit_response(0)]);
+        // 10 requests per second, so an empty bucket has a token again after 100ms.
+        let rate_limiter = Arc::new(RateLimiter::new(10, Duration::from_secs(1)));
+        let api_client = get_limited_client(&server, rate_limiter);
+
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        let started = Instant::now();
+        api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[test]
+    fn client_is_throttled_across_threads() {
+        let server = StubServer::start(vec![
+            StubResponse::new(200, r#"{"remaining": 1}"#),
+            StubResponse::new(200, r#"{"remaining": 1}"#),
+            StubResponse::new(200, r#"{"remaining": 1}"#),
+            StubResponse::new(200, r#"{"remaining": 1}"#),
+        ]);
+        // 2 requests right away, then one every 100ms.
+        let rate_limiter = Arc::new(RateLimiter::new(2, Duration::from_millis(200)));
+        let api_client = Arc::new(get_limited_client(&server, rate_limiter));
+
+        let started = Instant::now();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let api_client = api_client.clone();
+                thread::spawn(move || api_client.request(&misc::RatelimitDetails::new()).is_ok())
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+
+        assert!(started.elapsed() >= Duration::from_millis(180));
+        assert_eq!(server.requests().len(), 4);
+    }
+}
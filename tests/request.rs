@@ -0,0 +1,161 @@
+use heroku_rs::endpoints::{apps, config_vars, misc};
+use heroku_rs::framework::{
+    auth::Credentials,
+    endpoint::{ExtraHeaders, HerokuEndpoint, Method},
+    range::{ListEndpoint, Range},
+    request::build_request,
+    response::{parse_response, parse_response_with_meta, HerokuApiFailure},
+    ApiEnvironment,
+};
+use http::header::{HeaderName, HeaderValue};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// An endpoint with a query, which no endpoint of the crate has.
+struct SearchApps {
+    query: SearchQuery,
+}
+
+#[derive(Clone, Serialize)]
+struct SearchQuery {
+    name: String,
+    limit: Option<u32>,
+}
+
+impl HerokuEndpoint<Vec<apps::App>, SearchQuery> for SearchApps {
+    fn method(&self) -> Method {
+        Method::Get
+    }
+    fn path(&self) -> String {
+        String::from("apps")
+    }
+    fn query(&self) -> Option<SearchQuery> {
+        Some(self.query.clone())
+    }
+}
+
+fn token() -> Credentials {
+    Credentials::UserAuthToken {
+        token: String::from("TOKEN_HERE"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_request_sets_the_url_headers_and_credentials() {
+        let endpoint = apps::AppDetails { app_id: "my-app" }.with_header(
+            HeaderName::from_static("heroku-two-factor-code"),
+            HeaderValue::from_static("123456"),
+        );
+        let request = build_request(&endpoint, &ApiEnvironment::Production, &token()).unwrap();
+
+        assert_eq!(request.method(), http::Method::GET);
+        assert_eq!(request.uri(), "https://api.heroku.com/apps/my-app");
+        let headers = request.headers();
+        assert_eq!(headers["accept"], "application/vnd.heroku+json; version=3");
+        assert_eq!(headers["user-agent"], "heroku_rs");
+        assert_eq!(headers["authorization"], "Bearer TOKEN_HERE");
+        assert_eq!(headers["heroku-two-factor-code"], "123456");
+        assert!(headers.get("content-type").is_none());
+        assert!(request.body().is_empty());
+    }
+
+    #[test]
+    fn build_request_serializes_the_body_query_and_range() {
+        let mut params = HashMap::new();
+        params.insert(String::from("PORT"), String::from("5000"));
+        let environment =
+            ApiEnvironment::Custom(url::Url::parse("http://127.0.0.1:5000/").unwrap());
+        let credentials = Credentials::Basic {
+            email: String::from("user@example.com"),
+            api_key: String::from("KEY"),
+        };
+
+        let request = build_request(
+            &config_vars::AppConfigVarUpdate {
+                app_id: "my-app",
+                params,
+            },
+            &environment,
+            &credentials,
+        )
+        .unwrap();
+        assert_eq!(request.method(), http::Method::PATCH);
+        assert_eq!(
+            request.uri(),
+            "http://127.0.0.1:5000/apps/my-app/config-vars"
+        );
+        assert_eq!(request.headers()["content-type"], "application/json");
+        assert_eq!(
+            request.headers()["authorization"],
+            "Basic dXNlckBleGFtcGxlLmNvbTpLRVk="
+        );
+        assert_eq!(request.body().as_slice(), br#"{"PORT":"5000"}"#);
+
+        let search = SearchApps {
+            query: SearchQuery {
+                name: String::from("my app"),
+                limit: None,
+            },
+        }
+        .with_range(Range::new("name").max(10).build());
+        let request = build_request(&search, &environment, &credentials).unwrap();
+        assert_eq!(request.uri(), "http://127.0.0.1:5000/apps?name=my+app");
+        assert_eq!(request.headers()["range"], "name ..; max=10");
+    }
+
+    #[test]
+    fn build_request_rejects_invalid_credentials() {
+        let credentials = Credentials::UserAuthToken {
+            token: String::from("TOKEN\nHERE"),
+        };
+        let failure = build_request(
+            &misc::RatelimitDetails::new(),
+            &ApiEnvironment::Production,
+            &credentials,
+        )
+        .unwrap_err();
+
+        assert!(matches!(failure, HerokuApiFailure::Request(_)));
+        assert_eq!(failure.status(), None);
+    }
+
+    #[test]
+    fn parse_response_decodes_results_and_errors() {
+        let response = http::Response::builder()
+            .status(200)
+            .header("RateLimit-Remaining", "2400")
+            .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")
+            .body(br#"{"remaining": 2400}"#.to_vec())
+            .unwrap();
+        let ratelimit = parse_response_with_meta::<misc::Ratelimit>(response).unwrap();
+        assert_eq!(ratelimit.result.remaining, 2400);
+        assert_eq!(ratelimit.meta.ratelimit_remaining, Some(2400));
+        assert_eq!(
+            ratelimit.meta.request_id.as_deref(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+
+        let response = http::Response::builder()
+            .status(422)
+            .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")
+            .body(br#"{"id": "invalid_params", "message": "Name is already taken"}"#.to_vec())
+            .unwrap();
+        let failure = parse_response::<apps::App>(response).unwrap_err();
+        assert!(failure.is_invalid_params());
+        assert_eq!(
+            failure.request_id(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+
+        let response = http::Response::builder()
+            .status(200)
+            .body(br#"{"remaining": "many"}"#.to_vec())
+            .unwrap();
+        let failure = parse_response::<misc::Ratelimit>(response).unwrap_err();
+        assert!(matches!(failure, HerokuApiFailure::Decode { .. }));
+    }
+}
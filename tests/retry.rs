@@ -0,0 +1,180 @@
+use heroku_rs::endpoints::{apps, misc};
+use heroku_rs::framework::{
+    endpoint::Method, response::HerokuApiFailure, retry::RetryPolicy, HttpApiClient,
+    HttpApiClientConfig,
+};
+use std::time::{Duration, Instant};
+use stub::{stub_client_with, stub_config, StubResponse, StubServer};
+mod stub;
+
+const RATE_LIMIT: &str =
+    r#"{"id": "rate_limit", "message": "Your account reached the API rate limit"}"#;
+const UNAVAILABLE: &str = r#"{"id": "unavailable", "message": "Service temporarily unavailable"}"#;
+
+fn get_retry_client(server: &StubServer, retry_policy: RetryPolicy) -> HttpApiClient {
+    stub_client_with(
+        server,
+        HttpApiClientConfig {
+            retry_policy,
+            ..stub_config()
+        },
+    )
+}
+
+fn fast_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(1),
+        jitter: false,
+        ..RetryPolicy::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heroku_rs::framework::apiclient::HerokuApiClient;
+
+    #[test]
+    fn retries_until_success() {
+        let server = StubServer::start(vec![
+            StubResponse::new(429, RATE_LIMIT),
+            StubResponse::new(503, UNAVAILABLE),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let ratelimit = get_retry_client(&server, fast_policy(3))
+            .request(&misc::RatelimitDetails::new())
+            .unwrap();
+        assert_eq!(ratelimit.remaining, 4500);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let server = StubServer::start(vec![
+            StubResponse::new(503, UNAVAILABLE),
+            StubResponse::new(503, UNAVAILABLE),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let response =
+            get_retry_client(&server, fast_policy(2)).request(&misc::RatelimitDetails::new());
+        match response {
+            Err(HerokuApiFailure::Error(status, error)) => {
+                assert_eq!(status, 503);
+                assert_eq!(error.id, "unavailable");
+            }
+            other => panic!("Expected an unavailable error, got {:?}", other),
+        }
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn does_not_retry_other_statuses() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                404,
+                r#"{"id": "not_found", "message": "Couldn't find that app."}"#,
+            ),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let response =
+            get_retry_client(&server, fast_policy(3)).request(&misc::RatelimitDetails::new());
+        assert!(response.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn does_not_retry_non_idempotent_methods_by_default() {
+        let server = StubServer::start(vec![StubResponse::new(503, UNAVAILABLE)]);
+
+        let response =
+            get_retry_client(&server, fast_policy(3)).request(&apps::AppCreate::new().build());
+        assert!(response.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn retries_configured_methods_with_body() {
+        let server = StubServer::start(vec![
+            StubResponse::new(429, RATE_LIMIT),
+            StubResponse::new(200, "{}"),
+        ]);
+        let policy = RetryPolicy {
+            retry_methods: vec![Method::Post],
+            ..fast_policy(2)
+        };
+
+        let response = get_retry_client(&server, policy)
+            .request_raw(&apps::AppCreate::new().name("foo").build());
+        assert_eq!(response.unwrap().status(), 200);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].body, requests[1].body);
+        assert!(requests[1].body.contains("foo"));
+    }
+
+    #[test]
+    fn none_policy_does_not_retry() {
+        let server = StubServer::start(vec![
+            StubResponse::new(429, RATE_LIMIT),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let response =
+            get_retry_client(&server, RetryPolicy::none()).request(&misc::RatelimitDetails::new());
+        assert!(response.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn honours_retry_after() {
+        let server = StubServer::start(vec![
+            StubResponse::new(429, RATE_LIMIT).header("Retry-After", "1"),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let started = Instant::now();
+        let response =
+            get_retry_client(&server, fast_policy(2)).request(&misc::RatelimitDetails::new());
+        assert!(response.is_ok());
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn fails_on_retry_after_longer_than_max_backoff() {
+        let server = StubServer::start(vec![
+            StubResponse::new(429, RATE_LIMIT).header("Retry-After", "3600"),
+            StubResponse::new(200, r#"{"remaining": 4500}"#),
+        ]);
+
+        let started = Instant::now();
+        let failure = get_retry_client(&server, fast_policy(3))
+            .request(&misc::RatelimitDetails::new())
+            .unwrap_err();
+        assert!(failure.is_rate_limited());
+        assert_eq!(server.requests().len(), 1);
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn retries_pages_of_request_all() {
+        let server = StubServer::start(vec![
+            StubResponse::new(206, "[]").header("Next-Range", "]id..; max=1"),
+            StubResponse::new(503, UNAVAILABLE),
+            StubResponse::new(200, "[]"),
+        ]);
+
+        let stacks = get_retry_client(&server, fast_policy(2))
+            .request_all(&misc::StackList::new())
+            .unwrap();
+        assert!(stacks.is_empty());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].header("range"), Some("]id..; max=1"));
+    }
+}
@@ -0,0 +1,208 @@
+//! A tiny scripted HTTP server used to test the clients without reaching Heroku.
+#![allow(dead_code)]
+
+#[cfg(feature = "async")]
+use heroku_rs::framework::AsyncHttpApiClient;
+use heroku_rs::framework::{
+    auth::Credentials, retry::RetryPolicy, ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A canned response the stub server replies with.
+#[derive(Clone, Debug)]
+pub struct StubResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl StubResponse {
+    pub fn new(status: u16, body: &str) -> StubResponse {
+        StubResponse {
+            status,
+            headers: Vec::new(),
+            body: String::from(body),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> StubResponse {
+        self.headers.push((String::from(name), String::from(value)));
+        self
+    }
+}
+
+/// A request received by the stub server.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Serves the scripted responses in order, one per request, and records every request it gets.
+///
+/// Once the script is exhausted, every further request gets a `500`.
+pub struct StubServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl StubServer {
+    pub fn start(responses: Vec<StubResponse>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                let request = match read_request(&mut stream) {
+                    Some(request) => request,
+                    None => continue,
+                };
+                recorded.lock().unwrap().push(request);
+                let response = responses.next().unwrap_or_else(|| {
+                    StubResponse::new(500, r#"{"id":"stub","message":"no more responses"}"#)
+                });
+                write_response(&mut stream, &response);
+            }
+        });
+
+        StubServer { port, requests }
+    }
+
+    /// The base url to pass to `ApiEnvironment::Custom`.
+    pub fn url(&self) -> url::Url {
+        url::Url::parse(&format!("http://127.0.0.1:{}/", self.port)).unwrap()
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// The configuration of the stub clients, which do not retry so every scripted response is seen once.
+pub fn stub_config() -> HttpApiClientConfig {
+    HttpApiClientConfig {
+        retry_policy: RetryPolicy::none(),
+        ..HttpApiClientConfig::default()
+    }
+}
+
+/// A client of the stub server, sending `Bearer TOKEN_HERE`.
+pub fn stub_client(server: &StubServer) -> HttpApiClient {
+    stub_client_with(server, stub_config())
+}
+
+/// A client of the stub server with this configuration, sending `Bearer TOKEN_HERE`.
+pub fn stub_client_with(server: &StubServer, config: HttpApiClientConfig) -> HttpApiClient {
+    HttpApiClient::new(
+        stub_credentials(),
+        config,
+        ApiEnvironment::Custom(server.url()),
+    )
+    .unwrap()
+}
+
+/// An async client of the stub server, sending `Bearer TOKEN_HERE`.
+#[cfg(feature = "async")]
+pub fn async_stub_client(server: &StubServer) -> AsyncHttpApiClient {
+    async_stub_client_with(server, stub_config())
+}
+
+/// An async client of the stub server with this configuration, sending `Bearer TOKEN_HERE`.
+#[cfg(feature = "async")]
+pub fn async_stub_client_with(
+    server: &StubServer,
+    config: HttpApiClientConfig,
+) -> AsyncHttpApiClient {
+    AsyncHttpApiClient::new(
+        stub_credentials(),
+        config,
+        ApiEnvironment::Custom(server.url()),
+    )
+    .unwrap()
+}
+
+fn stub_credentials() -> Credentials {
+    Credentials::UserAuthToken {
+        token: String::from("TOKEN_HERE"),
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<RecordedRequest> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &StubResponse) {
+    let mut head = format!(
+        "HTTP/1.1 {} STUB\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    if !response
+        .headers
+        .iter()
+        .any(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+    {
+        head.push_str("Content-Type: application/json\r\n");
+    }
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(response.body.as_bytes());
+    let _ = stream.flush();
+}
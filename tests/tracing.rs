@@ -0,0 +1,277 @@
+#![cfg(feature = "tracing")]
+use heroku_rs::endpoints::{config_vars, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient, auth::Credentials, retry::RetryPolicy, ApiEnvironment,
+    HttpApiClient, HttpApiClientConfig,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use stub::{StubResponse, StubServer};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+mod stub;
+
+/// The fields recorded on a span or an event.
+type Fields = HashMap<String, String>;
+
+struct FieldsVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+/// Keeps every span and event in memory.
+#[derive(Clone, Default)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<HashMap<u64, (&'static Metadata<'static>, Fields)>>>,
+    events: Arc<Mutex<Vec<Fields>>>,
+    stack: Arc<Mutex<Vec<Id>>>,
+}
+
+impl Recorder {
+    /// The fields of the spans named `name`, in creation order.
+    fn spans(&self, name: &str) -> Vec<Fields> {
+        let spans = self.spans.lock().unwrap();
+        let mut ids: Vec<&u64> = spans
+            .iter()
+            .filter(|(_, (metadata, _))| metadata.name() == name)
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort();
+        ids.into_iter().map(|id| spans[id].1.clone()).collect()
+    }
+
+    /// Every value recorded on a span or an event.
+    fn values(&self) -> Vec<String> {
+        let spans = self.spans.lock().unwrap();
+        let events = self.events.lock().unwrap();
+        spans
+            .values()
+            .map(|(_, fields)| fields)
+            .chain(events.iter())
+            .flat_map(|fields| fields.values().cloned())
+            .collect()
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut fields = Fields::new();
+        span.record(&mut FieldsVisitor(&mut fields));
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, (span.metadata(), fields));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            values.record(&mut FieldsVisitor(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::new();
+        event.record(&mut FieldsVisitor(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.stack.lock().unwrap().push(span.clone());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> Current {
+        match self.stack.lock().unwrap().last() {
+            Some(id) => {
+                let metadata = self.spans.lock().unwrap()[&id.into_u64()].0;
+                Current::new(id.clone(), metadata)
+            }
+            None => Current::none(),
+        }
+    }
+}
+
+fn get_stub_client(server: &StubServer, retry_policy: RetryPolicy) -> HttpApiClient {
+    let credentials = Credentials::UserAuthToken {
+        token: String::from("SECRET_TOKEN"),
+    };
+    HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig {
+            retry_policy,
+            ..HttpApiClientConfig::default()
+        },
+        ApiEnvironment::Custom(server.url()),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_span_records_the_call_without_secrets() {
+        let server = StubServer::start(vec![StubResponse::new(
+            200,
+            r#"{"DATABASE_URL": "postgres://SECRET_VALUE"}"#,
+        )
+        .header("Request-Id", "01234567-89ab-cdef-0123-456789abcdef")]);
+        let api_client = get_stub_client(&server, RetryPolicy::none());
+        let recorder = Recorder::default();
+
+        let mut params = HashMap::new();
+        params.insert(
+            String::from("DATABASE_URL"),
+            String::from("postgres://SECRET_VALUE"),
+        );
+        tracing::subscriber::with_default(recorder.clone(), || {
+            api_client
+                .request(&config_vars::AppConfigVarUpdate {
+                    app_id: "example",
+                    params,
+                })
+                .unwrap();
+        });
+
+        let spans = recorder.spans("heroku_request");
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert_eq!(span["method"], "PATCH");
+        assert_eq!(span["path"], "/apps/{}/config-vars");
+        assert_eq!(span["app_id"], "example");
+        assert_eq!(span["status"], "200");
+        assert_eq!(span["request_id"], "01234567-89ab-cdef-0123-456789abcdef");
+        assert_eq!(span["attempts"], "1");
+        assert!(span.contains_key("duration_ms"));
+        assert!(!span.contains_key("error"));
+
+        assert!(recorder
+            .values()
+            .iter()
+            .all(|value| !value.contains("SECRET")));
+    }
+
+    #[test]
+    fn request_span_records_retries() {
+        let server = StubServer::start(vec![
+            StubResponse::new(
+                503,
+                r#"{"id": "unavailable", "message": "Service temporarily unavailable"}"#,
+            ),
+            StubResponse::new(200, r#"{"remaining": 2400}"#),
+        ]);
+        let api_client = get_stub_client(
+            &server,
+            RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                jitter: false,
+                ..RetryPolicy::default()
+            },
+        );
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        });
+
+        let spans = recorder.spans("heroku_request");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0]["path"], "/account/rate-limits");
+        assert!(!spans[0].contains_key("app_id"));
+        assert_eq!(spans[0]["status"], "200");
+        assert_eq!(spans[0]["attempts"], "2");
+
+        let events = recorder.events.lock().unwrap();
+        let retry = events
+            .iter()
+            .find(|event| event.get("message").map(String::as_str) == Some("retrying the request"))
+            .expect("a retry event");
+        assert_eq!(retry["status"], "503");
+    }
+
+    #[test]
+    fn request_span_records_transport_errors() {
+        let server = StubServer::start(vec![]);
+        let mut url = server.url();
+        url.set_port(Some(1)).unwrap();
+        let api_client = HttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: String::from("SECRET_TOKEN"),
+            },
+            HttpApiClientConfig {
+                retry_policy: RetryPolicy::none(),
+                ..HttpApiClientConfig::default()
+            },
+            ApiEnvironment::Custom(url),
+        )
+        .unwrap();
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            assert!(api_client.request(&misc::RatelimitDetails::new()).is_err());
+        });
+
+        let spans = recorder.spans("heroku_request");
+        assert!(spans[0].contains_key("error"));
+        assert!(!spans[0].contains_key("status"));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_request_span_records_the_call() {
+        use heroku_rs::framework::{apiclient::AsyncHerokuApiClient, AsyncHttpApiClient};
+
+        let server = StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)]);
+        let api_client = AsyncHttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: String::from("SECRET_TOKEN"),
+            },
+            HttpApiClientConfig::default(),
+            ApiEnvironment::Custom(server.url()),
+        )
+        .unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            runtime
+                .block_on(api_client.request(&misc::RatelimitDetails::new()))
+                .unwrap();
+        });
+
+        let spans = recorder.spans("heroku_request");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0]["path"], "/account/rate-limits");
+        assert_eq!(spans[0]["status"], "200");
+        assert_eq!(spans[0]["attempts"], "1");
+    }
+}
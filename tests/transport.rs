@@ -0,0 +1,209 @@
+use heroku_rs::endpoints::{config_vars, misc};
+use heroku_rs::framework::{
+    apiclient::HerokuApiClient,
+    auth::Credentials,
+    response::HerokuApiFailure,
+    retry::RetryPolicy,
+    transport::{ReqwestTransport, Transport},
+    ApiEnvironment, HttpApiClient, HttpApiClientConfig,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use stub::{StubResponse, StubServer};
+mod stub;
+
+/// Answers with the scripted responses in order, and keeps every request it is given.
+struct InMemoryTransport {
+    responses: Mutex<Vec<(u16, &'static str)>>,
+    requests: Mutex<Vec<http::Request<Vec<u8>>>>,
+}
+
+impl InMemoryTransport {
+    fn new(responses: Vec<(u16, &'static str)>) -> InMemoryTransport {
+        InMemoryTransport {
+            responses: Mutex::new(responses),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl Transport for InMemoryTransport {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        self.requests.lock().unwrap().push(request);
+        let (status, body) = self.responses.lock().unwrap().remove(0);
+        Ok(http::Response::builder()
+            .status(status)
+            .body(body.as_bytes().to_vec())
+            .unwrap())
+    }
+}
+
+#[derive(Debug)]
+struct Disconnected;
+
+impl fmt::Display for Disconnected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "disconnected")
+    }
+}
+
+impl std::error::Error for Disconnected {}
+
+/// Fails to send every request.
+struct DisconnectedTransport;
+
+impl Transport for DisconnectedTransport {
+    fn send(
+        &self,
+        _request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HerokuApiFailure> {
+        Err(HerokuApiFailure::Transport(Box::new(Disconnected)))
+    }
+}
+
+fn get_client(transport: Arc<dyn Transport>, retry_policy: RetryPolicy) -> HttpApiClient {
+    let credentials = Credentials::UserAuthToken {
+        token: String::from("TOKEN_HERE"),
+    };
+    HttpApiClient::new(
+        credentials,
+        HttpApiClientConfig {
+            retry_policy,
+            transport: Some(transport),
+            ..HttpApiClientConfig::default()
+        },
+        ApiEnvironment::Production,
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_transport_sends_the_requests() {
+        let transport = Arc::new(InMemoryTransport::new(vec![(200, r#"{"PORT": "5000"}"#)]));
+        let api_client = get_client(transport.clone(), RetryPolicy::none());
+
+        let mut params = HashMap::new();
+        params.insert(String::from("PORT"), String::from("5000"));
+        let config_vars = api_client
+            .request(&config_vars::AppConfigVarUpdate {
+                app_id: "my-app",
+                params,
+            })
+            .unwrap();
+        assert_eq!(config_vars["PORT"], "5000");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::PATCH);
+        assert_eq!(
+            requests[0].uri(),
+            "https://api.heroku.com/apps/my-app/config-vars"
+        );
+        assert_eq!(requests[0].headers()["authorization"], "Bearer TOKEN_HERE");
+        assert_eq!(requests[0].body().as_slice(), br#"{"PORT":"5000"}"#);
+    }
+
+    #[test]
+    fn custom_transport_raw_responses_have_the_request_url() {
+        let transport = Arc::new(InMemoryTransport::new(vec![(
+            200,
+            r#"{"remaining": 2400}"#,
+        )]));
+        let api_client = get_client(transport, RetryPolicy::none());
+
+        let response = api_client
+            .request_raw(&misc::RatelimitDetails::new())
+            .unwrap();
+        assert_eq!(
+            response
+                .extensions()
+                .get::<url::Url>()
+                .map(url::Url::as_str),
+            Some("https://api.heroku.com/account/rate-limits")
+        );
+    }
+
+    #[test]
+    fn custom_transport_requests_are_retried() {
+        let transport = Arc::new(InMemoryTransport::new(vec![
+            (
+                503,
+                r#"{"id": "unavailable", "message": "Service temporarily unavailable"}"#,
+            ),
+            (200, r#"{"remaining": 2400}"#),
+        ]));
+        let api_client = get_client(
+            transport.clone(),
+            RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                jitter: false,
+                ..RetryPolicy::default()
+            },
+        );
+
+        let ratelimit = api_client.request(&misc::RatelimitDetails::new()).unwrap();
+        assert_eq!(ratelimit.remaining, 2400);
+        assert_eq!(transport.requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn custom_transport_failures_are_returned() {
+        let api_client = get_client(Arc::new(DisconnectedTransport), RetryPolicy::default());
+
+        let failure = api_client
+            .request(&misc::RatelimitDetails::new())
+            .unwrap_err();
+        assert!(matches!(failure, HerokuApiFailure::Transport(_)));
+        assert_eq!(
+            failure.to_string(),
+            "Could not send the request: disconnected"
+        );
+    }
+
+    #[test]
+    fn reqwest_transport_sends_to_the_server() {
+        let server =
+            StubServer::start(vec![StubResponse::new(200, r#"{"remaining": 2400}"#)
+                .header("RateLimit-Remaining", "2400")]);
+        let transport = ReqwestTransport::default();
+
+        let request = http::Request::builder()
+            .uri(format!("{}account/rate-limits", server.url()))
+            .header("Accept", "application/vnd.heroku+json; version=3")
+            .body(Vec::new())
+            .unwrap();
+        let response = transport.send(request).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["ratelimit-remaining"], "2400");
+        assert_eq!(response.body().as_slice(), br#"{"remaining": 2400}"#);
+        assert_eq!(
+            response.extensions().get::<url::Url>(),
+            Some(&server.url().join("account/rate-limits").unwrap())
+        );
+        assert_eq!(server.requests()[0].path, "/account/rate-limits");
+    }
+
+    #[test]
+    fn reqwest_transport_connection_errors_are_transport_failures() {
+        let transport = ReqwestTransport::default();
+
+        let request = http::Request::builder()
+            .uri("http://127.0.0.1:1/account/rate-limits")
+            .body(Vec::new())
+            .unwrap();
+        let failure = transport.send(request).unwrap_err();
+
+        assert!(matches!(failure, HerokuApiFailure::Transport(_)));
+        assert!(failure.is_retryable());
+    }
+}
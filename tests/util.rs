@@ -20,7 +20,7 @@ pub fn get_client() -> HttpApiClient {
         credentials,
         HttpApiClientConfig {
             http_timeout: Duration::from_secs(10),
-            default_headers: http::HeaderMap::default(),
+            ..HttpApiClientConfig::default()
         },
         ApiEnvironment::Custom(url::Url::parse(TEST_ENDPOINT).unwrap()),
     )